@@ -7,6 +7,8 @@
 // From standard library
 
 // From this library
+use crate::core::errors::ErrorKind;
+
 use crate::cache::CacheError;
 
 /// [`CacheBuilder`](crate::cache::CacheBuilder) runtime errors.
@@ -19,3 +21,13 @@ pub enum CacheBuilderError {
     #[error("{}", .0)]
     MutuallyExclusive(String),
 }
+
+impl CacheBuilderError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Cache(e) => e.kind(),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
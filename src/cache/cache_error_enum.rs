@@ -8,6 +8,8 @@
 
 // From this library
 use crate::core::errors::ConversionError;
+use crate::core::errors::DeviceError;
+use crate::core::errors::ErrorKind;
 
 /// [`Cache`](crate::cache::Cache) runtime errors.
 #[derive(Debug, Error)]
@@ -21,6 +23,10 @@ pub enum CacheError {
     #[error("{0}")]
     DeviceCreation(String),
 
+    /// Error removing a device entry, by name, from the device cache.
+    #[error("{0}")]
+    DeviceRemoval(String),
+
     /// Given an empty string as a device name.
     #[error("{0}")]
     EmptyDeviceName(String),
@@ -33,7 +39,22 @@ pub enum CacheError {
     #[error("{0}")]
     Conversion(#[from] ConversionError),
 
+    /// Error caused by the state of a device, or reported by `libblkid`.
+    #[error(transparent)]
+    Device(#[from] DeviceError),
+
     /// Error while probing block devices.
     #[error("{0}")]
     ProbeError(String),
 }
+
+impl CacheError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Device(e) => e.kind(),
+            Self::DeviceNotFound(_) => ErrorKind::NotFound,
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -14,25 +14,33 @@
 use crate::core::device::Tag;
 use crate::core::device::TagName;
 use crate::core::errors::ConversionError;
+use crate::core::errors::DeviceError;
 use crate::core::partition::RawBytes;
+use crate::core::utils::misc::UEventAction;
 
 use crate::cache::operation_enum::Operation;
 use crate::cache::Builder;
 use crate::cache::CacheBuilder;
 use crate::cache::CacheError;
 use crate::cache::Device;
+use crate::cache::DeviceChange;
+use crate::cache::DeviceInfo;
 use crate::cache::EntryIter;
 
 use crate::ffi_utils;
 
 /// Set of information about all block devices on a system.
+///
+/// Methods which might probe devices, and thus modify or discard cache entries, take `&mut self`
+/// and return owned [`DeviceInfo`] snapshots. Methods which only read the cache take `&self`, and
+/// may return [`Device`]s borrowing the `Cache`.
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Cache {
     pub(crate) inner: libblkid::blkid_cache,
 }
 
-impl<'cache> Cache {
+impl Cache {
     #[doc(hidden)]
     /// Creates a device cache.
     ///
@@ -48,6 +56,7 @@ fn new(dest_file: *const libc::c_char) -> Result<Cache, CacheError> {
 
         let mut cache = MaybeUninit::<libblkid::blkid_cache>::zeroed();
 
+        ffi_utils::clear_errno();
         let result = unsafe { libblkid::blkid_get_cache(cache.as_mut_ptr(), dest_file) };
 
         match result {
@@ -58,6 +67,7 @@ fn new(dest_file: *const libc::c_char) -> Result<Cache, CacheError> {
                 Ok(Self { inner })
             }
             code => {
+                let err = DeviceError::libblkid_failure("blkid_get_cache", code);
                 let err_msg = "failed to create a new `Cache` instance".to_owned();
                 log::debug!(
                     "Cache::new {}. libblkid::blkid_get_cache: returned error code {}",
@@ -65,7 +75,7 @@ fn new(dest_file: *const libc::c_char) -> Result<Cache, CacheError> {
                     code
                 );
 
-                Err(CacheError::Creation(err_msg))
+                Err(CacheError::from(err))
             }
         }
     }
@@ -147,6 +157,7 @@ pub fn builder() -> CacheBuilder {
     pub fn probe_all_devices(&mut self) -> Result<(), CacheError> {
         log::debug!("Cache::probe_all_devices probing all devices");
 
+        ffi_utils::clear_errno();
         let result = unsafe { libblkid::blkid_probe_all(self.inner) };
 
         match result {
@@ -156,6 +167,7 @@ pub fn probe_all_devices(&mut self) -> Result<(), CacheError> {
                 Ok(())
             }
             code => {
+                let err = DeviceError::libblkid_failure("blkid_probe_all", code);
                 let err_msg = "failed to probe all devices".to_owned();
                 log::debug!(
                         "Cache::probe_all_devices {}. libblkid::blkid_probe_all returned error code {:?}",
@@ -163,7 +175,7 @@ pub fn probe_all_devices(&mut self) -> Result<(), CacheError> {
                         code
                     );
 
-                Err(CacheError::ProbeError(err_msg))
+                Err(CacheError::from(err))
             }
         }
     }
@@ -172,6 +184,7 @@ pub fn probe_all_devices(&mut self) -> Result<(), CacheError> {
     pub fn probe_all_new_devices(&mut self) -> Result<(), CacheError> {
         log::debug!("Cache::probe_all_new_devices probing all new devices");
 
+        ffi_utils::clear_errno();
         let result = unsafe { libblkid::blkid_probe_all_new(self.inner) };
 
         match result {
@@ -181,6 +194,7 @@ pub fn probe_all_new_devices(&mut self) -> Result<(), CacheError> {
                 Ok(())
             }
             code => {
+                let err = DeviceError::libblkid_failure("blkid_probe_all_new", code);
                 let err_msg = "failed to probe all new devices".to_owned();
                 log::debug!(
                         "Cache::probe_all_new_devices {}. libblkid::blkid_probe_all_new returned error code {:?}",
@@ -188,7 +202,7 @@ pub fn probe_all_new_devices(&mut self) -> Result<(), CacheError> {
                         code
                     );
 
-                Err(CacheError::ProbeError(err_msg))
+                Err(CacheError::from(err))
             }
         }
     }
@@ -212,6 +226,7 @@ pub fn probe_all_new_devices(&mut self) -> Result<(), CacheError> {
     pub fn probe_all_removable_devices(&mut self) -> Result<(), CacheError> {
         log::debug!("Cache::probe_all_removable_devices probing all removable devices");
 
+        ffi_utils::clear_errno();
         let result = unsafe { libblkid::blkid_probe_all_removable(self.inner) };
 
         match result {
@@ -221,10 +236,11 @@ pub fn probe_all_removable_devices(&mut self) -> Result<(), CacheError> {
                 Ok(())
             }
             code => {
+                let err = DeviceError::libblkid_failure("blkid_probe_all_removable", code);
                 let err_msg = "failed to probe all removable devices".to_owned();
                 log::debug!("Cache::probe_all_removable_devices {}. libblkid::blkid_probe_all_removable returned error code {}", err_msg, code);
 
-                Err(CacheError::ProbeError(err_msg))
+                Err(CacheError::from(err))
             }
         }
     }
@@ -320,8 +336,8 @@ pub fn tag_value_from_device<T>(&self, tag_name: &TagName, path: T) -> Option<Ra
         }
     }
 
-    /// Returns the first device with a matching `tag`. This function returns `None`,
-    /// if no device with the given `tag` was found.
+    /// Returns a snapshot of the first device with a matching `tag`. This function returns
+    /// `None`, if no device with the given `tag` was found.
     ///
     /// # Examples
     ///
@@ -349,7 +365,7 @@ pub fn tag_value_from_device<T>(&self, tag_name: &TagName, path: T) -> Option<Ra
     ///     Ok(())
     /// }
     /// ```
-    pub fn find_device_with_tag(&mut self, tag: &Tag) -> Option<Device> {
+    pub fn find_device_with_tag(&mut self, tag: &Tag) -> Option<DeviceInfo> {
         log::debug!(
             "Cache::find_device_with_tag trying to find device with tag: {:?}",
             tag
@@ -382,7 +398,7 @@ pub fn find_device_with_tag(&mut self, tag: &Tag) -> Option<Device> {
                 let device_ptr = unsafe { NonNull::new_unchecked(device_ptr) };
                 let device = Device::new(self, device_ptr);
 
-                Some(device)
+                Some(DeviceInfo::from(device))
             }
         }
     }
@@ -626,7 +642,7 @@ pub fn find_canonical_device_name_from_path<T>(&mut self, path: T) -> Option<Pat
     ///     Ok(())
     /// }
     /// ```
-    pub fn iter(&'cache self) -> EntryIter<'cache> {
+    pub fn iter(&self) -> EntryIter<'_> {
         log::debug!("Cache::iter creating a new `EntryIter` instance");
         EntryIter::new(self).unwrap()
     }
@@ -640,10 +656,10 @@ pub fn iter(&'cache self) -> EntryIter<'cache> {
     /// from the cache or by probing `device_name`,
     /// - `Operation::Verify`: refresh data in the cache belonging to `device_name`.
     fn search_for_device_info(
-        &'cache self,
+        &self,
         device_name: &Path,
         flag: Operation,
-    ) -> Result<Device<'cache>, CacheError> {
+    ) -> Result<Device<'_>, CacheError> {
         log::debug!(
             "Cache::search_for_device_info searching for device {:?}",
             device_name
@@ -700,7 +716,7 @@ fn search_for_device_info(
     }
 
     /// Adds a device named `device_name` to the cache, provided the device exists and one entry
-    /// with the same name is not already present.
+    /// with the same name is not already present. Returns a snapshot of the new entry.
     ///
     /// # Examples
     ///
@@ -721,17 +737,18 @@ fn search_for_device_info(
     ///     Ok(())
     /// }
     /// ```
-    pub fn add_new_entry<T>(&'cache mut self, device_name: T) -> Result<Device<'cache>, CacheError>
+    pub fn add_new_entry<T>(&mut self, device_name: T) -> Result<DeviceInfo, CacheError>
     where
         T: AsRef<Path>,
     {
         log::debug!("Cache::add_new_entry adding new empty cache entry");
 
         Self::search_for_device_info(self, device_name.as_ref(), Operation::Create)
+            .map(DeviceInfo::from)
     }
 
     /// Finds a device by name, either from the cache or by probing block devices connected to the
-    /// system.
+    /// system. Returns a snapshot of the device's entry.
     ///
     /// # Examples
     ///
@@ -756,7 +773,7 @@ pub fn add_new_entry<T>(&'cache mut self, device_name: T) -> Result<Device<'cach
     ///     Ok(())
     /// }
     /// ```
-    pub fn find_device_by_name<T>(&'cache mut self, device_name: T) -> Option<Device<'cache>>
+    pub fn find_device_by_name<T>(&mut self, device_name: T) -> Option<DeviceInfo>
     where
         T: AsRef<Path>,
     {
@@ -766,15 +783,35 @@ pub fn find_device_by_name<T>(&'cache mut self, device_name: T) -> Option<Device
             device_name
         );
 
-        Self::search_for_device_info(self, device_name, Operation::Normal).ok()
+        Self::search_for_device_info(self, device_name, Operation::Normal)
+            .ok()
+            .map(DeviceInfo::from)
     }
 
     /// Finds a device by name by only searching the cache. **Does NOT refresh any cached data
     /// before searching for a device.**
-    pub fn lookup_device_by_name<T>(
-        &'cache mut self,
-        device_name: T,
-    ) -> Result<Device<'cache>, CacheError>
+    ///
+    /// Looking-up a device does not modify the cache, so several [`Device`]s can be borrowed at
+    /// the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::cache::Cache;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut cache = Cache::builder().build()?;
+    ///     cache.probe_all_devices()?;
+    ///
+    ///     let vda = cache.lookup_device_by_name("/dev/vda")?;
+    ///     let vdb = cache.lookup_device_by_name("/dev/vdb")?;
+    ///
+    ///     assert_ne!(vda, vdb);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn lookup_device_by_name<T>(&self, device_name: T) -> Result<Device<'_>, CacheError>
     where
         T: AsRef<Path>,
     {
@@ -787,11 +824,12 @@ pub fn lookup_device_by_name<T>(
         Self::search_for_device_info(self, device_name, Operation::Find)
     }
 
-    /// Refreshes the cache **before** searching for a device by name.
+    /// Refreshes the cache **before** searching for a device by name. Returns a snapshot of the
+    /// device's entry.
     pub fn lookup_refreshed_device_by_name<T>(
-        &'cache mut self,
+        &mut self,
         device_name: T,
-    ) -> Result<Device<'cache>, CacheError>
+    ) -> Result<DeviceInfo, CacheError>
     where
         T: AsRef<Path>,
     {
@@ -801,10 +839,9 @@ pub fn lookup_refreshed_device_by_name<T>(
             device_name
         );
 
-        Self::search_for_device_info(self, device_name, Operation::Verify)
+        Self::search_for_device_info(self, device_name, Operation::Verify).map(DeviceInfo::from)
     }
 
-    /// Probes all block devices and populates the `Cache`.
     /// Checks that cached data in the `device` argument is consistent with its current state
     /// on the system, and refreshes it if necessary.
     ///
@@ -812,6 +849,8 @@ pub fn lookup_refreshed_device_by_name<T>(
     /// function to refresh your copy of the `device`'s metadata if you need up-to-date
     /// information.
     ///
+    /// Returns `device` unchanged if its data could not be refreshed.
+    ///
     /// # Examples
     ///
     /// ```ignore
@@ -819,7 +858,7 @@ pub fn lookup_refreshed_device_by_name<T>(
     ///
     /// fn main() -> rsblkid::Result<()> {
     ///     let mut cache = Cache::builder().build()?;
-    ///     let device = cache.find_device_by_name("/dev/vda")?;
+    ///     let device = cache.find_device_by_name("/dev/vda").unwrap();
     ///
     ///     // Run a long-lived process that might modify /dev/vda
     ///     // ...
@@ -831,7 +870,7 @@ pub fn lookup_refreshed_device_by_name<T>(
     ///     Ok(())
     /// }
     /// ```
-    pub fn refresh_device_data(&'cache mut self, device: Device<'cache>) -> Device<'cache> {
+    pub fn refresh_device_data(&mut self, device: DeviceInfo) -> DeviceInfo {
         log::debug!(
             "Cache::refresh_device_data refreshing data about device named {:?} ",
             device.name()
@@ -839,6 +878,151 @@ pub fn refresh_device_data(&'cache mut self, device: Device<'cache>) -> Device<'
 
         Self::lookup_refreshed_device_by_name(self, device.name()).unwrap_or(device)
     }
+
+    #[doc(hidden)]
+    /// Helper function: returns a copy of the tags of the cache entry named `device_name`, or
+    /// `None` if the cache does not have such an entry.
+    fn device_tags_snapshot(&self, device_name: &Path) -> Option<Vec<Tag>> {
+        Self::search_for_device_info(self, device_name, Operation::Find)
+            .ok()
+            .map(|device| device.iter().collect())
+    }
+
+    #[doc(hidden)]
+    /// Helper function: discards the entry named `device_name`, unless the device is still
+    /// present on the system. Returns a copy of the entry's tags if it is kept.
+    fn discard_entry(&mut self, device_name: &Path) -> Result<Option<Vec<Tag>>, CacheError> {
+        // `libblkid` discards the entry of a device it can not open, or that holds no data.
+        let tags: Vec<_> = match Self::search_for_device_info(self, device_name, Operation::Verify)
+        {
+            Ok(device) => device.iter().collect(),
+            Err(_) => return Ok(None),
+        };
+
+        if device_name.exists() {
+            log::debug!(
+                "Cache::discard_entry device {:?} is still present, keeping its entry",
+                device_name
+            );
+
+            return Ok(Some(tags));
+        }
+
+        // `libblkid` keeps the unverified entry of a device whose node was deleted. Only
+        // `blkid_gc_cache` discards it, along with any other entry of a missing device.
+        let is_only_stale_entry = self
+            .iter()
+            .all(|device| device.name() == device_name || device.name().exists());
+
+        if is_only_stale_entry {
+            self.garbage_collect();
+
+            Ok(self.device_tags_snapshot(device_name))
+        } else {
+            let err_msg = format!(
+                "can not discard entry {:?} without discarding the entries of other missing devices",
+                device_name
+            );
+            log::debug!("Cache::discard_entry {}", err_msg);
+
+            Err(CacheError::DeviceRemoval(err_msg))
+        }
+    }
+
+    /// Applies a `udev` event to the cache, keeping a long-lived `Cache` synchronized with
+    /// devices coming and going.
+    ///
+    /// Depending on the value of `action`:
+    /// - [`UEventAction::Add`] creates a new entry for `device_name`, then probes the device,
+    /// - [`UEventAction::Change`] refreshes the data about `device_name` (creating a new entry if
+    /// none exists),
+    /// - [`UEventAction::Remove`] discards the entry for `device_name` if the device is no
+    /// longer present on the system, leaving other entries untouched.
+    ///
+    /// Returns a [`DeviceChange`] describing what was modified in the cache, or `None` if the
+    /// event did not alter its content.
+    ///
+    /// **Note:** `libblkid` does not probe a device again if its cached data was verified
+    /// recently, and its device node was not modified since. In that case, a
+    /// [`UEventAction::Change`] event may leave the cache unaltered.
+    ///
+    /// `libblkid` can not discard a single entry whose device node was deleted, only all such
+    /// entries at once (see [`Cache::garbage_collect`]). A [`UEventAction::Remove`] event
+    /// for such a device returns [`CacheError::DeviceRemoval`] when other entries of missing
+    /// devices are in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::cache::{Cache, DeviceChange};
+    /// use rsblkid::core::utils::misc::UEventAction;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut cache = Cache::builder().build()?;
+    ///     cache.probe_all_devices()?;
+    ///
+    ///     // A USB stick was plugged in...
+    ///     match cache.apply_uevent("/dev/sdb1", UEventAction::Add)? {
+    ///         Some(DeviceChange::Appeared { device_name, tags }) => {
+    ///             println!("new device {} {:?}", device_name.display(), tags);
+    ///         }
+    ///         Some(DeviceChange::TagsChanged { device_name, before, after }) => {
+    ///             println!("{}: {:?} -> {:?}", device_name.display(), before, after);
+    ///         }
+    ///         _ => {}
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn apply_uevent<T>(
+        &mut self,
+        device_name: T,
+        action: UEventAction,
+    ) -> Result<Option<DeviceChange>, CacheError>
+    where
+        T: AsRef<Path>,
+    {
+        let device_name = device_name.as_ref();
+        log::debug!(
+            "Cache::apply_uevent applying ACTION={:?} to device {:?}",
+            action,
+            device_name
+        );
+
+        let before = self.device_tags_snapshot(device_name);
+
+        let after = match action {
+            UEventAction::Add | UEventAction::Change => {
+                if before.is_none() {
+                    Self::search_for_device_info(self, device_name, Operation::Create)?;
+                }
+
+                let device = Self::search_for_device_info(self, device_name, Operation::Verify)?;
+                let tags: Vec<_> = device.iter().collect();
+
+                Some(tags)
+            }
+            UEventAction::Remove if before.is_none() => None,
+            UEventAction::Remove => self.discard_entry(device_name)?,
+        };
+
+        let device_name = device_name.to_path_buf();
+        let change = match (before, after) {
+            (None, Some(tags)) => Some(DeviceChange::Appeared { device_name, tags }),
+            (Some(tags), None) => Some(DeviceChange::Disappeared { device_name, tags }),
+            (Some(before), Some(after)) if before != after => Some(DeviceChange::TagsChanged {
+                device_name,
+                before,
+                after,
+            }),
+            _unchanged => None,
+        };
+
+        log::debug!("Cache::apply_uevent cache change: {:?}", change);
+
+        Ok(change)
+    }
 }
 
 impl fmt::Display for Cache {
@@ -865,6 +1049,13 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+// SAFETY: a `blkid_cache` owns all the data it holds, and `libblkid` keeps no per-thread state
+// about it. It is therefore safe to move a `Cache` to another thread. `Device`s and iterators
+// borrow the `Cache` they come from, so they can not be left behind on the original thread.
+//
+// A `Cache` is NOT `Sync`; wrap it in a `SharedCache` to access it from multiple threads.
+unsafe impl Send for Cache {}
+
 impl Drop for Cache {
     /// Saves changes to device information into the destination file provided at construction.
     fn drop(&mut self) {
@@ -937,4 +1128,82 @@ fn lookup_refreshed_device_by_name_panics_when_device_does_not_exist() {
 
         let _ = cache.lookup_refreshed_device_by_name(DEV_DUMMY).unwrap();
     }
+
+    #[test]
+    fn cache_can_be_used_after_a_device_search() -> crate::Result<()> {
+        let mut cache = Cache::builder().discard_changes_on_drop().build()?;
+
+        let device = cache.find_device_by_name(DEV_DUMMY);
+        let other = cache.find_device_by_name(DEV_DUMMY);
+        let actual = device.map(|device| cache.refresh_device_data(device));
+        let expected = other;
+        assert_eq!(actual, expected);
+
+        let first = cache.lookup_device_by_name(DEV_DUMMY);
+        let second = cache.lookup_device_by_name(DEV_DUMMY);
+        assert!(first.is_err());
+        assert!(second.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_uevent_add_panics_when_device_does_not_exist() {
+        let mut cache = Cache::builder().discard_changes_on_drop().build().unwrap();
+
+        let _ = cache.apply_uevent(DEV_DUMMY, UEventAction::Add).unwrap();
+    }
+
+    #[test]
+    fn apply_uevent_remove_discards_the_entry_of_a_deleted_device() -> crate::Result<()> {
+        let mut cache = Cache::builder().discard_changes_on_drop().build()?;
+
+        let device = tempfile::NamedTempFile::new()?;
+        let device_name = device.path().to_path_buf();
+        cache.add_new_entry(&device_name)?;
+        device.close()?;
+
+        let actual = cache.apply_uevent(&device_name, UEventAction::Remove)?;
+        let expected = Some(DeviceChange::Disappeared {
+            device_name: device_name.clone(),
+            tags: vec![],
+        });
+        assert_eq!(actual, expected);
+        assert!(cache.lookup_device_by_name(&device_name).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_uevent_remove_keeps_unrelated_entries() -> crate::Result<()> {
+        let mut cache = Cache::builder().discard_changes_on_drop().build()?;
+
+        let device = tempfile::NamedTempFile::new()?;
+        let other = tempfile::NamedTempFile::new()?;
+        let device_name = device.path().to_path_buf();
+        let other_name = other.path().to_path_buf();
+        cache.add_new_entry(&device_name)?;
+        cache.add_new_entry(&other_name)?;
+        device.close()?;
+        other.close()?;
+
+        // Both entries are stale, discarding one would discard the other.
+        let actual = cache.apply_uevent(&device_name, UEventAction::Remove);
+        assert!(matches!(actual, Err(CacheError::DeviceRemoval(_))));
+        assert!(cache.lookup_device_by_name(&other_name).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_uevent_remove_ignores_a_device_not_in_cache() -> crate::Result<()> {
+        let mut cache = Cache::builder().discard_changes_on_drop().build()?;
+
+        let actual = cache.apply_uevent(DEV_DUMMY, UEventAction::Remove)?;
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
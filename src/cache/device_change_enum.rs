@@ -0,0 +1,52 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::device::Tag;
+
+/// Change made to a [`Cache`](crate::cache::Cache) entry after processing a device `uevent`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DeviceChange {
+    /// A new device entry was added to the cache.
+    Appeared {
+        /// Name of the new device.
+        device_name: PathBuf,
+        /// Tags collected when the device was probed.
+        tags: Vec<Tag>,
+    },
+
+    /// A device entry was removed from the cache.
+    Disappeared {
+        /// Name of the removed device.
+        device_name: PathBuf,
+        /// Tags the device had before its removal.
+        tags: Vec<Tag>,
+    },
+
+    /// The tags of a device entry were modified.
+    TagsChanged {
+        /// Name of the modified device.
+        device_name: PathBuf,
+        /// Tags before the device was refreshed.
+        before: Vec<Tag>,
+        /// Tags after the device was refreshed.
+        after: Vec<Tag>,
+    },
+}
+
+impl DeviceChange {
+    /// Returns the name of the device affected by this `DeviceChange`.
+    pub fn device_name(&self) -> &Path {
+        match self {
+            Self::Appeared { device_name, .. } => device_name,
+            Self::Disappeared { device_name, .. } => device_name,
+            Self::TagsChanged { device_name, .. } => device_name,
+        }
+    }
+}
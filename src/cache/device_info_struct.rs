@@ -0,0 +1,117 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::utils::format::DeviceTags;
+
+use crate::cache::Device;
+
+/// Owned snapshot of a [`Device`] entry in a [`Cache`](crate::cache::Cache).
+///
+/// Unlike a [`Device`], a `DeviceInfo` does not borrow the `Cache` it was copied from; it can
+/// outlive the `Cache`, or be sent to another thread.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DeviceInfo {
+    name: PathBuf,
+    tags: Vec<Tag>,
+}
+
+impl DeviceInfo {
+    /// Returns the device's name.
+    pub fn name(&self) -> &Path {
+        &self.name
+    }
+
+    /// Returns the device's tags.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Returns `true` if the device has the given `tag`.
+    pub fn has_tag<T>(&self, tag: T) -> bool
+    where
+        T: AsRef<Tag>,
+    {
+        let tag = tag.as_ref();
+
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Returns `true` if the device has a tag named `tag_name`.
+    pub fn has_tag_named<T>(&self, tag_name: T) -> bool
+    where
+        T: AsRef<TagName>,
+    {
+        let tag_name = tag_name.as_ref();
+
+        self.tags.iter().any(|t| &t.name() == tag_name)
+    }
+
+    /// Returns the tag named `tag_name`, if the device has one.
+    pub fn tag_named<T>(&self, tag_name: T) -> Option<&Tag>
+    where
+        T: AsRef<TagName>,
+    {
+        let tag_name = tag_name.as_ref();
+
+        self.tags.iter().find(|t| &t.name() == tag_name)
+    }
+
+    /// Returns an iterator over the device's tags.
+    pub fn iter(&self) -> std::slice::Iter<'_, Tag> {
+        self.tags.iter()
+    }
+}
+
+impl<'a> From<&Device<'a>> for DeviceInfo {
+    fn from(device: &Device<'a>) -> DeviceInfo {
+        let name = device.name().to_path_buf();
+        let tags = device.iter().collect();
+
+        Self { name, tags }
+    }
+}
+
+impl<'a> From<Device<'a>> for DeviceInfo {
+    fn from(device: Device<'a>) -> DeviceInfo {
+        Self::from(&device)
+    }
+}
+
+impl From<DeviceInfo> for DeviceTags {
+    fn from(device: DeviceInfo) -> DeviceTags {
+        DeviceTags::with_device_name(device.name, device.tags)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn device_info_can_find_a_tag() -> crate::Result<()> {
+        let label: Tag = "LABEL=nixos".parse()?;
+        let uuid: Tag = "UUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?;
+        let device = DeviceInfo {
+            name: PathBuf::from("/dev/vda"),
+            tags: vec![label.clone()],
+        };
+
+        assert!(device.has_tag(&label));
+        assert!(!device.has_tag(&uuid));
+        assert!(device.has_tag_named(TagName::Label));
+        assert!(!device.has_tag_named(TagName::Uuid));
+        assert_eq!(device.tag_named(TagName::Label), Some(&label));
+
+        Ok(())
+    }
+}
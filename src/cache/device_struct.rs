@@ -221,7 +221,7 @@ pub fn has_tag_named<T>(&self, tag_name: T) -> bool
     ///     Ok(())
     /// }
     /// ```
-    pub fn iter(&'a self) -> TagIter<'a> {
+    pub fn iter(&self) -> TagIter<'_> {
         log::debug!("Device::iter creating new `TagIter` instance");
         TagIter::new(self).unwrap()
     }
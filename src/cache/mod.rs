@@ -67,9 +67,12 @@
 pub use cache_builder_struct::CacheBuilder;
 pub use cache_error_enum::CacheError;
 pub use cache_struct::Cache;
+pub use device_change_enum::DeviceChange;
+pub use device_info_struct::DeviceInfo;
 pub use device_struct::Device;
 pub use entry_iter_error_enum::EntryIterError;
 pub use entry_iter_struct::EntryIter;
+pub use shared_cache_struct::SharedCache;
 pub use tag_iter_error_enum::TagIterError;
 pub use tag_iter_struct::TagIter;
 
@@ -77,9 +80,12 @@
 mod cache_builder_struct;
 mod cache_error_enum;
 mod cache_struct;
+mod device_change_enum;
+mod device_info_struct;
 mod device_struct;
 mod entry_iter_error_enum;
 mod entry_iter_struct;
 mod operation_enum;
+mod shared_cache_struct;
 mod tag_iter_error_enum;
 mod tag_iter_struct;
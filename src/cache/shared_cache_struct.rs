@@ -0,0 +1,250 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::partition::RawBytes;
+use crate::core::utils::misc::UEventAction;
+
+use crate::cache::Cache;
+use crate::cache::CacheError;
+use crate::cache::DeviceChange;
+use crate::cache::DeviceInfo;
+
+/// Thread-safe handle to a [`Cache`].
+///
+/// A `SharedCache` serializes access to the `Cache` it wraps, and returns owned [`DeviceInfo`]
+/// snapshots instead of [`Device`](crate::cache::Device)s borrowing the `Cache`. Cloning a
+/// `SharedCache` creates a new handle to the same `Cache`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::thread;
+/// use rsblkid::cache::Cache;
+/// use rsblkid::cache::SharedCache;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let cache = Cache::builder().discard_changes_on_drop().build()?;
+///     let shared = SharedCache::new(cache);
+///     shared.probe_all_devices()?;
+///
+///     let handle = shared.clone();
+///     let worker = thread::spawn(move || handle.devices());
+///
+///     for device in worker.join().unwrap() {
+///         println!("{}", device.name().display());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedCache {
+    inner: Arc<Mutex<Cache>>,
+}
+
+impl SharedCache {
+    /// Creates a new `SharedCache` wrapping `cache`.
+    pub fn new(cache: Cache) -> SharedCache {
+        log::debug!("SharedCache::new creating new `SharedCache` instance");
+
+        Self {
+            inner: Arc::new(Mutex::new(cache)),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: locks the wrapped `Cache`.
+    ///
+    /// A thread panicking while holding the lock does not leave the `Cache` in an inconsistent
+    /// state from `libblkid`'s point of view, so we recover from a poisoned lock instead of
+    /// propagating the panic.
+    fn lock(&self) -> MutexGuard<'_, Cache> {
+        self.inner.lock().unwrap_or_else(|poisoned| {
+            log::debug!("SharedCache::lock recovering from poisoned lock");
+
+            poisoned.into_inner()
+        })
+    }
+
+    /// Runs `f` with exclusive access to the wrapped `Cache`.
+    ///
+    /// Use this method to perform several operations on the `Cache` atomically, or to call a
+    /// method that `SharedCache` does not expose.
+    pub fn with_cache<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut Cache) -> R,
+    {
+        let mut cache = self.lock();
+
+        f(&mut cache)
+    }
+
+    /// Probes all block devices and populates the `Cache`.
+    pub fn probe_all_devices(&self) -> Result<(), CacheError> {
+        self.lock().probe_all_devices()
+    }
+
+    /// Probes all new block devices, and adds them to the `Cache`.
+    pub fn probe_all_new_devices(&self) -> Result<(), CacheError> {
+        self.lock().probe_all_new_devices()
+    }
+
+    /// Probes all removable block devices, and adds them to the `Cache`.
+    pub fn probe_all_removable_devices(&self) -> Result<(), CacheError> {
+        self.lock().probe_all_removable_devices()
+    }
+
+    /// Removes stale data about devices that are no-longer connected to the system.
+    pub fn garbage_collect(&self) {
+        self.lock().garbage_collect()
+    }
+
+    /// Returns the value of the tag named `tag_name` on a specific device at `path`, `None` if the
+    /// device does not have a tag matching the given name.
+    ///
+    /// See [`Cache::tag_value_from_device`].
+    pub fn tag_value_from_device<T>(&self, tag_name: &TagName, path: T) -> Option<RawBytes>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().tag_value_from_device(tag_name, path)
+    }
+
+    /// Returns a snapshot of the device with the given `tag`.
+    pub fn find_device_with_tag(&self, tag: &Tag) -> Option<DeviceInfo> {
+        self.lock().find_device_with_tag(tag)
+    }
+
+    /// Returns the name of the device with the given `tag`.
+    pub fn find_device_name_from_tag(&self, tag: &Tag) -> Option<PathBuf> {
+        self.lock().find_device_name_from_tag(tag)
+    }
+
+    /// Returns the canonical name of the device with the given `tag`.
+    pub fn find_canonical_device_name_from_tag(&self, tag: &Tag) -> Option<PathBuf> {
+        self.lock().find_canonical_device_name_from_tag(tag)
+    }
+
+    /// Returns the canonical name of the device at `path`.
+    pub fn find_canonical_device_name_from_path<T>(&self, path: T) -> Option<PathBuf>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().find_canonical_device_name_from_path(path)
+    }
+
+    /// Returns a snapshot of all the devices in the `Cache`.
+    pub fn devices(&self) -> Vec<DeviceInfo> {
+        self.lock().iter().map(DeviceInfo::from).collect()
+    }
+
+    /// Adds a device named `device_name` to the cache, provided the device exists and one entry
+    /// with the same name is not already present. Returns a snapshot of the new entry.
+    pub fn add_new_entry<T>(&self, device_name: T) -> Result<DeviceInfo, CacheError>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().add_new_entry(device_name)
+    }
+
+    /// Returns a snapshot of the device named `device_name`, either from the cache or by probing
+    /// block devices connected to the system.
+    pub fn find_device_by_name<T>(&self, device_name: T) -> Option<DeviceInfo>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().find_device_by_name(device_name)
+    }
+
+    /// Returns a snapshot of the device named `device_name` by only searching the cache. **Does
+    /// NOT refresh any cached data before searching for a device.**
+    pub fn lookup_device_by_name<T>(&self, device_name: T) -> Result<DeviceInfo, CacheError>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock()
+            .lookup_device_by_name(device_name)
+            .map(DeviceInfo::from)
+    }
+
+    /// Refreshes the cache **before** returning a snapshot of the device named `device_name`.
+    pub fn lookup_refreshed_device_by_name<T>(
+        &self,
+        device_name: T,
+    ) -> Result<DeviceInfo, CacheError>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().lookup_refreshed_device_by_name(device_name)
+    }
+
+    /// Applies a `udev` event to the cache.
+    ///
+    /// See [`Cache::apply_uevent`].
+    pub fn apply_uevent<T>(
+        &self,
+        device_name: T,
+        action: UEventAction,
+    ) -> Result<Option<DeviceChange>, CacheError>
+    where
+        T: AsRef<Path>,
+    {
+        self.lock().apply_uevent(device_name, action)
+    }
+
+    /// Consumes this `SharedCache`, returning the wrapped `Cache` if this is its last handle.
+    /// Otherwise, returns `Err(self)`.
+    pub fn try_into_inner(self) -> Result<Cache, SharedCache> {
+        match Arc::try_unwrap(self.inner) {
+            Ok(mutex) => Ok(mutex
+                .into_inner()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())),
+            Err(inner) => Err(Self { inner }),
+        }
+    }
+}
+
+impl From<Cache> for SharedCache {
+    fn from(cache: Cache) -> SharedCache {
+        Self::new(cache)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn shared_cache_is_send_and_sync() {
+        assert_send_sync::<SharedCache>();
+    }
+
+    #[test]
+    fn shared_cache_can_be_used_from_another_thread() -> crate::Result<()> {
+        let cache = Cache::builder().discard_changes_on_drop().build()?;
+        let shared = SharedCache::new(cache);
+
+        let handle = shared.clone();
+        let actual = std::thread::spawn(move || handle.lookup_device_by_name("/dev/DUMMY_DEVICE"))
+            .join()
+            .unwrap();
+        assert!(actual.is_err());
+
+        assert!(shared.try_into_inner().is_ok());
+
+        Ok(())
+    }
+}
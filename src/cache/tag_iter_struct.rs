@@ -63,33 +63,47 @@ impl<'a> Iterator for TagIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         log::debug!("TagIter::new advancing to next element");
 
-        let mut tag_name_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
-        let mut tag_value_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
-
-        let result = unsafe {
-            libblkid::blkid_tag_next(
-                self.inner,
-                tag_name_ptr.as_mut_ptr(),
-                tag_value_ptr.as_mut_ptr(),
-            )
-        };
-        match result {
-            0 => {
-                log::debug!("TagIter::next found next Tag");
-
-                let tag_name_ptr = unsafe { tag_name_ptr.assume_init() };
-                let tag_value_ptr = unsafe { tag_value_ptr.assume_init() };
-
-                let cstr = ffi_utils::const_c_char_array_to_c_str(tag_name_ptr);
-                let tag_name = TagName::try_from(cstr).ok()?;
-                let tag_value = ffi_utils::const_c_char_array_to_bytes(tag_value_ptr);
-
-                Tag::try_from((tag_name, tag_value)).ok()
-            }
-            code => {
-                log::debug!("TagIter::next can not get next element. libblkid::blkid_tag_next returned error code {:?}", code);
-
-                None
+        loop {
+            let mut tag_name_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+            let mut tag_value_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+
+            let result = unsafe {
+                libblkid::blkid_tag_next(
+                    self.inner,
+                    tag_name_ptr.as_mut_ptr(),
+                    tag_value_ptr.as_mut_ptr(),
+                )
+            };
+            match result {
+                0 => {
+                    log::debug!("TagIter::next found next Tag");
+
+                    let tag_name_ptr = unsafe { tag_name_ptr.assume_init() };
+                    let tag_value_ptr = unsafe { tag_value_ptr.assume_init() };
+
+                    let cstr = ffi_utils::const_c_char_array_to_c_str(tag_name_ptr);
+                    let tag_name = match TagName::try_from(cstr) {
+                        Ok(tag_name) => tag_name,
+                        Err(e) => {
+                            log::debug!("TagIter::next skipping Tag with unknown name. {:?}", e);
+
+                            continue;
+                        }
+                    };
+                    let tag_value = ffi_utils::const_c_char_array_to_bytes(tag_value_ptr);
+
+                    match Tag::try_from((tag_name, tag_value)) {
+                        Ok(tag) => return Some(tag),
+                        Err(e) => {
+                            log::debug!("TagIter::next skipping unconvertible Tag. {:?}", e);
+                        }
+                    }
+                }
+                code => {
+                    log::debug!("TagIter::next can not get next element. libblkid::blkid_tag_next returned error code {:?}", code);
+
+                    return None;
+                }
             }
         }
     }
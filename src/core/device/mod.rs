@@ -17,7 +17,9 @@
 pub use tag_enum::Tag;
 pub use tag_name_enum::TagName;
 pub use usage_enum::Usage;
+pub use uuid_format_enum::UuidFormat;
 pub use uuid_struct::Uuid;
+pub use uuid_variant_enum::UuidVariant;
 
 mod device_number_struct;
 mod id_struct;
@@ -28,4 +30,6 @@
 mod tag_enum;
 mod tag_name_enum;
 mod usage_enum;
+mod uuid_format_enum;
 mod uuid_struct;
+mod uuid_variant_enum;
@@ -7,9 +7,12 @@
 use std::fmt;
 
 // From this library
+use crate::core::device::Size;
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
 
+use crate::core::num;
+use crate::core::num::UnitSystem;
 use crate::core::num::UnsignedInt;
 
 /// Distance from the beginning of a device.
@@ -73,6 +76,65 @@ pub fn to_u64(&self) -> Option<u64> {
     pub fn to_u32(&self) -> Option<u32> {
         self.0.to_u32()
     }
+
+    /// Moves this `Offset` forward by `size` bytes. Returns `None` on overflow.
+    pub fn checked_add(&self, size: &Size) -> Option<Offset> {
+        self.as_u64().checked_add(size.as_u64()).map(Offset::from)
+    }
+
+    /// Moves this `Offset` backward by `size` bytes. Returns `None` if `size` is larger than
+    /// this `Offset`.
+    pub fn checked_sub(&self, size: &Size) -> Option<Offset> {
+        self.as_u64().checked_sub(size.as_u64()).map(Offset::from)
+    }
+
+    /// Returns the number of bytes from `start` to this `Offset`. Returns `None` if `start` is
+    /// past this `Offset`.
+    pub fn checked_distance_from(&self, start: &Offset) -> Option<Size> {
+        self.as_u64().checked_sub(start.as_u64()).map(Size::from)
+    }
+
+    /// Returns this `Offset` in bytes, as a `u64` whatever the width of its underlying value.
+    pub fn as_u64(&self) -> u64 {
+        self.0.as_u64()
+    }
+
+    /// Creates a `Offset` from a number of `sectors` of `sector_size` bytes. Returns `None` if the
+    /// result overflows a `u64`.
+    pub fn from_sectors(sectors: u64, sector_size: u64) -> Option<Offset> {
+        sectors.checked_mul(sector_size).map(Offset::from)
+    }
+
+    /// Converts this `Offset` to a number of sectors of `sector_size` bytes, rounded down. Returns
+    /// `None` if `sector_size` is zero.
+    pub fn to_sectors(&self, sector_size: u64) -> Option<u64> {
+        self.as_u64().checked_div(sector_size)
+    }
+
+    /// Returns `true` if this `Offset` is a multiple of `sector_size`.
+    pub fn is_aligned_to(&self, sector_size: u64) -> bool {
+        sector_size != 0 && self.as_u64() % sector_size == 0
+    }
+
+    /// Formats this `Offset` with the largest unit of `units` for which the value is at least `1`,
+    /// rounded to one decimal place (e.g. `10 GiB`, `1.5 MB`).
+    pub fn to_human_string(&self, units: UnitSystem) -> String {
+        num::to_human_string(self.as_u64(), units)
+    }
+
+    /// Parses a `Offset` from a human-readable string, following the conventions of `lsblk` and
+    /// `sfdisk`:
+    /// - a number without suffix, or with a `B` suffix is a number of bytes (e.g. `4096`),
+    /// - single letter suffixes `K`, `M`, `G`, `T`, `P`, `E` and `iB` suffixes (`KiB`, `MiB`,
+    ///   etc.) are powers of 1024 (e.g. `512M`, `10 GiB`),
+    /// - `B` suffixes (`KB`, `MB`, etc.) are powers of 1000 (e.g. `10GB`).
+    ///
+    /// Suffixes are case-insensitive, and numbers may have a fractional part (e.g. `1.5G`).
+    pub fn from_human_str(s: &str) -> Result<Offset, ParserError> {
+        num::parse_human_size(s)
+            .map(Offset::from)
+            .map_err(ParserError::Offset)
+    }
 }
 
 impl AsRef<Offset> for Offset {
@@ -122,3 +184,132 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "missing closing double-quote")]
+    fn offset_can_not_parse_an_offset_string_with_an_unclosed_double_quote() {
+        let _ = Offset::from_str_u64(r#""4096"#).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid integer value")]
+    fn offset_can_not_parse_an_invalid_offset() {
+        let _ = Offset::from_str_u64("DUMMY").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "number too large to fit in target type")]
+    fn offset_from_str_u32_can_not_parse_an_offset_larger_than_max_u32() {
+        let _ = Offset::from_str_u32("4294967296").unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes to UTF-8 string slice conversion error")]
+    fn offset_can_not_convert_invalid_bytes_into_an_offset() {
+        // some invalid bytes, in a vector
+        let bytes: Vec<u8> = vec![0, 159, 146, 150];
+        let _ = Offset::try_from_u64(bytes).unwrap();
+    }
+
+    #[test]
+    fn offset_can_parse_a_valid_offset() -> crate::Result<()> {
+        let actual = Offset::from_str_u64("1048576")?;
+        let expected = Offset::from(1_048_576u64);
+        assert_eq!(actual, expected);
+
+        let actual = Offset::from_str_u32("'512'")?;
+        let expected = Offset::from(512u32);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_can_convert_valid_bytes_into_an_offset() -> crate::Result<()> {
+        let actual = Offset::try_from_u64(b"1048576")?;
+        let expected = Offset::from(1_048_576u64);
+        assert_eq!(actual, expected);
+
+        let bytes: Vec<u8> = b"512".to_vec();
+        let actual = Offset::try_from_u32(bytes)?;
+        let expected = Offset::from(512u32);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_can_round_trip_through_its_string_representation() -> crate::Result<()> {
+        let offset = Offset::from(u64::MAX);
+        assert_eq!(offset.to_string(), "18446744073709551615");
+        let offset_str = offset.to_string();
+        assert_eq!(Offset::from_str_u64(&offset_str)?, offset);
+        assert_eq!(Offset::try_from_u64(offset.as_str())?, offset);
+
+        let offset = Offset::from(u32::MAX);
+        assert_eq!(offset.to_string(), "4294967295");
+        let offset_str = offset.to_string();
+        assert_eq!(Offset::from_str_u32(&offset_str)?, offset);
+        assert_eq!(Offset::try_from_u32(offset.as_str())?, offset);
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_keeps_the_width_of_its_underlying_value() {
+        let offset = Offset::from(512u32);
+        assert_eq!(offset.to_u32(), Some(512));
+        assert_eq!(offset.to_u64(), None);
+        assert_eq!(offset.as_u64(), 512);
+
+        let offset = Offset::from(512u64);
+        assert_eq!(offset.to_u32(), None);
+        assert_eq!(offset.to_u64(), Some(512));
+        assert_eq!(offset.into_unsigned_int(), UnsignedInt::from(512u64));
+    }
+
+    #[test]
+    fn offset_can_be_converted_to_and_from_sectors() {
+        let offset = Offset::from_sectors(2048, 512).unwrap();
+        assert_eq!(offset.as_u64(), 1_048_576);
+        assert_eq!(offset.to_sectors(4096), Some(256));
+        assert_eq!(offset.to_sectors(0), None);
+        assert!(offset.is_aligned_to(4096));
+        assert!(!offset.is_aligned_to(0));
+
+        assert_eq!(Offset::from_sectors(u64::MAX, 512), None);
+    }
+
+    #[test]
+    fn offset_arithmetic_checks_for_overflows() {
+        let offset = Offset::from(1024u64);
+        let size = Size::from(512u64);
+
+        assert_eq!(offset.checked_add(&size), Some(Offset::from(1536u64)));
+        assert_eq!(offset.checked_sub(&size), Some(Offset::from(512u64)));
+        assert_eq!(Offset::from(256u64).checked_sub(&size), None);
+        assert_eq!(Offset::from(u64::MAX).checked_add(&size), None);
+
+        let start = Offset::from(512u64);
+        assert_eq!(
+            offset.checked_distance_from(&start),
+            Some(Size::from(512u64))
+        );
+        assert_eq!(start.checked_distance_from(&offset), None);
+    }
+
+    #[test]
+    fn offset_can_be_formatted_and_parsed_in_human_readable_form() -> crate::Result<()> {
+        let offset = Offset::from_human_str("1MiB")?;
+        assert_eq!(offset.as_u64(), 1_048_576);
+        assert_eq!(offset.to_human_string(UnitSystem::Iec), "1 MiB");
+
+        Ok(())
+    }
+}
@@ -10,6 +10,8 @@
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
 
+use crate::core::num;
+use crate::core::num::UnitSystem;
 use crate::core::num::UnsignedInt;
 
 /// Size of a device, partition, etc.
@@ -73,6 +75,68 @@ pub fn to_u64(&self) -> Option<u64> {
     pub fn to_u32(&self) -> Option<u32> {
         self.0.to_u32()
     }
+
+    /// Adds `other` to this `Size`. Returns `None` on overflow.
+    pub fn checked_add(&self, other: &Size) -> Option<Size> {
+        self.as_u64().checked_add(other.as_u64()).map(Size::from)
+    }
+
+    /// Subtracts `other` from this `Size`. Returns `None` if `other` is larger.
+    pub fn checked_sub(&self, other: &Size) -> Option<Size> {
+        self.as_u64().checked_sub(other.as_u64()).map(Size::from)
+    }
+
+    /// Multiplies this `Size` by `factor`. Returns `None` on overflow.
+    pub fn checked_mul(&self, factor: u64) -> Option<Size> {
+        self.as_u64().checked_mul(factor).map(Size::from)
+    }
+
+    /// Divides this `Size` by `divisor`, rounding down. Returns `None` if `divisor` is zero.
+    pub fn checked_div(&self, divisor: u64) -> Option<Size> {
+        self.as_u64().checked_div(divisor).map(Size::from)
+    }
+
+    /// Returns this `Size` in bytes, as a `u64` whatever the width of its underlying value.
+    pub fn as_u64(&self) -> u64 {
+        self.0.as_u64()
+    }
+
+    /// Creates a `Size` from a number of `sectors` of `sector_size` bytes. Returns `None` if the
+    /// result overflows a `u64`.
+    pub fn from_sectors(sectors: u64, sector_size: u64) -> Option<Size> {
+        sectors.checked_mul(sector_size).map(Size::from)
+    }
+
+    /// Converts this `Size` to a number of sectors of `sector_size` bytes, rounded down. Returns
+    /// `None` if `sector_size` is zero.
+    pub fn to_sectors(&self, sector_size: u64) -> Option<u64> {
+        self.as_u64().checked_div(sector_size)
+    }
+
+    /// Returns `true` if this `Size` is a multiple of `sector_size`.
+    pub fn is_aligned_to(&self, sector_size: u64) -> bool {
+        sector_size != 0 && self.as_u64() % sector_size == 0
+    }
+
+    /// Formats this `Size` with the largest unit of `units` for which the value is at least `1`,
+    /// rounded to one decimal place (e.g. `10 GiB`, `1.5 MB`).
+    pub fn to_human_string(&self, units: UnitSystem) -> String {
+        num::to_human_string(self.as_u64(), units)
+    }
+
+    /// Parses a `Size` from a human-readable string, following the conventions of `lsblk` and
+    /// `sfdisk`:
+    /// - a number without suffix, or with a `B` suffix is a number of bytes (e.g. `4096`),
+    /// - single letter suffixes `K`, `M`, `G`, `T`, `P`, `E` and `iB` suffixes (`KiB`, `MiB`,
+    ///   etc.) are powers of 1024 (e.g. `512M`, `10 GiB`),
+    /// - `B` suffixes (`KB`, `MB`, etc.) are powers of 1000 (e.g. `10GB`).
+    ///
+    /// Suffixes are case-insensitive, and numbers may have a fractional part (e.g. `1.5G`).
+    pub fn from_human_str(s: &str) -> Result<Size, ParserError> {
+        num::parse_human_size(s)
+            .map(Size::from)
+            .map_err(ParserError::Size)
+    }
 }
 
 impl AsRef<Size> for Size {
@@ -122,3 +186,53 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn size_can_be_converted_to_and_from_sectors() {
+        let size = Size::from_sectors(2048, 512).unwrap();
+        assert_eq!(size.as_u64(), 1_048_576);
+        assert_eq!(size.to_sectors(4096), Some(256));
+        assert_eq!(size.to_sectors(0), None);
+        assert!(size.is_aligned_to(4096));
+
+        let size = Size::from(1000u32);
+        assert_eq!(size.to_sectors(512), Some(1));
+        assert!(!size.is_aligned_to(512));
+
+        assert_eq!(Size::from_sectors(u64::MAX, 512), None);
+    }
+
+    #[test]
+    fn size_arithmetic_checks_for_overflows() {
+        let size = Size::from(512u32);
+        let other = Size::from(1024u64);
+
+        assert_eq!(size.checked_add(&other), Some(Size::from(1536u64)));
+        assert_eq!(other.checked_sub(&size), Some(Size::from(512u64)));
+        assert_eq!(size.checked_sub(&other), None);
+        assert_eq!(Size::from(u64::MAX).checked_mul(2), None);
+        assert_eq!(other.checked_div(0), None);
+    }
+
+    #[test]
+    fn size_can_be_formatted_and_parsed_in_human_readable_form() -> crate::Result<()> {
+        let size = Size::from_human_str("512M")?;
+        assert_eq!(size.as_u64(), 536_870_912);
+        assert_eq!(size.to_human_string(UnitSystem::Iec), "512 MiB");
+        assert_eq!(size.to_human_string(UnitSystem::Si), "536.9 MB");
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported unit")]
+    fn size_can_not_parse_an_unsupported_unit() {
+        let _ = Size::from_human_str("512X").unwrap();
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Formats of the identifiers `libblkid` reports as UUIDs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum UuidFormat {
+    /// [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) UUID (e.g.
+    /// `ac4f36bf-191b-4fb0-b808-6d7fc9fc88be`).
+    Rfc4122,
+    /// `MBR` disk identifier (e.g. `8f8378c0`).
+    DosDiskId,
+    /// `MBR` partition identifier, a disk identifier followed by a partition number (e.g.
+    /// `8f8378c0-01`).
+    DosPartitionId,
+    /// `FAT` or `exFAT` volume serial number (e.g. `1234-ABCD`).
+    VolumeSerial,
+    /// `NTFS` volume serial number (e.g. `0123456789ABCDEF`).
+    NtfsSerial,
+    /// `ISO 9660` volume creation timestamp (e.g. `2023-05-01-12-34-56-00`).
+    IsoTimestamp,
+    /// `LVM` physical volume identifier (e.g. `Wf3yvK-Ar5b-qvUh-5mEc-q7rT-3C0U-Yx0dsV`).
+    LvmId,
+    /// Identifier in any other format, kept verbatim (e.g. a `ZFS` pool's decimal GUID
+    /// `6478298591378196346`).
+    Other,
+}
+
+impl UuidFormat {
+    /// View this `UuidFormat` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Rfc4122 => "rfc4122",
+            Self::DosDiskId => "dos-disk-id",
+            Self::DosPartitionId => "dos-partition-id",
+            Self::VolumeSerial => "volume-serial",
+            Self::NtfsSerial => "ntfs-serial",
+            Self::IsoTimestamp => "iso-timestamp",
+            Self::LvmId => "lvm-id",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl AsRef<str> for UuidFormat {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UuidFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
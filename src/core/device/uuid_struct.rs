@@ -4,21 +4,281 @@
 // From dependency library
 
 // From standard library
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 // From this library
+use crate::core::device::UuidFormat;
+use crate::core::device::UuidVariant;
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
+use crate::core::partition::FileSystem;
 
 /// A device's UUID.
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Uuid(String);
+///
+/// Besides [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122) UUIDs, `Uuid` accepts the
+/// identifiers `libblkid` reports in their stead (see [`UuidFormat`]). Values are stored in a
+/// canonical form:
+/// - RFC 4122 UUIDs, `MBR` disk and partition identifiers in lowercase, with dashes,
+/// - `FAT`/`exFAT` and `NTFS` serial numbers in uppercase, as printed by `blkid`.
+///
+/// `LVM` identifiers are the exception, their alphabet being case-sensitive, only dashes are
+/// normalised. So are identifiers in an unrecognised format (e.g. a `ZFS` pool's decimal GUID),
+/// kept verbatim as [`UuidFormat::Other`].
+///
+/// Some formats are ambiguous out of context (e.g. `HFS+` and `NTFS` both use 16 hexadecimal
+/// digits), [`Uuid::for_file_system`] uses the type of file system to tell them apart.
+///
+/// Comparisons ignore the format of a `Uuid`, and its separators. Values made only of
+/// hexadecimal digits are compared by the bytes they encode, i.e. regardless of case; other
+/// values are compared verbatim. Thus, a `Uuid` parsed out of context is equal to the same
+/// identifier converted by [`Uuid::for_file_system`].
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::device::{Uuid, UuidFormat};
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let uuid: Uuid = "AC4F36BF191B4FB0B8086D7FC9FC88BE".parse()?;
+///     let other: Uuid = "ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?;
+///
+///     assert_eq!(uuid, other);
+///     assert_eq!(uuid.format(), UuidFormat::Rfc4122);
+///     assert_eq!(uuid.version(), Some(4));
+///
+///     let serial: Uuid = "1234-abcd".parse()?;
+///     assert_eq!(serial.as_str(), "1234-ABCD");
+///     assert_eq!(serial.format(), UuidFormat::VolumeSerial);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Uuid {
+    value: String,
+    format: UuidFormat,
+}
 
 impl Uuid {
-    /// View this `Uuid` as a UTF-8 `str`.
+    /// View this `Uuid` as a UTF-8 `str`, in canonical form.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// Returns the format of this `Uuid`.
+    pub fn format(&self) -> UuidFormat {
+        self.format
+    }
+
+    /// Returns the 16 bytes of an RFC 4122 UUID, `None` for other formats.
+    pub fn to_bytes(&self) -> Option<[u8; 16]> {
+        if self.format != UuidFormat::Rfc4122 {
+            return None;
+        }
+
+        let digits: Vec<u8> = self
+            .value
+            .chars()
+            .filter_map(|c| c.to_digit(16))
+            .map(|d| d as u8)
+            .collect();
+
+        let mut bytes = [0u8; 16];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+            *byte = (pair[0] << 4) | pair[1];
+        }
+
+        Some(bytes)
+    }
+
+    /// Returns the version number of an RFC 4122 UUID (e.g. `4` for a randomly generated UUID),
+    /// `None` for other formats.
+    pub fn version(&self) -> Option<u8> {
+        self.to_bytes().map(|bytes| bytes[6] >> 4)
+    }
+
+    /// Returns the variant of an RFC 4122 UUID, `None` for other formats.
+    pub fn variant(&self) -> Option<UuidVariant> {
+        self.to_bytes()
+            .map(|bytes| UuidVariant::from_byte(bytes[8]))
+    }
+
+    /// Returns `true` if this `Uuid` is the nil UUID (all bits set to zero).
+    pub fn is_nil(&self) -> bool {
+        self.to_bytes()
+            .map(|bytes| bytes == [0u8; 16])
+            .unwrap_or(false)
+    }
+
+    /// Converts the `UUID` value `libblkid` reports for a `file_system` into a `Uuid`.
+    ///
+    /// Unlike [`Uuid::try_from`], only assigns a format specific to a type of file system (e.g.
+    /// [`UuidFormat::NtfsSerial`]) to identifiers read from that type of file system. Any other
+    /// identifier not in RFC 4122 form is kept verbatim, as [`UuidFormat::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsblkid::core::device::{Uuid, UuidFormat};
+    /// use rsblkid::core::partition::FileSystem;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let ntfs = Uuid::for_file_system(b"0123456789ABCDEF", &FileSystem::NTFS)?;
+    ///     assert_eq!(ntfs.format(), UuidFormat::NtfsSerial);
+    ///
+    ///     let hfs = Uuid::for_file_system(b"0123456789abcdef", &FileSystem::HFSPlus)?;
+    ///     assert_eq!(hfs.as_str(), "0123456789abcdef");
+    ///     assert_eq!(hfs.format(), UuidFormat::Other);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn for_file_system<T>(bytes: T, file_system: &FileSystem) -> Result<Uuid, ConversionError>
+    where
+        T: AsRef<[u8]>,
+    {
+        let trimmed = Self::decode(bytes.as_ref().to_vec())?;
+
+        Self::normalize(&trimmed, Some(file_system))
+            .map(|(format, value)| Self { value, format })
+            .ok_or_else(|| ConversionError::Uuid(String::from("empty UUID")))
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the key `Uuid`s are compared by. Separators are ignored, and
+    /// values made of an even number of hexadecimal digits are converted to the bytes they
+    /// encode.
+    fn canonical_bytes(&self) -> (bool, Vec<u8>) {
+        let digits: Vec<u8> = self.value.bytes().filter(|&byte| byte != b'-').collect();
+        let nibbles: Option<Vec<u8>> = digits
+            .iter()
+            .map(|&byte| char::from(byte).to_digit(16).map(|digit| digit as u8))
+            .collect();
+
+        match nibbles {
+            Some(nibbles) if nibbles.len() % 2 == 0 => {
+                let bytes = nibbles
+                    .chunks_exact(2)
+                    .map(|pair| (pair[0] << 4) | pair[1])
+                    .collect();
+
+                (true, bytes)
+            }
+            _otherwise => (false, digits),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: converts `bytes` to a string, minus the padding added by `libblkid`.
+    fn decode(bytes: Vec<u8>) -> Result<String, ConversionError> {
+        let string = String::from_utf8(bytes).map_err(|e| {
+            ConversionError::Uuid(format!("bytes to UTF-8 string conversion error. {:?}", e))
+        })?;
+
+        // `libblkid` pads some identifiers with NUL characters.
+        let trimmed = string.trim_end_matches('\0').trim().to_owned();
+
+        Ok(trimmed)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns `true` if identifiers in `format` may come from `file_system`.
+    fn is_format_of(format: UuidFormat, file_system: Option<&FileSystem>) -> bool {
+        let is_lvm = matches!(file_system, Some(FileSystem::LVM1 | FileSystem::LVM2));
+
+        match (format, file_system) {
+            // Out of context, any format is plausible.
+            (_, None) => true,
+            (UuidFormat::Rfc4122, Some(_)) => !is_lvm,
+            (UuidFormat::DosDiskId | UuidFormat::DosPartitionId, Some(_)) => false,
+            (UuidFormat::VolumeSerial, Some(fs)) => {
+                matches!(fs, FileSystem::VFAT | FileSystem::ExFAT)
+            }
+            (UuidFormat::NtfsSerial, Some(fs)) => *fs == FileSystem::NTFS,
+            (UuidFormat::IsoTimestamp, Some(fs)) => {
+                matches!(fs, FileSystem::Iso9660 | FileSystem::UDF)
+            }
+            (UuidFormat::LvmId, Some(_)) => is_lvm,
+            (UuidFormat::Other, Some(_)) => true,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: identifies the format of `s`, and converts it to its canonical form.
+    /// Returns `None` if `s` is empty.
+    fn normalize(s: &str, file_system: Option<&FileSystem>) -> Option<(UuidFormat, String)> {
+        if s.is_empty() {
+            return None;
+        }
+
+        let is_format = |format: UuidFormat| Self::is_format_of(format, file_system);
+
+        let groups: Vec<&str> = s.split('-').collect();
+        let lengths: Vec<usize> = groups.iter().map(|group| group.len()).collect();
+
+        let is_hex = groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()));
+        let is_numeric = groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_digit()));
+        let is_alphanumeric = groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        let joined = groups.concat();
+
+        match lengths.as_slice() {
+            [8, 4, 4, 4, 12] | [32] if is_hex && is_format(UuidFormat::Rfc4122) => {
+                let hex = joined.to_lowercase();
+                let canonical = format!(
+                    "{}-{}-{}-{}-{}",
+                    &hex[..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..]
+                );
+
+                Some((UuidFormat::Rfc4122, canonical))
+            }
+            [8] if is_hex && is_format(UuidFormat::DosDiskId) => {
+                Some((UuidFormat::DosDiskId, joined.to_lowercase()))
+            }
+            [8, 2] if is_hex && is_format(UuidFormat::DosPartitionId) => {
+                Some((UuidFormat::DosPartitionId, s.to_lowercase()))
+            }
+            [4, 4] if is_hex && is_format(UuidFormat::VolumeSerial) => {
+                Some((UuidFormat::VolumeSerial, s.to_uppercase()))
+            }
+            [16] if is_hex && is_format(UuidFormat::NtfsSerial) => {
+                Some((UuidFormat::NtfsSerial, joined.to_uppercase()))
+            }
+            [4, 2, 2, 2, 2, 2, 2] if is_numeric && is_format(UuidFormat::IsoTimestamp) => {
+                Some((UuidFormat::IsoTimestamp, s.to_owned()))
+            }
+            [6, 4, 4, 4, 4, 4, 6] | [32] if is_alphanumeric && is_format(UuidFormat::LvmId) => {
+                let canonical = format!(
+                    "{}-{}-{}-{}-{}-{}-{}",
+                    &joined[..6],
+                    &joined[6..10],
+                    &joined[10..14],
+                    &joined[14..18],
+                    &joined[18..22],
+                    &joined[22..26],
+                    &joined[26..]
+                );
+
+                Some((UuidFormat::LvmId, canonical))
+            }
+            // e.g. ZFS decimal GUIDs, VMFS `8-8-4-12` UUIDs, HFS+ 64-bit identifiers, etc.
+            _otherwise => Some((UuidFormat::Other, s.to_owned())),
+        }
     }
 }
 
@@ -32,13 +292,39 @@ fn as_ref(&self) -> &Uuid {
 impl AsRef<str> for Uuid {
     #[inline]
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.value
+    }
+}
+
+impl PartialEq for Uuid {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical_bytes() == other.canonical_bytes()
+    }
+}
+
+impl Eq for Uuid {}
+
+impl Hash for Uuid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bytes().hash(state);
+    }
+}
+
+impl PartialOrd for Uuid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Uuid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_bytes().cmp(&other.canonical_bytes())
     }
 }
 
 impl fmt::Display for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", &self.0)
+        write!(f, "{}", &self.value)
     }
 }
 
@@ -55,9 +341,11 @@ impl TryFrom<Vec<u8>> for Uuid {
     type Error = ConversionError;
 
     fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-        String::from_utf8(bytes).map(Uuid).map_err(|e| {
-            ConversionError::Uuid(format!("bytes to UTF-8 string conversion error. {:?}", e))
-        })
+        let trimmed = Self::decode(bytes)?;
+
+        Self::normalize(&trimmed, None)
+            .map(|(format, value)| Self { value, format })
+            .ok_or_else(|| ConversionError::Uuid(String::from("empty UUID")))
     }
 }
 
@@ -84,9 +372,11 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             Ok(trimmed)
         }?;
 
-        let uuid = Self(parsed.trim().to_lowercase());
+        let parsed = parsed.trim();
 
-        Ok(uuid)
+        Self::normalize(parsed, None)
+            .map(|(format, value)| Self { value, format })
+            .ok_or_else(|| ParserError::Uuid(format!("empty UUID in: {:?}", s)))
     }
 }
 
@@ -120,10 +410,179 @@ fn uuid_can_not_convert_invalid_bytes_into_a_uuid() {
     fn uuid_can_convert_valid_bytes_into_a_uuid() -> crate::Result<()> {
         let bytes: Vec<u8> = b"c12a7328-f81f-11d2-ba4b-00a0c93ec93b".to_vec();
         let actual = Uuid::try_from(bytes)?;
-        let uuid = String::from("c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
-        let expected = Uuid(uuid);
+        let expected = Uuid {
+            value: String::from("c12a7328-f81f-11d2-ba4b-00a0c93ec93b"),
+            format: UuidFormat::Rfc4122,
+        };
         assert_eq!(actual, expected);
 
         Ok(())
     }
+
+    #[test]
+    #[should_panic(expected = "empty UUID")]
+    fn uuid_can_not_parse_an_empty_uuid_string() {
+        let _: Uuid = "''".parse().unwrap();
+    }
+
+    #[test]
+    fn uuid_can_parse_an_unrecognised_uuid_string() -> crate::Result<()> {
+        let uuid: Uuid = "c12a7328-f81f-11d2".parse()?;
+        assert_eq!(uuid.as_str(), "c12a7328-f81f-11d2");
+        assert_eq!(uuid.format(), UuidFormat::Other);
+        assert_eq!(uuid.to_bytes(), None);
+
+        // ZFS pool GUID
+        let zfs = Uuid::try_from(b"6478298591378196346".as_slice())?;
+        assert_eq!(zfs.as_str(), "6478298591378196346");
+        assert_eq!(zfs.format(), UuidFormat::Other);
+
+        // VMFS UUID
+        let vmfs = Uuid::try_from(b"4b6c4ab8-48bed8e4-bd5c-0050568d4e2b".as_slice())?;
+        assert_eq!(vmfs.as_str(), "4b6c4ab8-48bed8e4-bd5c-0050568d4e2b");
+        assert_eq!(vmfs.format(), UuidFormat::Other);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_can_classify_an_identifier_by_file_system() -> crate::Result<()> {
+        let ntfs = Uuid::for_file_system(b"0123456789abcdef", &FileSystem::NTFS)?;
+        assert_eq!(ntfs.as_str(), "0123456789ABCDEF");
+        assert_eq!(ntfs.format(), UuidFormat::NtfsSerial);
+
+        let hfs = Uuid::for_file_system(b"d6d8e2c23b7e9a42", &FileSystem::HFSPlus)?;
+        assert_eq!(hfs.as_str(), "d6d8e2c23b7e9a42");
+        assert_eq!(hfs.format(), UuidFormat::Other);
+
+        let befs = Uuid::for_file_system(b"e8cb3a6bf4d1ef33", &FileSystem::BeFS)?;
+        assert_eq!(befs.format(), UuidFormat::Other);
+
+        // All hexadecimal LVM identifier
+        let lvm = Uuid::for_file_system(b"0123456789abcdef0123456789abcdef", &FileSystem::LVM2)?;
+        assert_eq!(lvm.as_str(), "012345-6789-abcd-ef01-2345-6789-abcdef");
+        assert_eq!(lvm.format(), UuidFormat::LvmId);
+
+        let ext4 = Uuid::for_file_system(b"0123456789abcdef0123456789abcdef", &FileSystem::Ext4)?;
+        assert_eq!(ext4.as_str(), "01234567-89ab-cdef-0123-456789abcdef");
+        assert_eq!(ext4.format(), UuidFormat::Rfc4122);
+
+        let vfat = Uuid::for_file_system(b"1234-abcd", &FileSystem::VFAT)?;
+        assert_eq!(vfat.format(), UuidFormat::VolumeSerial);
+
+        let iso = Uuid::for_file_system(b"2023-05-01-12-34-56-00", &FileSystem::Iso9660)?;
+        assert_eq!(iso.format(), UuidFormat::IsoTimestamp);
+
+        let zfs = Uuid::for_file_system(b"6478298591378196346\0\0", &FileSystem::ZFS)?;
+        assert_eq!(zfs.as_str(), "6478298591378196346");
+        assert_eq!(zfs.format(), UuidFormat::Other);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_can_parse_an_rfc4122_uuid_string() -> crate::Result<()> {
+        let uuid: Uuid = "C12A7328-F81F-11D2-BA4B-00A0C93EC93B".parse()?;
+
+        assert_eq!(uuid.as_str(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+        assert_eq!(uuid.format(), UuidFormat::Rfc4122);
+        assert_eq!(uuid.version(), Some(1));
+        assert_eq!(uuid.variant(), Some(UuidVariant::Rfc4122));
+
+        let expected = [
+            0xc1, 0x2a, 0x73, 0x28, 0xf8, 0x1f, 0x11, 0xd2, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e,
+            0xc9, 0x3b,
+        ];
+        assert_eq!(uuid.to_bytes(), Some(expected));
+
+        let nil: Uuid = "00000000000000000000000000000000".parse()?;
+        assert!(nil.is_nil());
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_can_parse_file_system_serial_numbers() -> crate::Result<()> {
+        let vfat: Uuid = "\"1234-abcd\"".parse()?;
+        assert_eq!(vfat.as_str(), "1234-ABCD");
+        assert_eq!(vfat.format(), UuidFormat::VolumeSerial);
+        assert_eq!(vfat.to_bytes(), None);
+        assert_eq!(vfat.version(), None);
+
+        let ntfs: Uuid = "0123456789abcdef".parse()?;
+        assert_eq!(ntfs.as_str(), "0123456789ABCDEF");
+        assert_eq!(ntfs.format(), UuidFormat::NtfsSerial);
+
+        let iso: Uuid = "2023-05-01-12-34-56-00".parse()?;
+        assert_eq!(iso.format(), UuidFormat::IsoTimestamp);
+
+        let lvm: Uuid = "Wf3yvKAr5bqvUh5mEcq7rT3C0UYx0dsV".parse()?;
+        assert_eq!(lvm.as_str(), "Wf3yvK-Ar5b-qvUh-5mEc-q7rT-3C0U-Yx0dsV");
+        assert_eq!(lvm.format(), UuidFormat::LvmId);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_can_parse_mbr_identifiers() -> crate::Result<()> {
+        let disk_id: Uuid = "8F8378C0".parse()?;
+        assert_eq!(disk_id.as_str(), "8f8378c0");
+        assert_eq!(disk_id.format(), UuidFormat::DosDiskId);
+
+        let partition_id = Uuid::try_from(b"8f8378c0-01".as_slice())?;
+        assert_eq!(partition_id.format(), UuidFormat::DosPartitionId);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_comparisons_are_normalised_across_formats() -> crate::Result<()> {
+        let uuid: Uuid = "AC4F36BF191B4FB0B8086D7FC9FC88BE".parse()?;
+        let other: Uuid = "ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?;
+        assert_eq!(uuid, other);
+
+        let serial: Uuid = "1234-ABCD".parse()?;
+        let other: Uuid = "1234-abcd".parse()?;
+        assert_eq!(serial, other);
+
+        // LVM identifiers are case-sensitive.
+        let lvm: Uuid = "Wf3yvK-Ar5b-qvUh-5mEc-q7rT-3C0U-Yx0dsV".parse()?;
+        let other: Uuid = "wf3yvk-ar5b-qvuh-5mec-q7rt-3c0u-yx0dsv".parse()?;
+        assert_ne!(lvm, other);
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_comparisons_ignore_the_context_of_a_conversion() -> crate::Result<()> {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |uuid: &Uuid| {
+            let mut hasher = DefaultHasher::new();
+            uuid.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // `HFS+` identifier, read as an `NTFS` serial number out of context.
+        let hfs = Uuid::for_file_system(b"d6d8e2c23b7e9a42", &FileSystem::HFSPlus)?;
+        let other: Uuid = "d6d8e2c23b7e9a42".parse()?;
+        assert_ne!(hfs.format(), other.format());
+        assert_eq!(hfs, other);
+        assert_eq!(hash(&hfs), hash(&other));
+        assert_eq!(hfs.cmp(&other), Ordering::Equal);
+
+        // All hexadecimal LVM identifier, read as an RFC 4122 UUID out of context.
+        let lvm = Uuid::for_file_system(b"0123456789abcdef0123456789abcdef", &FileSystem::LVM2)?;
+        let other = Uuid::try_from(b"0123456789abcdef0123456789abcdef".as_slice())?;
+        assert_ne!(lvm.format(), other.format());
+        assert_eq!(lvm, other);
+        assert_eq!(hash(&lvm), hash(&other));
+        assert_eq!(lvm.cmp(&other), Ordering::Equal);
+
+        let ntfs: Uuid = "0123456789ABCDEF".parse()?;
+        let other: Uuid = "0123456789ABCDF0".parse()?;
+        assert!(ntfs < other);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,52 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Variants of an [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.1.1) UUID, i.e.
+/// the layout of its remaining bits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum UuidVariant {
+    /// Reserved, NCS backward compatibility.
+    Ncs,
+    /// Layout specified in RFC 4122.
+    Rfc4122,
+    /// Reserved, Microsoft Corporation backward compatibility (e.g. GUIDs in old COM objects).
+    Microsoft,
+    /// Reserved for future definition.
+    Future,
+}
+
+impl UuidVariant {
+    /// Returns the `UuidVariant` encoded in the most significant bits of a UUID's 9th byte.
+    pub fn from_byte(byte: u8) -> UuidVariant {
+        match byte {
+            0x00..=0x7f => Self::Ncs,
+            0x80..=0xbf => Self::Rfc4122,
+            0xc0..=0xdf => Self::Microsoft,
+            0xe0..=0xff => Self::Future,
+        }
+    }
+
+    /// View this `UuidVariant` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ncs => "ncs",
+            Self::Rfc4122 => "rfc4122",
+            Self::Microsoft => "microsoft",
+            Self::Future => "future",
+        }
+    }
+}
+
+impl fmt::Display for UuidVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
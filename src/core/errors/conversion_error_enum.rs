@@ -16,6 +16,10 @@ pub enum ConversionError {
     #[error("{0}")]
     Bool(String),
 
+    /// Error while converting bytes into a [`BsdFsType`](crate::core::partition::BsdFsType).
+    #[error("{0}")]
+    BsdFsType(String),
+
     /// Error while converting a [`CStr`](std::ffi::CStr).
     #[error("{0}")]
     CStr(String),
@@ -68,6 +72,14 @@ pub enum ConversionError {
     #[error("{0}")]
     PartitionType(String),
 
+    /// Error while converting bytes into a [`SgiType`](crate::core::partition::SgiType).
+    #[error("{0}")]
+    SgiType(String),
+
+    /// Error while converting bytes into a [`SunTag`](crate::core::partition::SunTag).
+    #[error("{0}")]
+    SunTag(String),
+
     /// Error while converting bytes into a [`Tag`](crate::core::device::Tag).
     #[error("{0}")]
     Tag(String),
@@ -0,0 +1,49 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+use std::io;
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::errors::EncodeError;
+use crate::core::errors::ErrorKind;
+use crate::core::errors::ParserError;
+
+/// `dev_disk` module runtime errors.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DevDiskError {
+    /// Error while converting a symlink name to a [`Tag`](crate::core::device::Tag).
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+
+    /// Error while decoding a symlink name.
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+
+    /// The names of symlinks of a given kind do not match a [`Tag`](crate::core::device::Tag).
+    #[error("{0}")]
+    NoMatchingTag(String),
+
+    /// I/O runtime error.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// Error while parsing a string.
+    #[error(transparent)]
+    Parser(#[from] ParserError),
+}
+
+impl DevDiskError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(e) => ErrorKind::from(e),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+use std::io;
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::errors::ErrorKind;
+
+/// Errors caused by the state of a device, or reported by a `libblkid` function.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DeviceError {
+    /// The user does not have the permission to access a device.
+    #[error("permission denied: {}", .path.display())]
+    PermissionDenied {
+        /// Path to the device.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+
+    /// A device does not exist.
+    #[error("no such device: {}", .path.display())]
+    NotFound {
+        /// Path to the device.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+
+    /// A file is not a block device.
+    #[error("not a block device: {}", .path.display())]
+    NotABlockDevice {
+        /// Path to the file.
+        path: PathBuf,
+    },
+
+    /// A device is in use.
+    #[error("device busy: {}", .path.display())]
+    DeviceBusy {
+        /// Path to the device.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+
+    /// A device is read-only, or was opened in read-only mode.
+    #[error("read-only device: {}", .path.display())]
+    ReadOnly {
+        /// Path to the device.
+        path: PathBuf,
+        /// Underlying I/O error, if any.
+        #[source]
+        source: Option<io::Error>,
+    },
+
+    /// An operation is not supported.
+    #[error("unsupported operation: {0}")]
+    Unsupported(String),
+
+    /// A `libblkid` function returned an error code.
+    #[error("libblkid::{function} failed with error code {code}")]
+    LibblkidFailure {
+        /// Name of the `libblkid` function.
+        function: &'static str,
+        /// Code returned by the function.
+        code: i32,
+        /// Value of `errno` after the function call, if set.
+        errno: Option<i32>,
+        /// I/O error matching `errno`, if set.
+        #[source]
+        source: Option<io::Error>,
+    },
+
+    /// Error while performing Input/Output operations on a device.
+    #[error("I/O error on {}", .path.display())]
+    Io {
+        /// Path to the device.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl DeviceError {
+    /// Converts an I/O `error`, which occurred while accessing the device at `path`, to a
+    /// `DeviceError`.
+    pub fn from_io<T>(path: T, error: io::Error) -> DeviceError
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+
+        match ErrorKind::from(&error) {
+            ErrorKind::PermissionDenied => Self::PermissionDenied {
+                path,
+                source: error,
+            },
+            ErrorKind::NotFound => Self::NotFound {
+                path,
+                source: error,
+            },
+            ErrorKind::DeviceBusy => Self::DeviceBusy {
+                path,
+                source: error,
+            },
+            ErrorKind::ReadOnly => Self::ReadOnly {
+                path,
+                source: Some(error),
+            },
+            _otherwise => Self::Io {
+                path,
+                source: error,
+            },
+        }
+    }
+
+    /// Creates a `DeviceError` for a `libblkid` `function` returning the error `code`.
+    ///
+    /// **Note:** `libblkid` functions either return a negative `errno` value, or set `errno` and
+    /// return `-1`. Call `ffi_utils::clear_errno` right before
+    /// `function`, and this function right after it, before `errno` is overwritten.
+    pub(crate) fn libblkid_failure(function: &'static str, code: i32) -> DeviceError {
+        let errno = if code < -1 {
+            Some(-code)
+        } else {
+            io::Error::last_os_error()
+                .raw_os_error()
+                .filter(|&errno| errno != 0)
+        };
+        let source = errno.map(io::Error::from_raw_os_error);
+
+        Self::LibblkidFailure {
+            function,
+            code,
+            errno,
+            source,
+        }
+    }
+
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::PermissionDenied { .. } => ErrorKind::PermissionDenied,
+            Self::NotFound { .. } => ErrorKind::NotFound,
+            Self::NotABlockDevice { .. } => ErrorKind::NotABlockDevice,
+            Self::DeviceBusy { .. } => ErrorKind::DeviceBusy,
+            Self::ReadOnly { .. } => ErrorKind::ReadOnly,
+            Self::Unsupported(_) => ErrorKind::Unsupported,
+            Self::LibblkidFailure { errno, .. } => match errno.map(ErrorKind::from_errno) {
+                Some(ErrorKind::Other) | None => ErrorKind::LibblkidFailure,
+                Some(kind) => kind,
+            },
+            Self::Io { source, .. } => ErrorKind::from(source),
+        }
+    }
+
+    /// Returns the `errno` value associated with this error, if any.
+    pub fn errno(&self) -> Option<i32> {
+        match self {
+            Self::PermissionDenied { source, .. }
+            | Self::NotFound { source, .. }
+            | Self::DeviceBusy { source, .. }
+            | Self::Io { source, .. } => source.raw_os_error(),
+            Self::ReadOnly { source, .. } => source.as_ref().and_then(|e| e.raw_os_error()),
+            Self::LibblkidFailure { errno, .. } => *errno,
+            Self::NotABlockDevice { .. } | Self::Unsupported(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::error::Error;
+
+    #[test]
+    fn device_error_can_convert_an_io_error() {
+        let error = DeviceError::from_io("/dev/vda", io::Error::from_raw_os_error(libc::EACCES));
+
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(error.errno(), Some(libc::EACCES));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn device_error_libblkid_failure_keeps_negative_errno() {
+        let error = DeviceError::libblkid_failure("blkid_do_wipe", -libc::EBUSY);
+
+        assert_eq!(error.kind(), ErrorKind::DeviceBusy);
+        assert_eq!(error.errno(), Some(libc::EBUSY));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn device_error_libblkid_failure_ignores_a_cleared_errno() {
+        // Leave a stale `errno` behind, as an unrelated call would.
+        let _ = std::fs::File::open("/dev/DUMMY_DEVICE");
+        crate::ffi_utils::clear_errno();
+
+        let error = DeviceError::libblkid_failure("blkid_probe_all", -1);
+
+        assert_eq!(error.kind(), ErrorKind::LibblkidFailure);
+        assert_eq!(error.errno(), None);
+        assert!(error.source().is_none());
+    }
+}
@@ -17,6 +17,11 @@ pub enum EncodeError {
     #[error("error converting to`CString`: {0}")]
     CStringConversion(#[from] NulError),
 
+    /// Error while decoding a string encoded by
+    /// [`encode_string`](crate::core::utils::encode::encode_string).
+    #[error("{0}")]
+    StringDecoding(String),
+
     /// Error while encoding udev-unsafe characters.
     #[error("{0}")]
     StringEncoding(String),
@@ -0,0 +1,114 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::io;
+
+// From this library
+
+/// Category of a runtime error.
+///
+/// Lets callers branch on the cause of an error, without having to match on error messages.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rsblkid::core::errors::ErrorKind;
+/// use rsblkid::probe::Probe;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     match Probe::builder().scan_device("/dev/vda").build() {
+///         Ok(probe) => { /* ... */ }
+///         Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+///             eprintln!("try again with `sudo`");
+///         }
+///         Err(e) => return Err(e.into()),
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The user does not have the permission to access a device.
+    PermissionDenied,
+    /// A device or file does not exist.
+    NotFound,
+    /// A file is not a block device.
+    NotABlockDevice,
+    /// A device is in use by another process, or by the kernel.
+    DeviceBusy,
+    /// A device is read-only, or was opened in read-only mode.
+    ReadOnly,
+    /// An operation is not supported by a device, or by the installed version of `libblkid`.
+    Unsupported,
+    /// A call to a `libblkid` function failed for an unspecified reason.
+    LibblkidFailure,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    /// Converts an `errno` value to an `ErrorKind`.
+    pub fn from_errno(errno: i32) -> ErrorKind {
+        match errno {
+            libc::EACCES | libc::EPERM => Self::PermissionDenied,
+            libc::ENOENT | libc::ENODEV | libc::ENXIO => Self::NotFound,
+            libc::ENOTBLK => Self::NotABlockDevice,
+            libc::EBUSY => Self::DeviceBusy,
+            libc::EROFS => Self::ReadOnly,
+            libc::ENOTSUP | libc::ENOSYS => Self::Unsupported,
+            _otherwise => Self::Other,
+        }
+    }
+}
+
+impl From<&io::Error> for ErrorKind {
+    fn from(error: &io::Error) -> ErrorKind {
+        match error.raw_os_error() {
+            Some(errno) => Self::from_errno(errno),
+            None => match error.kind() {
+                io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+                io::ErrorKind::NotFound => Self::NotFound,
+                io::ErrorKind::Unsupported => Self::Unsupported,
+                _otherwise => Self::Other,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn error_kind_can_convert_errno_values() {
+        assert_eq!(
+            ErrorKind::from_errno(libc::EACCES),
+            ErrorKind::PermissionDenied
+        );
+        assert_eq!(ErrorKind::from_errno(libc::ENOENT), ErrorKind::NotFound);
+        assert_eq!(
+            ErrorKind::from_errno(libc::ENOTBLK),
+            ErrorKind::NotABlockDevice
+        );
+        assert_eq!(ErrorKind::from_errno(libc::EBUSY), ErrorKind::DeviceBusy);
+        assert_eq!(ErrorKind::from_errno(libc::EROFS), ErrorKind::ReadOnly);
+        assert_eq!(ErrorKind::from_errno(libc::ENOTSUP), ErrorKind::Unsupported);
+        assert_eq!(ErrorKind::from_errno(libc::EINVAL), ErrorKind::Other);
+    }
+
+    #[test]
+    fn error_kind_can_convert_an_io_error() {
+        let error = io::Error::from_raw_os_error(libc::EROFS);
+        assert_eq!(ErrorKind::from(&error), ErrorKind::ReadOnly);
+
+        let error = io::Error::new(io::ErrorKind::NotFound, "missing");
+        assert_eq!(ErrorKind::from(&error), ErrorKind::NotFound);
+    }
+}
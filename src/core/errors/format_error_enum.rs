@@ -0,0 +1,33 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::errors::EncodeError;
+use crate::core::errors::ParserError;
+
+/// `format` module runtime errors.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum FormatError {
+    /// Error while converting a value to a [`Tag`](crate::core::device::Tag).
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+
+    /// Error while encoding udev-unsafe characters.
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+
+    /// Error while parsing a string into a [`Tag`](crate::core::device::Tag).
+    #[error(transparent)]
+    Parser(#[from] ParserError),
+
+    /// Malformed `blkid` output.
+    #[error("{0}")]
+    Syntax(String),
+}
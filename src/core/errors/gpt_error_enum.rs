@@ -0,0 +1,38 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+use std::io;
+
+// From this library
+use crate::core::errors::ErrorKind;
+
+/// `gpt` module runtime errors.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum GptError {
+    /// Invalid argument.
+    #[error("{0}")]
+    Config(String),
+
+    /// I/O runtime error.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// Error while parsing an on-disk GPT structure.
+    #[error("{0}")]
+    Parse(String),
+}
+
+impl GptError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(e) => ErrorKind::from(e),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -11,6 +11,8 @@
 use std::io;
 
 // From this library
+use crate::core::errors::DeviceError;
+use crate::core::errors::ErrorKind;
 
 /// `misc` module runtime errors.
 #[derive(Debug, Error)]
@@ -24,6 +26,10 @@ pub enum MiscError {
     #[error("error converting to`CString`: {}", .0)]
     CStringConversion(#[from] NulError),
 
+    /// Error caused by the state of a device, or reported by `libblkid`.
+    #[error(transparent)]
+    Device(#[from] DeviceError),
+
     /// I/O runtime error.
     #[error(transparent)]
     Io(#[from] io::Error),
@@ -32,3 +38,14 @@ pub enum MiscError {
     #[error("{}", .0)]
     SendUEvent(String),
 }
+
+impl MiscError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Device(e) => e.kind(),
+            Self::Io(e) => ErrorKind::from(e),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -9,11 +9,23 @@
 
 // From this library
 pub use conversion_error_enum::ConversionError;
+pub use dev_disk_error_enum::DevDiskError;
+pub use device_error_enum::DeviceError;
 pub use encode_error_enum::EncodeError;
+pub use error_kind_enum::ErrorKind;
+pub use format_error_enum::FormatError;
+pub use gpt_error_enum::GptError;
 pub use misc_error_enum::MiscError;
 pub use parser_error_enum::ParserError;
+pub use table_writer_error_enum::TableWriterError;
 
 mod conversion_error_enum;
+mod dev_disk_error_enum;
+mod device_error_enum;
 mod encode_error_enum;
+mod error_kind_enum;
+mod format_error_enum;
+mod gpt_error_enum;
 mod misc_error_enum;
 mod parser_error_enum;
+mod table_writer_error_enum;
@@ -12,10 +12,22 @@
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum ParserError {
+    /// Error while parsing a string into an [`Arch`](crate::core::partition::Arch).
+    #[error("{0}")]
+    Arch(String),
+
     /// Error while parsing a string into a [`Bool`](crate::core::num::Bool).
     #[error("{0}")]
     Bool(String),
 
+    /// Error while parsing a string into a [`BsdFsType`](crate::core::partition::BsdFsType).
+    #[error("{0}")]
+    BsdFsType(String),
+
+    /// Error while parsing a string into a [`Codepage`](crate::core::partition::Codepage).
+    #[error("{0}")]
+    Codepage(String),
+
     /// Error while parsing a string into a [`DeviceNumber`](crate::core::device::DeviceNumber).
     #[error("{0}")]
     DeviceNumber(String),
@@ -40,6 +52,10 @@ pub enum ParserError {
     #[error("{0}")]
     Label(String),
 
+    /// Error while parsing a string into a [`LinkKind`](crate::core::utils::dev_disk::LinkKind).
+    #[error("{0}")]
+    LinkKind(String),
+
     /// Error while parsing a string into a [`Name`](crate::core::device::Name).
     #[error("{0}")]
     Name(String),
@@ -70,10 +86,18 @@ pub enum ParserError {
     #[error("{0}")]
     RawBytes(String),
 
+    /// Error while parsing a string into a [`SgiType`](crate::core::partition::SgiType).
+    #[error("{0}")]
+    SgiType(String),
+
     /// Error while parsing a string into a [`Size`](crate::core::device::Size).
     #[error("{0}")]
     Size(String),
 
+    /// Error while parsing a string into a [`SunTag`](crate::core::partition::SunTag).
+    #[error("{0}")]
+    SunTag(String),
+
     /// Error while parsing a string into a [`Tag`](crate::core::device::Tag).
     #[error("{0}")]
     Tag(String),
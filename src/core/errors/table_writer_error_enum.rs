@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+use std::io;
+
+// From this library
+use crate::core::errors::ErrorKind;
+use crate::core::errors::GptError;
+
+/// `table_writer` module runtime errors.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TableWriterError {
+    /// Invalid partition, or partition table, parameters.
+    #[error("{0}")]
+    Config(String),
+
+    /// Error while reading, or writing, a GUID Partition Table.
+    #[error(transparent)]
+    Gpt(#[from] GptError),
+
+    /// I/O runtime error.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    /// Partition table, or partition, type not supported by the writer.
+    #[error("{0}")]
+    Unsupported(String),
+}
+
+impl TableWriterError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Gpt(e) => e.kind(),
+            Self::Io(e) => ErrorKind::from(e),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -9,7 +9,165 @@
 
 // From this library
 pub use bool_struct::Bool;
+pub use unit_system_enum::UnitSystem;
 pub use unsigned_int_enum::UnsignedInt;
 
 mod bool_struct;
+mod unit_system_enum;
 mod unsigned_int_enum;
+
+#[doc(hidden)]
+/// Helper function: formats a number of `bytes` with the largest unit of `units` for which the
+/// value is at least `1`, rounded to one decimal place (e.g. `1.5 GiB`).
+pub(crate) fn to_human_string(bytes: u64, units: UnitSystem) -> String {
+    let base = units.base() as u128;
+    let symbols = units.symbols();
+
+    let mut exponent = 0;
+    let mut divisor: u128 = 1;
+    while exponent < symbols.len() - 1 && bytes as u128 / divisor >= base {
+        divisor *= base;
+        exponent += 1;
+    }
+
+    if exponent == 0 {
+        return format!("{} {}", bytes, symbols[0]);
+    }
+
+    let mut tenths = (bytes as u128 * 10 + divisor / 2) / divisor;
+    // Rounding up can reach the next unit (e.g. 1023.96 KiB).
+    if tenths >= base * 10 && exponent < symbols.len() - 1 {
+        divisor *= base;
+        exponent += 1;
+        tenths = (bytes as u128 * 10 + divisor / 2) / divisor;
+    }
+
+    match tenths % 10 {
+        0 => format!("{} {}", tenths / 10, symbols[exponent]),
+        decimal => format!("{}.{} {}", tenths / 10, decimal, symbols[exponent]),
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: parses a human-readable size, following the conventions of `util-linux`
+/// tools (`lsblk`, `sfdisk`, etc.):
+/// - a number without suffix, or with a `B` suffix is a number of bytes,
+/// - single letter suffixes (`K`, `M`, `G`, `T`, `P`, `E`), and `iB` suffixes (`KiB`, `MiB`,
+///   etc.) are powers of 1024,
+/// - `B` suffixes (`KB`, `MB`, etc.) are powers of 1000.
+///
+/// Suffixes are case-insensitive, and numbers may have a fractional part (e.g. `1.5G`).
+pub(crate) fn parse_human_size(s: &str) -> Result<u64, String> {
+    let trimmed = s.trim();
+    let split_at = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split_at);
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    if integer.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        let err_msg = format!("invalid size: {:?}", s);
+        return Err(err_msg);
+    }
+
+    let suffix = suffix.trim().to_uppercase();
+    let (prefix, unit) = suffix.split_at(suffix.chars().next().map_or(0, |c| c.len_utf8()));
+    let exponent = match prefix {
+        "" | "B" if unit.is_empty() => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        "P" => 5,
+        "E" => 6,
+        _unsupported => {
+            let err_msg = format!("unsupported unit: {:?} in {:?}", suffix, s);
+            return Err(err_msg);
+        }
+    };
+    let base: u128 = match unit {
+        "" | "IB" => 1024,
+        "B" => 1000,
+        _unsupported => {
+            let err_msg = format!("unsupported unit: {:?} in {:?}", suffix, s);
+            return Err(err_msg);
+        }
+    };
+    let multiplier = base.pow(exponent);
+
+    let err_overflow = || format!("size too large: {:?}", s);
+    let integer: u128 = integer.parse().map_err(|_| err_overflow())?;
+    // Fractions of a byte are truncated, extra digits can not change the result.
+    let fraction = &fraction[..fraction.len().min(20)];
+    let fraction_bytes = if fraction.is_empty() {
+        0
+    } else {
+        let numerator: u128 = fraction.parse().map_err(|_| err_overflow())?;
+        numerator * multiplier / 10u128.pow(fraction.len() as u32)
+    };
+
+    integer
+        .checked_mul(multiplier)
+        .and_then(|bytes| bytes.checked_add(fraction_bytes))
+        .and_then(|bytes| u64::try_from(bytes).ok())
+        .ok_or_else(err_overflow)
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn to_human_string_selects_the_largest_unit() {
+        assert_eq!(to_human_string(0, UnitSystem::Iec), "0 B");
+        assert_eq!(to_human_string(512, UnitSystem::Iec), "512 B");
+        assert_eq!(to_human_string(1536, UnitSystem::Iec), "1.5 KiB");
+        assert_eq!(
+            to_human_string(10 * 1024u64.pow(3), UnitSystem::Iec),
+            "10 GiB"
+        );
+        assert_eq!(
+            to_human_string(10 * 1024u64.pow(3), UnitSystem::Si),
+            "10.7 GB"
+        );
+        assert_eq!(to_human_string(1_048_575, UnitSystem::Iec), "1 MiB");
+        assert_eq!(to_human_string(u64::MAX, UnitSystem::Iec), "16 EiB");
+    }
+
+    #[test]
+    fn parse_human_size_follows_util_linux_conventions() -> Result<(), String> {
+        assert_eq!(parse_human_size("4096")?, 4096);
+        assert_eq!(parse_human_size("512B")?, 512);
+        assert_eq!(parse_human_size("512M")?, 512 * 1024 * 1024);
+        assert_eq!(parse_human_size("512m")?, 512 * 1024 * 1024);
+        assert_eq!(parse_human_size("10 GiB")?, 10 * 1024u64.pow(3));
+        assert_eq!(parse_human_size("10GB")?, 10 * 1000u64.pow(3));
+        assert_eq!(parse_human_size("1.5K")?, 1536);
+        assert_eq!(parse_human_size("10.7 GB")?, 10_700_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_human_size_rejects_invalid_sizes() {
+        assert!(parse_human_size("").is_err());
+        assert!(parse_human_size("M").is_err());
+        assert!(parse_human_size("1.2.3M").is_err());
+        assert!(parse_human_size("12Q").is_err());
+        assert!(parse_human_size("12MiBs").is_err());
+        assert!(parse_human_size("16E").is_err());
+    }
+
+    #[test]
+    fn human_sizes_round_trip() -> Result<(), String> {
+        for units in enum_iterator::all::<UnitSystem>() {
+            let bytes = 3 * units.base().pow(4);
+            let actual = parse_human_size(&to_human_string(bytes, units))?;
+            assert_eq!(actual, bytes);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Systems of units used to express a number of bytes in a human-readable form.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Sequence)]
+#[non_exhaustive]
+pub enum UnitSystem {
+    /// Binary prefixes, powers of 1024 (e.g. `KiB`, `MiB`, `GiB`).
+    Iec,
+    /// Decimal prefixes, powers of 1000 (e.g. `kB`, `MB`, `GB`).
+    Si,
+}
+
+impl UnitSystem {
+    /// View this `UnitSystem` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Iec => "iec",
+            Self::Si => "si",
+        }
+    }
+
+    /// Returns the ratio between two consecutive units.
+    pub fn base(&self) -> u64 {
+        match self {
+            Self::Iec => 1024,
+            Self::Si => 1000,
+        }
+    }
+
+    /// Returns the symbols of the units in this system, by increasing order of magnitude.
+    pub fn symbols(&self) -> [&'static str; 7] {
+        match self {
+            Self::Iec => ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            Self::Si => ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
+
+impl AsRef<str> for UnitSystem {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
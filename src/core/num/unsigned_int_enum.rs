@@ -134,6 +134,14 @@ pub fn to_u64(&self) -> Option<u64> {
         }
     }
 
+    /// Returns the underlying value in this `UnsignedInt`, widened to a `u64` if it is a `u32`.
+    pub fn as_u64(&self) -> u64 {
+        match self {
+            Self::U32(value, _) => *value as u64,
+            Self::U64(value, _) => *value,
+        }
+    }
+
     /// Returns the underlying `u32` in this `UnsignedInt` if applicable, `None` otherwise.
     pub fn to_u32(&self) -> Option<u32> {
         match self {
@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Alignment requirements of a partition's first sector, taken from a device's
+/// [`Topology`](crate::probe::Topology).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum Alignment {
+    /// Aligned on a physical sector boundary. Below this requirement, every write to the
+    /// partition's edges triggers a read-modify-write cycle.
+    Physical,
+    /// Aligned on the device's optimal I/O size boundary (e.g. a RAID stripe).
+    Optimal,
+}
+
+impl Alignment {
+    /// View this `Alignment` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Physical => "physical",
+            Self::Optimal => "optimal",
+        }
+    }
+}
+
+impl AsRef<str> for Alignment {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
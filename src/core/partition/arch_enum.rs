@@ -0,0 +1,205 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::errors::ParserError;
+
+/// CPU architectures with dedicated partition types in the [Discoverable Partitions
+/// Specification](https://uapi-group.org/specifications/specs/discoverable_partitions_specification/).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum Arch {
+    /// Alpha
+    Alpha,
+    /// ARC
+    Arc,
+    /// ARM 32-bit
+    Arm,
+    /// AArch64
+    Arm64,
+    /// IA-64
+    Ia64,
+    /// LoongArch 64-bit
+    LoongArch64,
+    /// 32-bit MIPS big-endian
+    Mips,
+    /// 64-bit MIPS big-endian
+    Mips64,
+    /// 32-bit MIPS little-endian
+    MipsLe,
+    /// 64-bit MIPS little-endian
+    Mips64Le,
+    /// PA-RISC
+    Parisc,
+    /// 32-bit PowerPC
+    Ppc,
+    /// 64-bit PowerPC big-endian
+    Ppc64,
+    /// 64-bit PowerPC little-endian
+    Ppc64Le,
+    /// RISC-V 32-bit
+    RiscV32,
+    /// RISC-V 64-bit
+    RiscV64,
+    /// s390
+    S390,
+    /// s390x
+    S390x,
+    /// TILE-Gx
+    TileGx,
+    /// x86
+    X86,
+    /// x86-64
+    X86_64,
+}
+
+impl Arch {
+    /// Returns the architecture this library was compiled for, `None` if it has no dedicated
+    /// partition types.
+    pub fn native() -> Option<Arch> {
+        if cfg!(target_arch = "x86_64") {
+            Some(Self::X86_64)
+        } else if cfg!(target_arch = "x86") {
+            Some(Self::X86)
+        } else if cfg!(target_arch = "aarch64") {
+            Some(Self::Arm64)
+        } else if cfg!(target_arch = "arm") {
+            Some(Self::Arm)
+        } else if cfg!(target_arch = "riscv64") {
+            Some(Self::RiscV64)
+        } else if cfg!(target_arch = "riscv32") {
+            Some(Self::RiscV32)
+        } else if cfg!(target_arch = "loongarch64") {
+            Some(Self::LoongArch64)
+        } else if cfg!(target_arch = "s390x") {
+            Some(Self::S390x)
+        } else if cfg!(all(target_arch = "powerpc64", target_endian = "little")) {
+            Some(Self::Ppc64Le)
+        } else if cfg!(target_arch = "powerpc64") {
+            Some(Self::Ppc64)
+        } else if cfg!(target_arch = "powerpc") {
+            Some(Self::Ppc)
+        } else if cfg!(all(target_arch = "mips64", target_endian = "little")) {
+            Some(Self::Mips64Le)
+        } else if cfg!(target_arch = "mips64") {
+            Some(Self::Mips64)
+        } else if cfg!(all(target_arch = "mips", target_endian = "little")) {
+            Some(Self::MipsLe)
+        } else if cfg!(target_arch = "mips") {
+            Some(Self::Mips)
+        } else {
+            None
+        }
+    }
+
+    /// View this `Arch` as a UTF-8 `str`, using `systemd`'s architecture identifiers.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Alpha => "alpha",
+            Self::Arc => "arc",
+            Self::Arm => "arm",
+            Self::Arm64 => "arm64",
+            Self::Ia64 => "ia64",
+            Self::LoongArch64 => "loongarch64",
+            Self::Mips => "mips",
+            Self::Mips64 => "mips64",
+            Self::MipsLe => "mips-le",
+            Self::Mips64Le => "mips64-le",
+            Self::Parisc => "parisc",
+            Self::Ppc => "ppc",
+            Self::Ppc64 => "ppc64",
+            Self::Ppc64Le => "ppc64-le",
+            Self::RiscV32 => "riscv32",
+            Self::RiscV64 => "riscv64",
+            Self::S390 => "s390",
+            Self::S390x => "s390x",
+            Self::TileGx => "tilegx",
+            Self::X86 => "x86",
+            Self::X86_64 => "x86-64",
+        }
+    }
+}
+
+impl AsRef<Arch> for Arch {
+    #[inline]
+    fn as_ref(&self) -> &Arch {
+        self
+    }
+}
+
+impl AsRef<str> for Arch {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Arch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Arch {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "alpha" => Ok(Self::Alpha),
+            "arc" => Ok(Self::Arc),
+            "arm" => Ok(Self::Arm),
+            "arm64" => Ok(Self::Arm64),
+            "ia64" => Ok(Self::Ia64),
+            "loongarch64" => Ok(Self::LoongArch64),
+            "mips" => Ok(Self::Mips),
+            "mips64" => Ok(Self::Mips64),
+            "mips-le" => Ok(Self::MipsLe),
+            "mips64-le" => Ok(Self::Mips64Le),
+            "parisc" => Ok(Self::Parisc),
+            "ppc" => Ok(Self::Ppc),
+            "ppc64" => Ok(Self::Ppc64),
+            "ppc64-le" => Ok(Self::Ppc64Le),
+            "riscv32" => Ok(Self::RiscV32),
+            "riscv64" => Ok(Self::RiscV64),
+            "s390" => Ok(Self::S390),
+            "s390x" => Ok(Self::S390x),
+            "tilegx" => Ok(Self::TileGx),
+            "x86" => Ok(Self::X86),
+            "x86-64" => Ok(Self::X86_64),
+            _unsupported => {
+                let err_msg = format!("unsupported architecture: {:?}", s);
+                Err(ParserError::Arch(err_msg))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn arch_can_parse_its_string_representation() -> crate::Result<()> {
+        for arch in enum_iterator::all::<Arch>() {
+            let actual: Arch = arch.as_str().parse()?;
+            assert_eq!(actual, arch);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported architecture")]
+    fn arch_can_not_parse_an_unsupported_architecture() {
+        let _: Arch = "z80".parse().unwrap();
+    }
+}
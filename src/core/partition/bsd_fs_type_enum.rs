@@ -0,0 +1,301 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::errors::ParserError;
+
+/// File system types of partitions in a `BSD disklabel`.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum BsdFsType {
+    /// Unused partition.
+    Unused = 0,
+    /// Swap space.
+    Swap = 1,
+    /// Sixth Edition Unix file system.
+    Version6 = 2,
+    /// Seventh Edition Unix file system.
+    Version7 = 3,
+    /// System V file system.
+    SystemV = 4,
+    /// Seventh Edition Unix file system with 1K blocks (4.1BSD).
+    Bsd41 = 5,
+    /// Eighth Edition Unix file system with 4K blocks.
+    EighthEdition = 6,
+    /// Berkeley Fast File System (4.2BSD), i.e. `UFS`.
+    FFS = 7,
+    /// MS-DOS file system.
+    MSDOS = 8,
+    /// Log-structured File System (4.4BSD).
+    LFS = 9,
+    /// Unknown file system.
+    Other = 10,
+    /// OS/2 High Performance File System.
+    HPFS = 11,
+    /// ISO 9660 file system.
+    ISO9660 = 12,
+    /// Boot partition.
+    Boot = 13,
+    /// AmigaDOS file system.
+    ADOS = 14,
+    /// Macintosh Hierarchical File System.
+    HFS = 15,
+    /// Digital Unix Advanced File System.
+    AdvFS = 16,
+}
+
+impl BsdFsType {
+    /// View this `BsdFsType` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unused => "0x00",
+            Self::Swap => "0x01",
+            Self::Version6 => "0x02",
+            Self::Version7 => "0x03",
+            Self::SystemV => "0x04",
+            Self::Bsd41 => "0x05",
+            Self::EighthEdition => "0x06",
+            Self::FFS => "0x07",
+            Self::MSDOS => "0x08",
+            Self::LFS => "0x09",
+            Self::Other => "0x0a",
+            Self::HPFS => "0x0b",
+            Self::ISO9660 => "0x0c",
+            Self::Boot => "0x0d",
+            Self::ADOS => "0x0e",
+            Self::HFS => "0x0f",
+            Self::AdvFS => "0x10",
+        }
+    }
+
+    /// Returns the name `fdisk` displays for this `BsdFsType`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unused => "unused",
+            Self::Swap => "swap",
+            Self::Version6 => "Version 6",
+            Self::Version7 => "Version 7",
+            Self::SystemV => "System V",
+            Self::Bsd41 => "4.1BSD",
+            Self::EighthEdition => "Eighth Edition",
+            Self::FFS => "4.2BSD",
+            Self::MSDOS => "MS-DOS",
+            Self::LFS => "4.4LFS",
+            Self::Other => "unknown",
+            Self::HPFS => "HPFS",
+            Self::ISO9660 => "ISO-9660",
+            Self::Boot => "boot",
+            Self::ADOS => "ADOS",
+            Self::HFS => "HFS",
+            Self::AdvFS => "AdvFS",
+        }
+    }
+}
+
+impl AsRef<BsdFsType> for BsdFsType {
+    #[inline]
+    fn as_ref(&self) -> &BsdFsType {
+        self
+    }
+}
+
+impl AsRef<str> for BsdFsType {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for BsdFsType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&[u8]> for BsdFsType {
+    type Error = ConversionError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        std::str::from_utf8(bytes)
+            .map_err(|e| {
+                ConversionError::BsdFsType(format!(
+                    "bytes to UTF-8 string slice conversion error. {:?}",
+                    e
+                ))
+            })
+            .and_then(|s| Self::from_str(s).map_err(|e| ConversionError::BsdFsType(e.to_string())))
+    }
+}
+
+impl TryFrom<Vec<u8>> for BsdFsType {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl FromStr for BsdFsType {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Remove opening opening/closing quotes/double-quotes if present
+        let err_missing_dquote = format!("missing closing double-quote in: {}", s);
+        let err_missing_quote = format!("missing closing quote in: {}", s);
+
+        let trimmed = s.trim();
+        let stripped = if trimmed.starts_with('"') {
+            trimmed
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or(ParserError::BsdFsType(err_missing_dquote))
+        } else if trimmed.starts_with('\'') {
+            trimmed
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .ok_or(ParserError::BsdFsType(err_missing_quote))
+        } else {
+            Ok(trimmed)
+        }?;
+
+        // Remove hex string prefix and convert to `BsdFsType`.
+        stripped
+            .trim()
+            .strip_prefix("0x")
+            .ok_or(ParserError::BsdFsType(format!(
+                "missing '0x' prefix in: {}",
+                s
+            )))
+            .and_then(|h| {
+                u8::from_str_radix(h, 16).map_err(|e| {
+                    let err_msg = format!("invalid hexadecimal string: {} {:?}", s, e);
+
+                    ParserError::BsdFsType(err_msg)
+                })
+            })
+            .and_then(|n| {
+                Self::try_from(n).map_err(|_| {
+                    let err_msg = format!("unsupported BSD file system type: {}", s);
+
+                    ParserError::BsdFsType(err_msg)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "missing closing double-quote")]
+    fn bsd_fs_type_can_not_parse_a_bsd_fs_type_string_with_an_unclosed_double_quote() {
+        let _: BsdFsType = r#""0x07"#.parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing closing quote")]
+    fn bsd_fs_type_can_not_parse_a_bsd_fs_type_string_with_an_unclosed_quote() {
+        let _: BsdFsType = "'0x07".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn bsd_fs_type_can_not_parse_an_empty_string() {
+        let _: BsdFsType = "".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn bsd_fs_type_can_not_parse_a_bsd_fs_type_missing_its_0x_prefix() {
+        let _: BsdFsType = "07".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn bsd_fs_type_can_not_parse_a_bsd_fs_type_string_with_an_invalid_hexadecimal() {
+        let _: BsdFsType = "0xDUMMY".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn bsd_fs_type_can_not_parse_a_bsd_fs_type_string_with_a_too_large_hexadecimal() {
+        let _: BsdFsType = "0xffffff".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported BSD file system type")]
+    fn bsd_fs_type_can_not_parse_an_unsupported_bsd_fs_type() {
+        let _: BsdFsType = "0x11".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes to UTF-8 string slice conversion error")]
+    fn bsd_fs_type_can_not_convert_invalid_bytes_into_a_bsd_fs_type() {
+        // some invalid bytes, in a vector
+        let bytes: Vec<u8> = vec![0, 159, 146, 150];
+        let _ = BsdFsType::try_from(bytes).unwrap();
+    }
+
+    #[test]
+    fn bsd_fs_type_can_convert_valid_bytes_into_a_bsd_fs_type() -> crate::Result<()> {
+        let bytes: Vec<u8> = b"0x07".to_vec();
+        let actual = BsdFsType::try_from(bytes)?;
+        let expected = BsdFsType::FFS;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bsd_fs_type_can_parse_a_quoted_bsd_fs_type() -> crate::Result<()> {
+        let actual: BsdFsType = r#""0x07""#.parse()?;
+        let expected = BsdFsType::FFS;
+        assert_eq!(actual, expected);
+
+        let actual: BsdFsType = "'0x07'".parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bsd_fs_type_can_round_trip_through_its_string_representation() -> crate::Result<()> {
+        for bsd_fs_type in enum_iterator::all::<BsdFsType>() {
+            let actual: BsdFsType = bsd_fs_type.to_string().parse()?;
+            assert_eq!(actual, bsd_fs_type);
+
+            let actual = BsdFsType::try_from(bsd_fs_type.as_str().as_bytes())?;
+            assert_eq!(actual, bsd_fs_type);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bsd_fs_type_can_round_trip_through_its_numeric_value() -> crate::Result<()> {
+        for bsd_fs_type in enum_iterator::all::<BsdFsType>() {
+            let value: u8 = bsd_fs_type.into();
+            let actual = BsdFsType::try_from(value)?;
+            assert_eq!(actual, bsd_fs_type);
+            assert_eq!(bsd_fs_type.to_string(), format!("{:#04x}", value));
+        }
+
+        Ok(())
+    }
+}
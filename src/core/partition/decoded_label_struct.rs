@@ -0,0 +1,186 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::partition::DecodingConfidence;
+use crate::core::partition::LabelEncoding;
+
+/// File system label decoded from the raw bytes stored on disk.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecodedLabel {
+    label: String,
+    encoding: LabelEncoding,
+    confidence: DecodingConfidence,
+}
+
+impl DecodedLabel {
+    /// Decodes the `bytes` of a label with the given `encoding`.
+    ///
+    /// Trailing NUL characters are removed, as are the trailing spaces FAT file systems use to
+    /// pad their labels.
+    pub fn decode<T>(bytes: T, encoding: LabelEncoding) -> DecodedLabel
+    where
+        T: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+
+        let (label, confidence) = match encoding {
+            LabelEncoding::Utf8 => match std::str::from_utf8(bytes) {
+                Ok(label) => (label.to_owned(), DecodingConfidence::Certain),
+                Err(_) => (
+                    String::from_utf8_lossy(bytes).to_string(),
+                    DecodingConfidence::Lossy,
+                ),
+            },
+            LabelEncoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            LabelEncoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+            LabelEncoding::Codepage(codepage) => {
+                let confidence = if bytes.is_ascii() {
+                    DecodingConfidence::Certain
+                } else {
+                    DecodingConfidence::Assumed
+                };
+
+                let label = codepage.decode(bytes).trim_end_matches(' ').to_owned();
+
+                (label, confidence)
+            }
+        };
+
+        let label = label.trim_end_matches('\0').to_owned();
+        log::debug!(
+            "DecodedLabel::decode decoded {:?} as {:?} with {} ({})",
+            bytes,
+            label,
+            encoding,
+            confidence
+        );
+
+        Self {
+            label,
+            encoding,
+            confidence,
+        }
+    }
+
+    /// Returns the decoded label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the encoding used to decode the label.
+    pub fn encoding(&self) -> LabelEncoding {
+        self.encoding
+    }
+
+    /// Returns how reliable the decoded label is.
+    pub fn confidence(&self) -> DecodingConfidence {
+        self.confidence
+    }
+
+    /// Converts this `DecodedLabel` into its label.
+    pub fn into_string(self) -> String {
+        self.label
+    }
+}
+
+impl AsRef<str> for DecodedLabel {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.label()
+    }
+}
+
+impl fmt::Display for DecodedLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: decodes a UTF-16 byte sequence, `to_u16` sets the byte order of code units.
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> (String, DecodingConfidence) {
+    let chunks = bytes.chunks_exact(2);
+    // A trailing odd byte can not be part of a valid code unit.
+    let mut confidence = if chunks.remainder().is_empty() {
+        DecodingConfidence::Certain
+    } else {
+        DecodingConfidence::Lossy
+    };
+
+    let units = chunks.map(|pair| to_u16([pair[0], pair[1]]));
+    let label = char::decode_utf16(units)
+        .map(|c| {
+            c.unwrap_or_else(|_| {
+                confidence = DecodingConfidence::Lossy;
+
+                char::REPLACEMENT_CHARACTER
+            })
+        })
+        .collect();
+
+    (label, confidence)
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::partition::Codepage;
+    use crate::core::partition::FileSystem;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn decoded_label_can_decode_a_fat_label_in_an_oem_codepage() {
+        let encoding = LabelEncoding::for_file_system(&FileSystem::VFAT, Codepage::Cp850);
+        let decoded = DecodedLabel::decode(b"\xe5\xe5\xe5     ", encoding);
+
+        assert_eq!(decoded.label(), "ÕÕÕ");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Assumed);
+
+        let decoded = DecodedLabel::decode(b"NO NAME    ", encoding);
+
+        assert_eq!(decoded.label(), "NO NAME");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+    }
+
+    #[test]
+    fn decoded_label_can_decode_utf16_labels() {
+        let encoding = LabelEncoding::for_file_system(&FileSystem::NTFS, Codepage::Cp437);
+        let decoded = DecodedLabel::decode(b"D\x00\xe9\x00j\x00\xe0\x00\x00\x00", encoding);
+
+        assert_eq!(decoded.label(), "Déjà");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+
+        let encoding = LabelEncoding::for_file_system(&FileSystem::HFSPlus, Codepage::Cp437);
+        let decoded = DecodedLabel::decode(b"\x00M\x00a\x00c", encoding);
+
+        assert_eq!(decoded.label(), "Mac");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+    }
+
+    #[test]
+    fn decoded_label_flags_invalid_byte_sequences_as_lossy() {
+        // Unpaired high surrogate.
+        let decoded = DecodedLabel::decode(b"A\x00\x00\xd8", LabelEncoding::Utf16Le);
+
+        assert_eq!(decoded.label(), "A\u{FFFD}");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Lossy);
+
+        // Odd number of bytes.
+        let decoded = DecodedLabel::decode(b"A\x00B", LabelEncoding::Utf16Le);
+
+        assert_eq!(decoded.label(), "A");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Lossy);
+
+        let decoded = DecodedLabel::decode(b"caf\xe9", LabelEncoding::Utf8);
+
+        assert_eq!(decoded.label(), "caf\u{FFFD}");
+        assert_eq!(decoded.confidence(), DecodingConfidence::Lossy);
+    }
+}
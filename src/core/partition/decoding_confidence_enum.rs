@@ -0,0 +1,41 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// How reliable the result of decoding a label is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum DecodingConfidence {
+    /// The label was decoded without error, and its encoding is either mandated by the file
+    /// system, or irrelevant (e.g. a pure ASCII label).
+    Certain,
+    /// The label was decoded without error, but with an encoding the file system does not
+    /// record (e.g. the OEM code page of a FAT label).
+    Assumed,
+    /// The label holds byte sequences invalid in the selected encoding, which were replaced with
+    /// [U+FFFD REPLACEMENT CHARACTER](std::char::REPLACEMENT_CHARACTER).
+    Lossy,
+}
+
+impl DecodingConfidence {
+    /// View this `DecodingConfidence` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Certain => "certain",
+            Self::Assumed => "assumed",
+            Self::Lossy => "lossy",
+        }
+    }
+}
+
+impl fmt::Display for DecodingConfidence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
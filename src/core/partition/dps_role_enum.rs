@@ -0,0 +1,104 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Roles of partitions defined by the [Discoverable Partitions
+/// Specification](https://uapi-group.org/specifications/specs/discoverable_partitions_specification/)
+/// (DPS), which `systemd-gpt-auto-generator` relies on to mount partitions without an entry in
+/// `/etc/fstab`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum DpsRole {
+    /// Root file system, mounted on `/`.
+    Root,
+    /// `/usr` file system.
+    Usr,
+    /// `dm-verity` hash data of the root file system.
+    RootVerity,
+    /// `dm-verity` hash data of the `/usr` file system.
+    UsrVerity,
+    /// Signature of the root file system's `dm-verity` root hash.
+    RootVeritySig,
+    /// Signature of the `/usr` file system's `dm-verity` root hash.
+    UsrVeritySig,
+    /// EFI System Partition (ESP).
+    Esp,
+    /// Extended Boot Loader Partition (XBOOTLDR), mounted on `/boot`.
+    Xbootldr,
+    /// Swap space.
+    Swap,
+    /// `/home` file system.
+    Home,
+    /// `/srv` file system.
+    Srv,
+    /// `/var` file system.
+    Var,
+    /// `/var/tmp` file system.
+    Tmp,
+    /// Per-user home directory, managed by `systemd-homed`.
+    UserHome,
+}
+
+impl DpsRole {
+    /// View this `DpsRole` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Root => "root",
+            Self::Usr => "usr",
+            Self::RootVerity => "root-verity",
+            Self::UsrVerity => "usr-verity",
+            Self::RootVeritySig => "root-verity-sig",
+            Self::UsrVeritySig => "usr-verity-sig",
+            Self::Esp => "esp",
+            Self::Xbootldr => "xbootldr",
+            Self::Swap => "swap",
+            Self::Home => "home",
+            Self::Srv => "srv",
+            Self::Var => "var",
+            Self::Tmp => "tmp",
+            Self::UserHome => "user-home",
+        }
+    }
+
+    /// Returns `true` if partitions with this role have a different type for each [`Arch`](crate::core::partition::Arch).
+    pub fn is_arch_specific(&self) -> bool {
+        matches!(
+            self,
+            Self::Root
+                | Self::Usr
+                | Self::RootVerity
+                | Self::UsrVerity
+                | Self::RootVeritySig
+                | Self::UsrVeritySig
+        )
+    }
+
+    /// Returns the directory on which `systemd-gpt-auto-generator` mounts partitions with this
+    /// role, `None` for partitions that are not mounted.
+    pub fn mount_point(&self) -> Option<&str> {
+        match self {
+            Self::Root => Some("/"),
+            Self::Usr => Some("/usr"),
+            Self::Esp => Some("/efi"),
+            Self::Xbootldr => Some("/boot"),
+            Self::Home => Some("/home"),
+            Self::Srv => Some("/srv"),
+            Self::Var => Some("/var"),
+            Self::Tmp => Some("/var/tmp"),
+            _otherwise => None,
+        }
+    }
+}
+
+impl fmt::Display for DpsRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
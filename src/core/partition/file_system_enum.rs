@@ -12,6 +12,7 @@
 // From this library
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
+use crate::core::partition::FileSystemInfo;
 
 /// Supported file systems.
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence)]
@@ -284,6 +285,12 @@ pub fn to_c_string(&self) -> CString {
         // unwrap the new CString.
         CString::new(self.as_str()).unwrap()
     }
+
+    /// Returns the capabilities of this `FileSystem` (usage class, label constraints, UUID
+    /// support, etc.).
+    pub fn info(&self) -> FileSystemInfo {
+        FileSystemInfo::for_file_system(self)
+    }
 }
 
 impl AsRef<FileSystem> for FileSystem {
@@ -0,0 +1,419 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Usage;
+use crate::core::partition::FileSystem;
+use crate::core::partition::LabelCharset;
+
+/// Capabilities of a [`FileSystem`], as recorded by `libblkid` and the tools creating it.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::device::Usage;
+/// use rsblkid::core::partition::FileSystem;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let info = FileSystem::VFAT.info();
+///
+///     assert_eq!(info.usage(), Usage::FileSystem);
+///     assert_eq!(info.max_label_length(), Some(11));
+///     assert!(info.is_valid_label("BOOT"));
+///     assert!(!info.is_valid_label("boot?"));
+///
+///     let info = FileSystem::NTFS.info();
+///     assert_eq!(info.mount_type(), Some("ntfs3"));
+///     assert!(info.has_different_mount_type());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FileSystemInfo {
+    usage: Usage,
+    label_charset: Option<LabelCharset>,
+    max_label_length: Option<usize>,
+    has_uuid: bool,
+    has_uuid_sub: bool,
+    mount_type: Option<&'static str>,
+    has_different_mount_type: bool,
+}
+
+impl FileSystemInfo {
+    #[doc(hidden)]
+    /// Creates a `FileSystemInfo` for a file system without label, nor UUID.
+    const fn new(usage: Usage) -> FileSystemInfo {
+        Self {
+            usage,
+            label_charset: None,
+            max_label_length: None,
+            has_uuid: false,
+            has_uuid_sub: false,
+            mount_type: None,
+            has_different_mount_type: false,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Sets the characters allowed in, and the maximum length of a label.
+    const fn with_label(mut self, charset: LabelCharset, max_length: usize) -> FileSystemInfo {
+        self.label_charset = Some(charset);
+        self.max_label_length = Some(max_length);
+        self
+    }
+
+    #[doc(hidden)]
+    /// Marks the file system as having a UUID.
+    const fn with_uuid(mut self) -> FileSystemInfo {
+        self.has_uuid = true;
+        self
+    }
+
+    #[doc(hidden)]
+    /// Marks the file system as having a sub-UUID.
+    const fn with_uuid_sub(mut self) -> FileSystemInfo {
+        self.has_uuid_sub = true;
+        self
+    }
+
+    #[doc(hidden)]
+    /// Sets the name of the kernel driver used to mount the file system.
+    const fn with_mount_type(mut self, mount_type: &'static str) -> FileSystemInfo {
+        self.mount_type = Some(mount_type);
+        self
+    }
+
+    /// Returns the capabilities of a `file_system`.
+    pub(crate) fn for_file_system(file_system: &FileSystem) -> FileSystemInfo {
+        let mut info = match file_system {
+            FileSystem::AdaptecRaid => Self::new(Usage::Raid),
+            FileSystem::APFS => Self::new(Usage::FileSystem).with_uuid(),
+            FileSystem::Bcache => Self::new(Usage::Other)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid(),
+            FileSystem::BcacheFs => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid()
+                .with_uuid_sub()
+                .with_mount_type("bcachefs"),
+            FileSystem::BeFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid()
+                .with_mount_type("befs"),
+            FileSystem::BFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 6)
+                .with_mount_type("bfs"),
+            FileSystem::BitLocker => Self::new(Usage::Crypto),
+            FileSystem::BlueStore => Self::new(Usage::Other).with_uuid(),
+            FileSystem::BTRFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 255)
+                .with_uuid()
+                .with_uuid_sub()
+                .with_mount_type("btrfs"),
+            FileSystem::Cramfs => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_mount_type("cramfs"),
+            FileSystem::DDFRaid => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::DmIntegrity => Self::new(Usage::Crypto),
+            FileSystem::DmSnapshot => Self::new(Usage::Other),
+            FileSystem::DmVerify => Self::new(Usage::Crypto).with_uuid(),
+            FileSystem::DRBD => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::DRBDManage => Self::new(Usage::Other).with_uuid(),
+            FileSystem::DRBDProxyDatalog => Self::new(Usage::Other).with_uuid(),
+            FileSystem::EROFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("erofs"),
+            FileSystem::ExFAT => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Utf16, 11)
+                .with_uuid()
+                .with_mount_type("exfat"),
+            FileSystem::Exfs => Self::new(Usage::FileSystem),
+            FileSystem::Ext2 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("ext2"),
+            FileSystem::Ext3 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("ext3"),
+            FileSystem::Ext4 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("ext4"),
+            FileSystem::Ext4Dev => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("ext4"),
+            FileSystem::F2FS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Utf16, 512)
+                .with_uuid()
+                .with_mount_type("f2fs"),
+            FileSystem::FileVault => Self::new(Usage::Crypto)
+                .with_label(LabelCharset::Bytes, 64)
+                .with_uuid(),
+            FileSystem::GFS => Self::new(Usage::FileSystem).with_label(LabelCharset::Bytes, 64),
+            FileSystem::GFS2 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 64)
+                .with_uuid()
+                .with_mount_type("gfs2"),
+            FileSystem::HFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 27)
+                .with_uuid()
+                .with_mount_type("hfs"),
+            FileSystem::HFSPlus => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Utf16, 255)
+                .with_uuid()
+                .with_mount_type("hfsplus"),
+            FileSystem::HighPoint37x => Self::new(Usage::Raid),
+            FileSystem::HighPoint45x => Self::new(Usage::Raid),
+            FileSystem::HPFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 11)
+                .with_uuid()
+                .with_mount_type("hpfs"),
+            FileSystem::Iso9660 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Iso9660, 32)
+                .with_uuid()
+                .with_mount_type("iso9660"),
+            FileSystem::ISWRaid => Self::new(Usage::Raid),
+            FileSystem::JBD => Self::new(Usage::Other)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid(),
+            FileSystem::JFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("jfs"),
+            FileSystem::JmicronRaid => Self::new(Usage::Raid),
+            FileSystem::LinuxRaid => Self::new(Usage::Raid)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid()
+                .with_uuid_sub(),
+            FileSystem::LSIRaid => Self::new(Usage::Raid),
+            FileSystem::LUKS => Self::new(Usage::Crypto)
+                .with_label(LabelCharset::Bytes, 48)
+                .with_uuid(),
+            FileSystem::LVM1 => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::LVM2 => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::Minix => Self::new(Usage::FileSystem).with_mount_type("minix"),
+            FileSystem::Mpool => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid(),
+            FileSystem::MSDOS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Fat, 11)
+                .with_uuid()
+                .with_mount_type("vfat"),
+            FileSystem::Netware => Self::new(Usage::FileSystem).with_uuid(),
+            FileSystem::Nilfs2 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 80)
+                .with_uuid()
+                .with_mount_type("nilfs2"),
+            FileSystem::NTFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Utf16, 128)
+                .with_uuid()
+                .with_mount_type("ntfs3"),
+            FileSystem::NvidiaRaid => Self::new(Usage::Raid),
+            FileSystem::OCFS => Self::new(Usage::Other)
+                .with_label(LabelCharset::Bytes, 64)
+                .with_uuid(),
+            FileSystem::OCFS2 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 64)
+                .with_uuid()
+                .with_mount_type("ocfs2"),
+            FileSystem::PromiseRaid => Self::new(Usage::Raid),
+            FileSystem::ReFs => Self::new(Usage::FileSystem),
+            FileSystem::Reiserfs => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid()
+                .with_mount_type("reiserfs"),
+            FileSystem::Reiser4 => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid(),
+            FileSystem::Romfs => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 128)
+                .with_mount_type("romfs"),
+            FileSystem::SiliconRaid => Self::new(Usage::Raid),
+            FileSystem::Squashfs => Self::new(Usage::FileSystem).with_mount_type("squashfs"),
+            FileSystem::Squashfs3 => Self::new(Usage::FileSystem),
+            FileSystem::Stratis => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::Swap => Self::new(Usage::Other)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid(),
+            FileSystem::SwapSuspend => Self::new(Usage::Other)
+                .with_label(LabelCharset::Bytes, 16)
+                .with_uuid(),
+            FileSystem::SYSV => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 6)
+                .with_mount_type("sysv"),
+            FileSystem::UBI => Self::new(Usage::Raid),
+            FileSystem::UBIFS => Self::new(Usage::FileSystem)
+                .with_uuid()
+                .with_mount_type("ubifs"),
+            FileSystem::UDF => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 126)
+                .with_uuid()
+                .with_mount_type("udf"),
+            FileSystem::UFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid()
+                .with_mount_type("ufs"),
+            FileSystem::VDO => Self::new(Usage::Other).with_uuid(),
+            FileSystem::VFAT => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Fat, 11)
+                .with_uuid()
+                .with_mount_type("vfat"),
+            FileSystem::VIARaid => Self::new(Usage::Raid),
+            FileSystem::VMFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 128)
+                .with_uuid(),
+            FileSystem::VMFSVolume => Self::new(Usage::Raid).with_uuid(),
+            FileSystem::Vxfs => Self::new(Usage::FileSystem).with_mount_type("freevxfs"),
+            FileSystem::Xenix => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 6)
+                .with_mount_type("sysv"),
+            FileSystem::XFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 12)
+                .with_uuid()
+                .with_mount_type("xfs"),
+            FileSystem::XFSLog => Self::new(Usage::Other).with_uuid(),
+            FileSystem::ZFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 255)
+                .with_uuid()
+                .with_uuid_sub(),
+            FileSystem::ZoneFS => Self::new(Usage::FileSystem)
+                .with_label(LabelCharset::Bytes, 32)
+                .with_uuid()
+                .with_mount_type("zonefs"),
+        };
+
+        info.has_different_mount_type = info
+            .mount_type
+            .map(|mount_type| mount_type != file_system.as_str())
+            .unwrap_or(false);
+
+        info
+    }
+
+    /// Returns the usage class of the file system.
+    pub fn usage(&self) -> Usage {
+        self.usage
+    }
+
+    /// Returns `true` if the file system can have a label (`LABEL`).
+    pub fn supports_label(&self) -> bool {
+        self.label_charset.is_some()
+    }
+
+    /// Returns the characters allowed in a label, `None` if the file system does not support
+    /// labels.
+    pub fn label_charset(&self) -> Option<LabelCharset> {
+        self.label_charset
+    }
+
+    /// Returns the maximum length of a label, in the unit set by
+    /// [`label_charset`](FileSystemInfo::label_charset). Returns `None` if the file system does
+    /// not support labels.
+    pub fn max_label_length(&self) -> Option<usize> {
+        self.max_label_length
+    }
+
+    /// Returns `true` if the file system has a UUID (`UUID`).
+    pub fn supports_uuid(&self) -> bool {
+        self.has_uuid
+    }
+
+    /// Returns `true` if the file system has a sub-UUID (`UUID_SUB`), e.g. the device ID of a
+    /// member of a multi-device `btrfs` file system.
+    pub fn supports_uuid_sub(&self) -> bool {
+        self.has_uuid_sub
+    }
+
+    /// Returns the file system type to pass to `mount`, `None` if the Linux kernel can not mount
+    /// it (e.g. RAID members, encrypted volumes, swap space).
+    pub fn mount_type(&self) -> Option<&'static str> {
+        self.mount_type
+    }
+
+    /// Returns `true` if the file system type to pass to `mount` differs from the name reported
+    /// by `libblkid` (e.g. `ntfs` is mounted as `ntfs3`).
+    pub fn has_different_mount_type(&self) -> bool {
+        self.has_different_mount_type
+    }
+
+    /// Returns `true` if `label` is a valid, non-empty label for the file system.
+    pub fn is_valid_label(&self, label: &str) -> bool {
+        match (self.label_charset, self.max_label_length) {
+            (Some(charset), Some(max_length)) => {
+                !label.is_empty()
+                    && label.chars().all(|c| charset.allows(c))
+                    && charset.length(label) <= max_length
+            }
+            _otherwise => false,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn file_system_info_can_validate_labels() {
+        let info = FileSystem::VFAT.info();
+        assert!(info.is_valid_label("EFI SYSTEM"));
+        assert!(!info.is_valid_label(""));
+        assert!(!info.is_valid_label("efi"));
+        assert!(!info.is_valid_label("A:B"));
+        assert!(!info.is_valid_label("LONGER LABEL"));
+
+        let info = FileSystem::ExFAT.info();
+        assert_eq!(info.label_charset(), Some(LabelCharset::Utf16));
+        assert!(info.is_valid_label("Déjà vu"));
+        assert!(!info.is_valid_label("Déjà vu encore"));
+
+        let info = FileSystem::Ext4.info();
+        // 15 characters, 16 bytes in UTF-8.
+        assert!(info.is_valid_label("données_backup1"));
+        assert!(!info.is_valid_label("données_backup12"));
+
+        let info = FileSystem::LVM2.info();
+        assert!(!info.supports_label());
+        assert!(!info.is_valid_label("vg0"));
+    }
+
+    #[test]
+    fn file_system_info_reports_usage_and_mount_type() {
+        assert_eq!(FileSystem::LUKS.info().usage(), Usage::Crypto);
+        assert_eq!(FileSystem::LinuxRaid.info().usage(), Usage::Raid);
+        assert_eq!(FileSystem::Swap.info().usage(), Usage::Other);
+
+        let info = FileSystem::BTRFS.info();
+        assert!(info.supports_uuid_sub());
+        assert_eq!(info.mount_type(), Some("btrfs"));
+        assert!(!info.has_different_mount_type());
+
+        let info = FileSystem::MSDOS.info();
+        assert_eq!(info.mount_type(), Some("vfat"));
+        assert!(info.has_different_mount_type());
+
+        assert_eq!(FileSystem::Swap.info().mount_type(), None);
+    }
+
+    #[test]
+    fn file_system_info_label_capabilities_are_consistent() {
+        for file_system in enum_iterator::all::<FileSystem>() {
+            let info = file_system.info();
+            assert_eq!(
+                info.label_charset().is_some(),
+                info.max_label_length().is_some()
+            );
+        }
+    }
+}
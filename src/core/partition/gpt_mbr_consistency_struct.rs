@@ -0,0 +1,265 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::MbrAnomaly;
+use crate::core::partition::MbrEntry;
+use crate::core::partition::MbrKind;
+use crate::core::partition::Severity;
+
+/// Consistency between the Master Boot Record (MBR) and the GUID Partition Table (GPT) of a
+/// disk, produced by [`Probe::gpt_mbr_consistency`](crate::probe::Probe::gpt_mbr_consistency).
+///
+/// A GPT disk should start with a protective MBR, holding a single `0xEE` entry covering the
+/// whole disk. Hybrid MBRs, used to boot legacy operating systems with a BIOS, add entries
+/// that must mirror GPT partitions exactly.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GptMbrConsistency {
+    kind: MbrKind,
+    entries: [MbrEntry; 4],
+    anomalies: Vec<MbrAnomaly>,
+}
+
+impl GptMbrConsistency {
+    /// Checks the first sector of a device of `device_size` logical sectors against the
+    /// location and size, in logical sectors, of its GPT partitions.
+    pub fn check(sector: &[u8], device_size: u64, gpt_partitions: &[(u64, u64)]) -> Self {
+        let entries = match MbrEntry::parse_table(sector) {
+            Some(entries) => entries,
+            None => {
+                return Self {
+                    kind: MbrKind::Missing,
+                    entries: [MbrEntry::default(); 4],
+                    anomalies: vec![MbrAnomaly::MissingSignature],
+                }
+            }
+        };
+
+        let used: Vec<_> = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !e.is_empty())
+            .map(|(i, e)| (i + 1, e))
+            .collect();
+        let protective: Vec<_> = used.iter().filter(|(_, e)| e.is_protective()).collect();
+        let hybrid: Vec<_> = used.iter().filter(|(_, e)| !e.is_protective()).collect();
+
+        let kind = match (protective.is_empty(), hybrid.is_empty()) {
+            (true, _) => MbrKind::Foreign,
+            (false, true) => MbrKind::Protective,
+            (false, false) => MbrKind::Hybrid,
+        };
+
+        let mut anomalies = vec![];
+
+        if protective.is_empty() {
+            anomalies.push(MbrAnomaly::NoProtectiveEntry);
+        }
+
+        if protective.len() > 1 {
+            anomalies.push(MbrAnomaly::MultipleProtectiveEntries {
+                count: protective.len(),
+            });
+        }
+
+        // The protective entry covers the whole disk, except the MBR, within the limits of
+        // a 32-bit sector count.
+        let expected = device_size.saturating_sub(1).min(u32::MAX as u64);
+        for (entry, e) in protective.iter() {
+            if e.start() != 1 {
+                anomalies.push(MbrAnomaly::ProtectiveEntryWrongStart {
+                    entry: *entry,
+                    start: e.start(),
+                });
+            }
+
+            if kind == MbrKind::Protective && e.size() != expected {
+                anomalies.push(MbrAnomaly::ProtectiveEntryWrongSize {
+                    entry: *entry,
+                    size: e.size(),
+                    expected,
+                });
+            }
+
+            if e.is_bootable() {
+                anomalies.push(MbrAnomaly::ProtectiveEntryBootable { entry: *entry });
+            }
+        }
+
+        for (entry, e) in hybrid.iter() {
+            if !gpt_partitions.contains(&(e.start(), e.size())) {
+                anomalies.push(MbrAnomaly::HybridEntryMismatch {
+                    entry: *entry,
+                    start: e.start(),
+                    size: e.size(),
+                });
+            }
+        }
+
+        for (i, (entry, e)) in used.iter().enumerate() {
+            // A protective entry of maximum size legitimately exceeds disks larger than 2 TiB.
+            let is_clamped = e.is_protective() && e.size() == u32::MAX as u64;
+            if e.end() > device_size && !is_clamped {
+                anomalies.push(MbrAnomaly::EntryBeyondDeviceEnd { entry: *entry });
+            }
+
+            for (other, o) in used.iter().skip(i + 1) {
+                if e.start().max(o.start()) < e.end().min(o.end()) {
+                    anomalies.push(MbrAnomaly::OverlappingEntries {
+                        entry: *entry,
+                        other: *other,
+                    });
+                }
+            }
+        }
+
+        log::debug!(
+            "GptMbrConsistency::check MBR kind: {:?}, anomalies: {:?}",
+            kind,
+            anomalies
+        );
+
+        Self {
+            kind,
+            entries,
+            anomalies,
+        }
+    }
+
+    /// Returns the kind of MBR found.
+    pub fn kind(&self) -> MbrKind {
+        self.kind
+    }
+
+    /// Returns the four MBR partition entries (all empty if the MBR is missing).
+    pub fn entries(&self) -> &[MbrEntry; 4] {
+        &self.entries
+    }
+
+    /// Returns the anomalies detected.
+    pub fn anomalies(&self) -> &[MbrAnomaly] {
+        &self.anomalies
+    }
+
+    /// Returns the severity of the most serious anomaly, `None` if there are no anomalies.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.anomalies.iter().map(|a| a.severity()).max()
+    }
+
+    /// Returns `true` if the MBR is a protective or a hybrid MBR, consistent with the GPT.
+    pub fn is_consistent(&self) -> bool {
+        self.max_severity() < Some(Severity::Error)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    // 1 GiB disk, in 512-byte sectors.
+    const DEVICE_SIZE: u64 = 2097152;
+
+    fn mbr(entries: &[MbrEntry]) -> [u8; 512] {
+        let mut sector = [0u8; 512];
+        for (i, entry) in entries.iter().enumerate() {
+            sector[446 + i * 16..446 + (i + 1) * 16].copy_from_slice(&entry.to_bytes());
+        }
+        sector[510] = 0x55;
+        sector[511] = 0xaa;
+
+        sector
+    }
+
+    #[test]
+    fn gpt_mbr_consistency_accepts_a_valid_protective_mbr() {
+        let sector = mbr(&[MbrEntry::new(false, 0xee, 1, 2097151)]);
+        let report = GptMbrConsistency::check(&sector, DEVICE_SIZE, &[(2048, 204800)]);
+
+        assert_eq!(report.kind(), MbrKind::Protective);
+        assert!(report.anomalies().is_empty());
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn gpt_mbr_consistency_accepts_a_clamped_protective_entry_on_a_large_disk() {
+        let sector = mbr(&[MbrEntry::new(false, 0xee, 1, u32::MAX)]);
+        let report = GptMbrConsistency::check(&sector, 1 << 33, &[]);
+
+        assert!(report.anomalies().is_empty());
+    }
+
+    #[test]
+    fn gpt_mbr_consistency_reports_a_protective_entry_of_the_wrong_size() {
+        let sector = mbr(&[MbrEntry::new(true, 0xee, 1, 1048575)]);
+        let report = GptMbrConsistency::check(&sector, DEVICE_SIZE, &[]);
+
+        let expected = [
+            MbrAnomaly::ProtectiveEntryWrongSize {
+                entry: 1,
+                size: 1048575,
+                expected: 2097151,
+            },
+            MbrAnomaly::ProtectiveEntryBootable { entry: 1 },
+        ];
+        assert_eq!(report.anomalies(), &expected);
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn gpt_mbr_consistency_checks_hybrid_entries_against_the_gpt() {
+        let sector = mbr(&[
+            MbrEntry::new(false, 0xee, 1, 2047),
+            MbrEntry::new(true, 0x07, 2048, 204800),
+            MbrEntry::new(false, 0x83, 206848, 4096),
+        ]);
+        let gpt = [(2048, 204800), (206848, 8192)];
+        let report = GptMbrConsistency::check(&sector, DEVICE_SIZE, &gpt);
+
+        let expected = [MbrAnomaly::HybridEntryMismatch {
+            entry: 3,
+            start: 206848,
+            size: 4096,
+        }];
+        assert_eq!(report.kind(), MbrKind::Hybrid);
+        assert_eq!(report.anomalies(), &expected);
+        assert!(!report.is_consistent());
+    }
+
+    #[test]
+    fn gpt_mbr_consistency_reports_missing_and_foreign_mbrs() {
+        let report = GptMbrConsistency::check(&[0u8; 512], DEVICE_SIZE, &[]);
+        assert_eq!(report.kind(), MbrKind::Missing);
+        assert_eq!(report.anomalies(), &[MbrAnomaly::MissingSignature]);
+
+        let sector = mbr(&[
+            MbrEntry::new(false, 0x83, 2048, 1048576),
+            MbrEntry::new(false, 0x82, 1048576, 1050624),
+        ]);
+        let report = GptMbrConsistency::check(&sector, DEVICE_SIZE, &[]);
+
+        let expected = [
+            MbrAnomaly::NoProtectiveEntry,
+            MbrAnomaly::HybridEntryMismatch {
+                entry: 1,
+                start: 2048,
+                size: 1048576,
+            },
+            MbrAnomaly::HybridEntryMismatch {
+                entry: 2,
+                start: 1048576,
+                size: 1050624,
+            },
+            MbrAnomaly::OverlappingEntries { entry: 1, other: 2 },
+            MbrAnomaly::EntryBeyondDeviceEnd { entry: 2 },
+        ];
+        assert_eq!(report.kind(), MbrKind::Foreign);
+        assert_eq!(report.anomalies(), &expected);
+    }
+}
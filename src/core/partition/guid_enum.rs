@@ -11,6 +11,7 @@
 // From this library
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
+use crate::core::partition::GuidInfo;
 
 /// Supported GPT partitions.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence)]
@@ -325,6 +326,10 @@ pub enum Guid {
     LinuxHome,
     /// Linux /srv (server data) partition
     LinuxServerData,
+    /// Linux /var partition
+    LinuxVar,
+    /// Linux /var/tmp partition
+    LinuxVarTmp,
     /// Linux Per‐user home partition
     LinuxPerUserHome,
     /// Linux Plain dm-crypt partition
@@ -812,6 +817,8 @@ pub fn as_str(&self) -> &str {
             Self::LinuxLVM => "e6d6d379-f507-44c2-a23c-238f2a3df928",
             Self::LinuxHome => "933ac7e1-2eb4-4f13-b844-0e14e2aef915",
             Self::LinuxServerData => "3b8f8425-20e0-4f3b-907f-1a25a76f98e8",
+            Self::LinuxVar => "4d21b016-b534-45c2-a9fb-5c16e091fd2d",
+            Self::LinuxVarTmp => "7ec6f557-3bc5-4aca-b293-16ef5df639d1",
             Self::LinuxPerUserHome => "773f91ef-66d4-49b5-bd83-d683bf40ad16",
             Self::LinuxPlain => "7ffec5c9-2d00-49b7-8941-3ea10a5586b7",
             Self::LinuxLUKS => "ca7d7ccb-63ed-4c53-861c-1742536059cc",
@@ -966,6 +973,12 @@ pub fn as_str(&self) -> &str {
             Self::Minix => "481b2a38-0561-420b-b72a-f1c4988efc16",
         }
     }
+
+    /// Returns metadata about this partition type (description, operating system, role in the
+    /// Discoverable Partitions Specification, etc.).
+    pub fn info(&self) -> GuidInfo {
+        GuidInfo::for_guid(self)
+    }
 }
 
 impl AsRef<Guid> for Guid {
@@ -1186,6 +1199,8 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "e6d6d379-f507-44c2-a23c-238f2a3df928" => Ok(Self::LinuxLVM),
             "933ac7e1-2eb4-4f13-b844-0e14e2aef915" => Ok(Self::LinuxHome),
             "3b8f8425-20e0-4f3b-907f-1a25a76f98e8" => Ok(Self::LinuxServerData),
+            "4d21b016-b534-45c2-a9fb-5c16e091fd2d" => Ok(Self::LinuxVar),
+            "7ec6f557-3bc5-4aca-b293-16ef5df639d1" => Ok(Self::LinuxVarTmp),
             "773f91ef-66d4-49b5-bd83-d683bf40ad16" => Ok(Self::LinuxPerUserHome),
             "7ffec5c9-2d00-49b7-8941-3ea10a5586b7" => Ok(Self::LinuxPlain),
             "ca7d7ccb-63ed-4c53-861c-1742536059cc" => Ok(Self::LinuxLUKS),
@@ -2141,6 +2156,16 @@ fn guid_can_parse_a_valid_guid() -> crate::Result<()> {
         let expected = Guid::LinuxServerData;
         assert_eq!(actual, expected);
 
+        let guid_str = "4d21b016-b534-45c2-a9fb-5c16e091fd2d";
+        let actual: Guid = guid_str.parse()?;
+        let expected = Guid::LinuxVar;
+        assert_eq!(actual, expected);
+
+        let guid_str = "7ec6f557-3bc5-4aca-b293-16ef5df639d1";
+        let actual: Guid = guid_str.parse()?;
+        let expected = Guid::LinuxVarTmp;
+        assert_eq!(actual, expected);
+
         let guid_str = "773f91ef-66d4-49b5-bd83-d683bf40ad16";
         let actual: Guid = guid_str.parse()?;
         let expected = Guid::LinuxPerUserHome;
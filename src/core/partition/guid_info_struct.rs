@@ -0,0 +1,444 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::Arch;
+use crate::core::partition::DpsRole;
+use crate::core::partition::Guid;
+use crate::core::partition::OsFamily;
+
+/// Metadata about a GPT partition type.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::partition::{Arch, DpsRole, Guid, OsFamily};
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let info = Guid::LinuxRootx86_64.info();
+///
+///     assert_eq!(info.description(), "Linux Root partition (x86-64)");
+///     assert_eq!(info.os_family(), OsFamily::Linux);
+///     assert_eq!(info.dps_role(), Some(DpsRole::Root));
+///     assert_eq!(info.arch(), Some(Arch::X86_64));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GuidInfo {
+    description: &'static str,
+    os_family: OsFamily,
+    dps_role: Option<DpsRole>,
+    arch: Option<Arch>,
+}
+
+impl GuidInfo {
+    #[doc(hidden)]
+    /// Creates a `GuidInfo` for a partition type outside the Discoverable Partitions
+    /// Specification.
+    const fn new(description: &'static str, os_family: OsFamily) -> GuidInfo {
+        Self {
+            description,
+            os_family,
+            dps_role: None,
+            arch: None,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Sets the partition's role in the Discoverable Partitions Specification.
+    const fn with_dps_role(mut self, dps_role: DpsRole) -> GuidInfo {
+        self.dps_role = Some(dps_role);
+        self
+    }
+
+    #[doc(hidden)]
+    /// Sets the architecture of an architecture-specific partition type.
+    const fn with_arch(mut self, arch: Arch) -> GuidInfo {
+        self.arch = Some(arch);
+        self
+    }
+
+    /// Returns the metadata of a partition type.
+    pub(crate) fn for_guid(guid: &Guid) -> GuidInfo {
+        match guid {
+            Guid::EmptyPartition => Self::new("Unused entry", OsFamily::Generic),
+            Guid::MBRPartition => Self::new("MBR partition scheme", OsFamily::Generic),
+            Guid::EfiSystem => Self::new("EFI System partition", OsFamily::Generic).with_dps_role(DpsRole::Esp),
+            Guid::BIOSBoot => Self::new("BIOS boot partition", OsFamily::Generic),
+            Guid::IntelFastFlash => Self::new("Intel Fast Flash (iFFS) partition (for Intel Rapid Start technology)", OsFamily::Generic),
+            Guid::SonyBoot => Self::new("Sony boot partition", OsFamily::Generic),
+            Guid::LenovoBoot => Self::new("Lenovo boot partition", OsFamily::Generic),
+            Guid::MicrosoftReserved => Self::new("Windows Microsoft Reserved Partition (MSR)", OsFamily::Windows),
+            Guid::WindowsBasicData => Self::new("Windows Basic data partition", OsFamily::Windows),
+            Guid::WindowsLDM => Self::new("Windows Logical Disk Manager (LDM) metadata partition", OsFamily::Windows),
+            Guid::WindowsLDMData => Self::new("Windows Logical Disk Manager data partition", OsFamily::Windows),
+            Guid::WindowsRecovery => Self::new("Windows Recovery Environment", OsFamily::Windows),
+            Guid::IBMGPFS => Self::new("IBM General Parallel File System (GPFS) partition", OsFamily::Windows),
+            Guid::WindowsStorageSpaces => Self::new("Windows Storage Spaces partition", OsFamily::Windows),
+            Guid::WindowsStorageReplica => Self::new("Windows Storage Replica partition", OsFamily::Windows),
+            Guid::HPUXData => Self::new("HP-UX Data partition", OsFamily::HpUx),
+            Guid::HPUXService => Self::new("HP-UX Service partition", OsFamily::HpUx),
+            Guid::LinuxData => Self::new("Linux filesystem data", OsFamily::Linux),
+            Guid::LinuxRaid => Self::new("Linux RAID partition", OsFamily::Linux),
+            Guid::LinuxRootAlpha => Self::new("Linux Root partition (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Alpha),
+            Guid::LinuxRootARC => Self::new("Linux Root partition (ARC)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Arc),
+            Guid::LinuxRootARM32 => Self::new("Linux Root partition (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Arm),
+            Guid::LinuxRootARM64 => Self::new("Linux Root partition (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Arm64),
+            Guid::LinuxRootIA64 => Self::new("Linux Root partition (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Ia64),
+            Guid::LinuxRootLoongArch64 => Self::new("Linux Root partition (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::LoongArch64),
+            Guid::LinuxRootMIPS => Self::new("Linux Root partition (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Mips),
+            Guid::LinuxRootMIPS64 => Self::new("Linux Root partition (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Mips64),
+            Guid::LinuxRootMIPSEL => Self::new("Linux Root partition (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::MipsLe),
+            Guid::LinuxRootMIPS64EL => Self::new("Linux Root partition (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Mips64Le),
+            Guid::LinuxRootPARISC => Self::new("Linux Root partition (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Parisc),
+            Guid::LinuxRootPPC32 => Self::new("Linux Root partition (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Ppc),
+            Guid::LinuxRootPPC64 => Self::new("Linux Root partition (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Ppc64),
+            Guid::LinuxRootPPC64LE => Self::new("Linux Root partition (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::Ppc64Le),
+            Guid::LinuxRootRISCV32 => Self::new("Linux Root partition (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::RiscV32),
+            Guid::LinuxRootRISCV64 => Self::new("Linux Root partition (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::RiscV64),
+            Guid::LinuxRootS390 => Self::new("Linux Root partition (s390)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::S390),
+            Guid::LinuxRootS390x => Self::new("Linux Root partition (s390x)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::S390x),
+            Guid::LinuxRootTILEGx => Self::new("Linux Root partition (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::TileGx),
+            Guid::LinuxRootx86 => Self::new("Linux Root partition (x86)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::X86),
+            Guid::LinuxRootx86_64 => Self::new("Linux Root partition (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::Root).with_arch(Arch::X86_64),
+            Guid::LinuxUsrAlpha => Self::new("Linux /usr partition (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Alpha),
+            Guid::LinuxUsrARC => Self::new("Linux /usr partition (ARC)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Arc),
+            Guid::LinuxUsrARM32 => Self::new("Linux /usr partition (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Arm),
+            Guid::LinuxUsrARM64 => Self::new("Linux /usr partition (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Arm64),
+            Guid::LinuxUsrIA64 => Self::new("Linux /usr partition (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Ia64),
+            Guid::LinuxUsrLoongArch64 => Self::new("Linux /usr partition (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::LoongArch64),
+            Guid::LinuxUsrMIPS => Self::new("Linux /usr partition (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Mips),
+            Guid::LinuxUsrMIPS64 => Self::new("Linux /usr partition (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Mips64),
+            Guid::LinuxUsrMIPSEL => Self::new("Linux /usr partition (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::MipsLe),
+            Guid::LinuxUsrMIPS64EL => Self::new("Linux /usr partition (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Mips64Le),
+            Guid::LinuxUsrPARISC => Self::new("Linux /usr partition (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Parisc),
+            Guid::LinuxUsrPPC32 => Self::new("Linux /usr partition (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Ppc),
+            Guid::LinuxUsrPPC64 => Self::new("Linux /usr partition (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Ppc64),
+            Guid::LinuxUsrPPC64LE => Self::new("Linux /usr partition (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::Ppc64Le),
+            Guid::LinuxUsrRISCV32 => Self::new("Linux /usr partition (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::RiscV32),
+            Guid::LinuxUsrRISCV64 => Self::new("Linux /usr partition (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::RiscV64),
+            Guid::LinuxUsrS390 => Self::new("Linux /usr partition (s390)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::S390),
+            Guid::LinuxUsrS390x => Self::new("Linux /usr partition (s390x)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::S390x),
+            Guid::LinuxUsrTILEGx => Self::new("Linux /usr partition (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::TileGx),
+            Guid::LinuxUsrx86 => Self::new("Linux /usr partition (x86)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::X86),
+            Guid::LinuxUsrx86_64 => Self::new("Linux /usr partition (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::Usr).with_arch(Arch::X86_64),
+            Guid::LinuxRootVerityAlpha => Self::new("Linux Root verity partition for dm-verity (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Alpha),
+            Guid::LinuxRootVerityARC => Self::new("Linux Root verity partition for dm-verity (ARC)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Arc),
+            Guid::LinuxRootVerityARM32 => Self::new("Linux Root verity partition for dm-verity (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Arm),
+            Guid::LinuxRootVerityARM64 => Self::new("Linux Root verity partition for dm-verity (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Arm64),
+            Guid::LinuxRootVerityIA64 => Self::new("Linux Root verity partition for dm-verity (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Ia64),
+            Guid::LinuxRootVerityLoongArch64 => Self::new("Linux Root verity partition for dm-verity (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::LoongArch64),
+            Guid::LinuxRootVerityMIPS => Self::new("Linux Root verity partition for dm-verity (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Mips),
+            Guid::LinuxRootVerityMIPS64 => Self::new("Linux Root verity partition for dm-verity (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Mips64),
+            Guid::LinuxRootVerityMIPSEL => Self::new("Linux Root verity partition for dm-verity (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::MipsLe),
+            Guid::LinuxRootVerityMIPS64EL => Self::new("Linux Root verity partition for dm-verity (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Mips64Le),
+            Guid::LinuxRootVerityPARISC => Self::new("Linux Root verity partition for dm-verity (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Parisc),
+            Guid::LinuxRootVerityPPC32 => Self::new("Linux Root verity partition for dm-verity (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Ppc),
+            Guid::LinuxRootVerityPPC64 => Self::new("Linux Root verity partition for dm-verity (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Ppc64),
+            Guid::LinuxRootVerityPPC64LE => Self::new("Linux Root verity partition for dm-verity (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::Ppc64Le),
+            Guid::LinuxRootVerityRISCV32 => Self::new("Linux Root verity partition for dm-verity (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::RiscV32),
+            Guid::LinuxRootVerityRISCV64 => Self::new("Linux Root verity partition for dm-verity (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::RiscV64),
+            Guid::LinuxRootVerityS390 => Self::new("Linux Root verity partition for dm-verity (s390)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::S390),
+            Guid::LinuxRootVerityS390x => Self::new("Linux Root verity partition for dm-verity (s390x)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::S390x),
+            Guid::LinuxRootVerityTILEGx => Self::new("Linux Root verity partition for dm-verity (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::TileGx),
+            Guid::LinuxRootVerityx86 => Self::new("Linux Root verity partition for dm-verity (x86)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::X86),
+            Guid::LinuxRootVerityx86_64 => Self::new("Linux Root verity partition for dm-verity (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::RootVerity).with_arch(Arch::X86_64),
+            Guid::LinuxUsrVerityAlpha => Self::new("Linux /usr verity partition for dm-verity (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Alpha),
+            Guid::LinuxUsrVerityARC => Self::new("Linux /usr verity partition for dm-verity (ARC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Arc),
+            Guid::LinuxUsrVerityARM32 => Self::new("Linux /usr verity partition for dm-verity (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Arm),
+            Guid::LinuxUsrVerityARM64 => Self::new("Linux /usr verity partition for dm-verity (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Arm64),
+            Guid::LinuxUsrVerityIA64 => Self::new("Linux /usr verity partition for dm-verity (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Ia64),
+            Guid::LinuxUsrVerityLoongArch64 => Self::new("Linux /usr verity partition for dm-verity (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::LoongArch64),
+            Guid::LinuxUsrVerityMIPS => Self::new("Linux /usr verity partition for dm-verity (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Mips),
+            Guid::LinuxUsrVerityMIPS64 => Self::new("Linux /usr verity partition for dm-verity (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Mips64),
+            Guid::LinuxUsrVerityMIPSEL => Self::new("Linux /usr verity partition for dm-verity (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::MipsLe),
+            Guid::LinuxUsrVerityMIPS64EL => Self::new("Linux /usr verity partition for dm-verity (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Mips64Le),
+            Guid::LinuxUsrVerityPARISC => Self::new("Linux /usr verity partition for dm-verity (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Parisc),
+            Guid::LinuxUsrVerityPPC32 => Self::new("Linux /usr verity partition for dm-verity (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Ppc),
+            Guid::LinuxUsrVerityPPC64 => Self::new("Linux /usr verity partition for dm-verity (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Ppc64),
+            Guid::LinuxUsrVerityPPC64LE => Self::new("Linux /usr verity partition for dm-verity (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::Ppc64Le),
+            Guid::LinuxUsrVerityRISCV32 => Self::new("Linux /usr verity partition for dm-verity (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::RiscV32),
+            Guid::LinuxUsrVerityRISCV64 => Self::new("Linux /usr verity partition for dm-verity (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::RiscV64),
+            Guid::LinuxUsrVerityS390 => Self::new("Linux /usr verity partition for dm-verity (s390)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::S390),
+            Guid::LinuxUsrVerityS390x => Self::new("Linux /usr verity partition for dm-verity (s390x)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::S390x),
+            Guid::LinuxUsrVerityTILEGx => Self::new("Linux /usr verity partition for dm-verity (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::TileGx),
+            Guid::LinuxUsrVerityx86 => Self::new("Linux /usr verity partition for dm-verity (x86)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::X86),
+            Guid::LinuxUsrVerityx86_64 => Self::new("Linux /usr verity partition for dm-verity (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVerity).with_arch(Arch::X86_64),
+            Guid::LinuxRootVeritySigAlpha => Self::new("Linux Root verity signature partition for dm-verity (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Alpha),
+            Guid::LinuxRootVeritySigARC => Self::new("Linux Root verity signature partition for dm-verity (ARC)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Arc),
+            Guid::LinuxRootVeritySigARM32 => Self::new("Linux Root verity signature partition for dm-verity (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Arm),
+            Guid::LinuxRootVeritySigARM64 => Self::new("Linux Root verity signature partition for dm-verity (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Arm64),
+            Guid::LinuxRootVeritySigIA64 => Self::new("Linux Root verity signature partition for dm-verity (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Ia64),
+            Guid::LinuxRootVeritySigLoongArch64 => Self::new("Linux Root verity signature partition for dm-verity (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::LoongArch64),
+            Guid::LinuxRootVeritySigMIPS => Self::new("Linux Root verity signature partition for dm-verity (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Mips),
+            Guid::LinuxRootVeritySigMIPS64 => Self::new("Linux Root verity signature partition for dm-verity (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Mips64),
+            Guid::LinuxRootVeritySigMIPSEL => Self::new("Linux Root verity signature partition for dm-verity (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::MipsLe),
+            Guid::LinuxRootVeritySigMIPS64EL => Self::new("Linux Root verity signature partition for dm-verity (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Mips64Le),
+            Guid::LinuxRootVeritySigPARISC => Self::new("Linux Root verity signature partition for dm-verity (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Parisc),
+            Guid::LinuxRootVeritySigPPC32 => Self::new("Linux Root verity signature partition for dm-verity (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Ppc),
+            Guid::LinuxRootVeritySigPPC64 => Self::new("Linux Root verity signature partition for dm-verity (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Ppc64),
+            Guid::LinuxRootVeritySigPPC64LE => Self::new("Linux Root verity signature partition for dm-verity (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::Ppc64Le),
+            Guid::LinuxRootVeritySigRISCV32 => Self::new("Linux Root verity signature partition for dm-verity (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::RiscV32),
+            Guid::LinuxRootVeritySigRISCV64 => Self::new("Linux Root verity signature partition for dm-verity (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::RiscV64),
+            Guid::LinuxRootVeritySigS390 => Self::new("Linux Root verity signature partition for dm-verity (s390)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::S390),
+            Guid::LinuxRootVeritySigS390x => Self::new("Linux Root verity signature partition for dm-verity (s390x)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::S390x),
+            Guid::LinuxRootVeritySigTILEGx => Self::new("Linux Root verity signature partition for dm-verity (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::TileGx),
+            Guid::LinuxRootVeritySigx86 => Self::new("Linux Root verity signature partition for dm-verity (x86)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::X86),
+            Guid::LinuxRootVeritySigx86_64 => Self::new("Linux Root verity signature partition for dm-verity (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::RootVeritySig).with_arch(Arch::X86_64),
+            Guid::LinuxUsrVeritySigAlpha => Self::new("Linux /usr verity signature partition for dm-verity (Alpha)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Alpha),
+            Guid::LinuxUsrVeritySigARC => Self::new("Linux /usr verity signature partition for dm-verity (ARC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Arc),
+            Guid::LinuxUsrVeritySigARM32 => Self::new("Linux /usr verity signature partition for dm-verity (ARM 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Arm),
+            Guid::LinuxUsrVeritySigARM64 => Self::new("Linux /usr verity signature partition for dm-verity (AArch64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Arm64),
+            Guid::LinuxUsrVeritySigIA64 => Self::new("Linux /usr verity signature partition for dm-verity (IA-64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Ia64),
+            Guid::LinuxUsrVeritySigLoongArch64 => Self::new("Linux /usr verity signature partition for dm-verity (LoongArch 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::LoongArch64),
+            Guid::LinuxUsrVeritySigMIPS => Self::new("Linux /usr verity signature partition for dm-verity (mips: 32‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Mips),
+            Guid::LinuxUsrVeritySigMIPS64 => Self::new("Linux /usr verity signature partition for dm-verity (mips64: 64‐bit MIPS big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Mips64),
+            Guid::LinuxUsrVeritySigMIPSEL => Self::new("Linux /usr verity signature partition for dm-verity (mipsel: 32‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::MipsLe),
+            Guid::LinuxUsrVeritySigMIPS64EL => Self::new("Linux /usr verity signature partition for dm-verity (mips64el: 64‐bit MIPS little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Mips64Le),
+            Guid::LinuxUsrVeritySigPARISC => Self::new("Linux /usr verity signature partition for dm-verity (PA-RISC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Parisc),
+            Guid::LinuxUsrVeritySigPPC32 => Self::new("Linux /usr verity signature partition for dm-verity (32‐bit PowerPC)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Ppc),
+            Guid::LinuxUsrVeritySigPPC64 => Self::new("Linux /usr verity signature partition for dm-verity (64‐bit PowerPC big‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Ppc64),
+            Guid::LinuxUsrVeritySigPPC64LE => Self::new("Linux /usr verity signature partition for dm-verity (64‐bit PowerPC little‐endian)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::Ppc64Le),
+            Guid::LinuxUsrVeritySigRISCV32 => Self::new("Linux /usr verity signature partition for dm-verity (RISC-V 32‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::RiscV32),
+            Guid::LinuxUsrVeritySigRISCV64 => Self::new("Linux /usr verity signature partition for dm-verity (RISC-V 64‐bit)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::RiscV64),
+            Guid::LinuxUsrVeritySigS390 => Self::new("Linux /usr verity signature partition for dm-verity (s390)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::S390),
+            Guid::LinuxUsrVeritySigS390x => Self::new("Linux /usr verity signature partition for dm-verity (s390x)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::S390x),
+            Guid::LinuxUsrVeritySigTILEGx => Self::new("Linux /usr verity signature partition for dm-verity (TILE-Gx)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::TileGx),
+            Guid::LinuxUsrVeritySigx86 => Self::new("Linux /usr verity signature partition for dm-verity (x86)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::X86),
+            Guid::LinuxUsrVeritySigx86_64 => Self::new("Linux /usr verity signature partition for dm-verity (x86-64)", OsFamily::Linux).with_dps_role(DpsRole::UsrVeritySig).with_arch(Arch::X86_64),
+            Guid::LinuxXBOOTLDR => Self::new("Linux /boot, as an Extended Boot Loader (XBOOTLDR) partition", OsFamily::Linux).with_dps_role(DpsRole::Xbootldr),
+            Guid::LinuxSwap => Self::new("Linux Swap partition", OsFamily::Linux).with_dps_role(DpsRole::Swap),
+            Guid::LinuxLVM => Self::new("Linux Logical Volume Manager (LVM) partition", OsFamily::Linux),
+            Guid::LinuxHome => Self::new("Linux /home partition", OsFamily::Linux).with_dps_role(DpsRole::Home),
+            Guid::LinuxServerData => Self::new("Linux /srv (server data) partition", OsFamily::Linux).with_dps_role(DpsRole::Srv),
+            Guid::LinuxVar => Self::new("Linux /var partition", OsFamily::Linux).with_dps_role(DpsRole::Var),
+            Guid::LinuxVarTmp => Self::new("Linux /var/tmp partition", OsFamily::Linux).with_dps_role(DpsRole::Tmp),
+            Guid::LinuxPerUserHome => Self::new("Linux Per‐user home partition", OsFamily::Linux).with_dps_role(DpsRole::UserHome),
+            Guid::LinuxPlain => Self::new("Linux Plain dm-crypt partition", OsFamily::Linux),
+            Guid::LinuxLUKS => Self::new("Linux LUKS partition", OsFamily::Linux),
+            Guid::LinuxReserved => Self::new("Linux Reserved", OsFamily::Linux),
+            Guid::FreeBSDBoot => Self::new("FreeBSD Boot partition", OsFamily::FreeBsd),
+            Guid::FreeBSDDisklabel => Self::new("FreeBSD BSD disklabel partition", OsFamily::FreeBsd),
+            Guid::FreeBSDSwap => Self::new("FreeBSD Swap partition", OsFamily::FreeBsd),
+            Guid::FreeBSDUFS => Self::new("FreeBSD Unix File System (UFS) partition", OsFamily::FreeBsd),
+            Guid::FreeBSDVinum => Self::new("FreeBSD Vinum volume manager partition", OsFamily::FreeBsd),
+            Guid::FreeBSDZFS => Self::new("FreeBSD ZFS partition", OsFamily::FreeBsd),
+            Guid::FreeBSDnandfs => Self::new("FreeBSD nandfs partition", OsFamily::FreeBsd),
+            Guid::MacOSHFSPlus => Self::new("macOS Darwin Hierarchical File System Plus (HFS+) partition", OsFamily::MacOs),
+            Guid::MacOSAPFS => Self::new("macOS Darwin Apple APFS container", OsFamily::MacOs),
+            Guid::MacOSUFS => Self::new("macOS Darwin Apple UFS container", OsFamily::MacOs),
+            Guid::MacOSRAID => Self::new("macOS Darwin Apple RAID partition", OsFamily::MacOs),
+            Guid::MacOSRAIDOffline => Self::new("macOS Darwin Apple RAID partition, offline", OsFamily::MacOs),
+            Guid::MacOSBootRecovery => Self::new("macOS Darwin Apple Boot partition (Recovery HD)", OsFamily::MacOs),
+            Guid::MacOSLabel => Self::new("macOS Darwin Apple Label", OsFamily::MacOs),
+            Guid::MacOSAppleTVRecovery => Self::new("macOS Darwin Apple TV Recovery partition", OsFamily::MacOs),
+            Guid::MacOSHFSPlusFileVault => Self::new("macOS Darwin Apple Core Storage container", OsFamily::MacOs),
+            Guid::MacOSAPFSPreboot => Self::new("macOS Darwin Apple APFS Preboot partition", OsFamily::MacOs),
+            Guid::MacOSAPFSRecovery => Self::new("macOS Darwin Apple APFS Recovery partition", OsFamily::MacOs),
+            Guid::SolarisBoot => Self::new("Solaris/Illumos Boot partition", OsFamily::Solaris),
+            Guid::SolarisRoot => Self::new("Solaris/Illumos Root partition", OsFamily::Solaris),
+            Guid::SolarisSwap => Self::new("Solaris/Illumos Swap partition", OsFamily::Solaris),
+            Guid::SolarisBackup => Self::new("Solaris/Illumos Backup partition", OsFamily::Solaris),
+            Guid::SolarisUsr => Self::new("Solaris/Illumos /usr partition, or macOS ZFS", OsFamily::Solaris),
+            Guid::SolarisVar => Self::new("Solaris/Illumos /var partition", OsFamily::Solaris),
+            Guid::SolarisHome => Self::new("Solaris/Illumos /home partition", OsFamily::Solaris),
+            Guid::SolarisAlternateSector => Self::new("Solaris/Illumos Alternate sector", OsFamily::Solaris),
+            Guid::SolarisReserved1 => Self::new("Solaris/Illumos Reserved partition", OsFamily::Solaris),
+            Guid::SolarisReserved2 => Self::new("Solaris/Illumos Reserved partition", OsFamily::Solaris),
+            Guid::SolarisReserved3 => Self::new("Solaris/Illumos Reserved partition", OsFamily::Solaris),
+            Guid::SolarisReserved4 => Self::new("Solaris/Illumos Reserved partition", OsFamily::Solaris),
+            Guid::SolarisReserved5 => Self::new("Solaris/Illumos Reserved partition", OsFamily::Solaris),
+            Guid::NetBSDSwap => Self::new("NetBSD Swap partition", OsFamily::NetBsd),
+            Guid::NetBSDFFS => Self::new("NetBSD FFS partition", OsFamily::NetBsd),
+            Guid::NetBSDLFS => Self::new("NetBSD LFS partition", OsFamily::NetBsd),
+            Guid::NetBSDRAID => Self::new("NetBSD RAID partition", OsFamily::NetBsd),
+            Guid::NetBSDConcatenated => Self::new("NetBSD Concatenated partition", OsFamily::NetBsd),
+            Guid::NetBSDEncrypted => Self::new("NetBSD Encrypted partition", OsFamily::NetBsd),
+            Guid::ChromeOSKernel => Self::new("ChromeOS kernel", OsFamily::ChromeOs),
+            Guid::ChromeOSRootFs => Self::new("ChromeOS rootfs", OsFamily::ChromeOs),
+            Guid::ChromeOSFirmware => Self::new("ChromeOS firmware", OsFamily::ChromeOs),
+            Guid::ChromeOSFuture => Self::new("ChromeOS future use", OsFamily::ChromeOs),
+            Guid::ChromeOSMiniOS => Self::new("ChromeOS miniOS", OsFamily::ChromeOs),
+            Guid::ChromeOSHibernate => Self::new("ChromeOS hibernate", OsFamily::ChromeOs),
+            Guid::CoreOSUsr => Self::new("CoreOS /usr partition (coreos-usr)", OsFamily::CoreOs),
+            Guid::CoreOSResize => Self::new("CoreOS Resizable rootfs (coreos-resize)", OsFamily::CoreOs),
+            Guid::CoreOSReserved => Self::new("CoreOS OEM customizations (coreos-reserved)", OsFamily::CoreOs),
+            Guid::CoreOSRootRAID => Self::new("CoreOS Root filesystem on RAID (coreos-root-raid)", OsFamily::CoreOs),
+            Guid::HaikuBFS => Self::new("Haiku BFS", OsFamily::Haiku),
+            Guid::MidnightBSDBoot => Self::new("MidnightBSD Boot partition", OsFamily::MidnightBsd),
+            Guid::MidnightBSDData => Self::new("MidnightBSD Data partition", OsFamily::MidnightBsd),
+            Guid::MidnightBSDSwap => Self::new("MidnightBSD Swap partition", OsFamily::MidnightBsd),
+            Guid::MidnightBSDUFS => Self::new("MidnightBSD Unix File System (UFS) partition", OsFamily::MidnightBsd),
+            Guid::MidnightBSDVinum => Self::new("MidnightBSD Vinum volume manager partition", OsFamily::MidnightBsd),
+            Guid::MidnightBSDZFS => Self::new("MidnightBSD ZFS partition", OsFamily::MidnightBsd),
+            Guid::CephJournal => Self::new("Ceph Journal", OsFamily::Ceph),
+            Guid::CephDMCryptJournal => Self::new("Ceph dm-crypt journal", OsFamily::Ceph),
+            Guid::CephOSD => Self::new("Ceph OSD", OsFamily::Ceph),
+            Guid::CephDMCryptOSD => Self::new("Ceph dm-crypt OSD", OsFamily::Ceph),
+            Guid::CephDisk => Self::new("Ceph Disk in creation", OsFamily::Ceph),
+            Guid::CephDMCryptDisk => Self::new("Ceph dm-crypt disk in creation", OsFamily::Ceph),
+            Guid::CephBlock => Self::new("Ceph Block", OsFamily::Ceph),
+            Guid::CephBlockDB => Self::new("Ceph Block DB", OsFamily::Ceph),
+            Guid::CephBlockLog => Self::new("Ceph Block write-ahead log", OsFamily::Ceph),
+            Guid::CephLockbox => Self::new("Ceph Lockbox for dm-crypt keys", OsFamily::Ceph),
+            Guid::CephMultipathOSD => Self::new("Ceph Multipath OSD", OsFamily::Ceph),
+            Guid::CephMultipathJournal => Self::new("Ceph Multipath journal", OsFamily::Ceph),
+            Guid::CephMultipathBlock1 => Self::new("Ceph Multipath block", OsFamily::Ceph),
+            Guid::CephMultipathBlock2 => Self::new("Ceph Multipath block", OsFamily::Ceph),
+            Guid::CephMultipathBlockDB => Self::new("Ceph Multipath block DB", OsFamily::Ceph),
+            Guid::CephMultipathLog => Self::new("Ceph Multipath block write-ahead log", OsFamily::Ceph),
+            Guid::CephDMCryptBlock => Self::new("Ceph dm-crypt block", OsFamily::Ceph),
+            Guid::CephDMCryptBlockDB => Self::new("Ceph dm-crypt block DB", OsFamily::Ceph),
+            Guid::CephDMCryptBlockLog => Self::new("Ceph dm-crypt block write-ahead log", OsFamily::Ceph),
+            Guid::CephLUKSOSD => Self::new("Ceph dm-crypt LUKS OSD", OsFamily::Ceph),
+            Guid::CephLUKSJournal => Self::new("Ceph dm-crypt LUKS journal", OsFamily::Ceph),
+            Guid::CephLUKSBlock => Self::new("Ceph dm-crypt LUKS block", OsFamily::Ceph),
+            Guid::CephLUKSBlockDB => Self::new("Ceph dm-crypt LUKS block DB", OsFamily::Ceph),
+            Guid::CephLUKSBlockLog => Self::new("Ceph dm-crypt LUKS block write-ahead log", OsFamily::Ceph),
+            Guid::OpenBSDData => Self::new("OpenBSD Data partition", OsFamily::OpenBsd),
+            Guid::QNX6Fs => Self::new("QNX Power-safe (QNX6) file system", OsFamily::Qnx),
+            Guid::Plan9 => Self::new("Plan 9 partition", OsFamily::Plan9),
+            Guid::VMWareVMKCORE => Self::new("VMWare ESX vmkcore (coredump partition)", OsFamily::VMware),
+            Guid::VMWareVMFS => Self::new("VMWare ESX VMFS filesystem partition", OsFamily::VMware),
+            Guid::VMWareWmkReserved => Self::new("VMWare ESX VMware Reserved", OsFamily::VMware),
+            Guid::AndroidBootloader => Self::new("Android-IA Bootloader", OsFamily::Android),
+            Guid::AndroidBootloader2 => Self::new("Android-IA Bootloader2", OsFamily::Android),
+            Guid::AndroidBoot => Self::new("Android-IA Boot", OsFamily::Android),
+            Guid::AndroidRecovery => Self::new("Android-IA Recovery", OsFamily::Android),
+            Guid::AndroidMisc => Self::new("Android-IA Misc", OsFamily::Android),
+            Guid::AndroidMetadata => Self::new("Android-IA Metadata", OsFamily::Android),
+            Guid::AndroidSystem => Self::new("Android-IA System", OsFamily::Android),
+            Guid::AndroidCache => Self::new("Android-IA Cache", OsFamily::Android),
+            Guid::AndroidData => Self::new("Android-IA Data", OsFamily::Android),
+            Guid::AndroidPersistent => Self::new("Android-IA Persistent", OsFamily::Android),
+            Guid::AndroidVendor => Self::new("Android-IA Vendor", OsFamily::Android),
+            Guid::AndroidConfig => Self::new("Android-IA Config", OsFamily::Android),
+            Guid::AndroidFactory => Self::new("Android-IA Factory", OsFamily::Android),
+            Guid::AndroidFactoryAlt => Self::new("Android-IA Factory (alt)", OsFamily::Android),
+            Guid::AndroidFastboot => Self::new("Android-IA Fastboot / Tertiary", OsFamily::Android),
+            Guid::AndroidOEM => Self::new("Android-IA OEM", OsFamily::Android),
+            Guid::Android6Meta => Self::new("Android 6.0+ ARM Android Meta", OsFamily::Android),
+            Guid::Android6Ext => Self::new("Android 6.0+ ARM Android EXT", OsFamily::Android),
+            Guid::ONIEBoot => Self::new("Open Network Install Environment (ONIE) Boot", OsFamily::Onie),
+            Guid::ONIEConfig => Self::new("Open Network Install Environment (ONIE) Config", OsFamily::Onie),
+            Guid::PPCPrePBoot => Self::new("PowerPC PReP boot", OsFamily::Generic),
+            Guid::AtariTOSBasicData => Self::new("Atari TOS Basic data partition (GEM, BGM, F32)", OsFamily::AtariTos),
+            Guid::AtariTOSRawData => Self::new("Atari TOS Raw data partition (RAW), XHDI", OsFamily::AtariTos),
+            Guid::VeraCryptEncrypted => Self::new("VeraCrypt Encrypted data partition", OsFamily::VeraCrypt),
+            Guid::OS2ArcaOS => Self::new("OS/2 ArcaOS Type 1", OsFamily::Os2),
+            Guid::SPDK => Self::new("Storage Performance Development Kit (SPDK) block device", OsFamily::Spdk),
+            Guid::BareboxState => Self::new("barebox bootloader state", OsFamily::Barebox),
+            Guid::UBootEnv => Self::new("U-Boot bootloader environment", OsFamily::UBoot),
+            Guid::SoftRAIDStatus => Self::new("SoftRAID status", OsFamily::SoftRaid),
+            Guid::SoftRAIDScratch => Self::new("SoftRAID scratch", OsFamily::SoftRaid),
+            Guid::SoftRAIDVolume => Self::new("SoftRAID volume", OsFamily::SoftRaid),
+            Guid::SoftRAIDCache => Self::new("SoftRAID cache", OsFamily::SoftRaid),
+            Guid::FuchsiaBoot => Self::new("Fuchsia Bootloader (slot A/B/R)", OsFamily::Fuchsia),
+            Guid::FuchsiaSystemData => Self::new("Fuchsia Durable mutable encrypted system data", OsFamily::Fuchsia),
+            Guid::FuchsiaBootData => Self::new("Fuchsia Durable mutable bootloader data (including A/B/R metadata)", OsFamily::Fuchsia),
+            Guid::FuchsiaFactorySystemData => Self::new("Fuchsia Factory-provisioned read-only system data", OsFamily::Fuchsia),
+            Guid::FuchsiaFactoryBootData => Self::new("Fuchsia Factory-provisioned read-only bootloader data", OsFamily::Fuchsia),
+            Guid::FuchsiaVolumeManager => Self::new("Fuchsia Fuchsia Volume Manager", OsFamily::Fuchsia),
+            Guid::FuchsiaVerifiedBoot => Self::new("Fuchsia Verified boot metadata (slot A/B/R)", OsFamily::Fuchsia),
+            Guid::FuchsiaZirconBoot => Self::new("Fuchsia Zircon boot image (slot A/B/R)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacySystem => Self::new("Fuchsia legacy fuchsia-system", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyData => Self::new("Fuchsia legacy fuchsia-data", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyInstall => Self::new("Fuchsia legacy fuchsia-install", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyBlob => Self::new("Fuchsia legacy fuchsia-blob", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyFVM => Self::new("Fuchsia legacy fuchsia-fvm", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyZirconBootSlotA => Self::new("Fuchsia legacy Zircon boot image (slot A)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyZirconBootSlotB => Self::new("Fuchsia legacy Zircon boot image (slot B)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyZirconBootSlotR => Self::new("Fuchsia legacy Zircon boot image (slot R)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacySysConfig => Self::new("Fuchsia legacy sys-config", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyFactoryConfig => Self::new("Fuchsia legacy factory-config", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyBoot => Self::new("Fuchsia legacy bootloader", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyGuidTest => Self::new("Fuchsia legacy guid-test", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyVerifiedBootSlotA => Self::new("Fuchsia legacy Verified boot metadata (slot A)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyVerifiedBootSlotB => Self::new("Fuchsia legacy Verified boot metadata (slot B)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyVerifiedBootSlotR => Self::new("Fuchsia legacy Verified boot metadata (slot R)", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyMisc => Self::new("Fuchsia legacy misc", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyEmmcBoot1 => Self::new("Fuchsia legacy emmc-boot1", OsFamily::Fuchsia),
+            Guid::FuchsiaLegacyEmmcBoot2 => Self::new("Fuchsia legacy emmc-boot2", OsFamily::Fuchsia),
+            Guid::Minix => Self::new("Minix filesystem", OsFamily::Minix),
+        }
+    }
+
+    /// Returns a short description of the partition type.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the operating system, or software project, defining the partition type.
+    pub fn os_family(&self) -> OsFamily {
+        self.os_family
+    }
+
+    /// Returns the partition's role in the Discoverable Partitions Specification, `None` if the
+    /// type is not part of it.
+    pub fn dps_role(&self) -> Option<DpsRole> {
+        self.dps_role
+    }
+
+    /// Returns the CPU architecture of an architecture-specific partition type (e.g. root or
+    /// `/usr` partitions), `None` otherwise.
+    pub fn arch(&self) -> Option<Arch> {
+        self.arch
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn guid_info_identifies_dps_partition_types() {
+        let info = Guid::EfiSystem.info();
+        assert_eq!(info.os_family(), OsFamily::Generic);
+        assert_eq!(info.dps_role(), Some(DpsRole::Esp));
+        assert_eq!(info.arch(), None);
+
+        let info = Guid::LinuxUsrVeritySigARM64.info();
+        assert_eq!(info.dps_role(), Some(DpsRole::UsrVeritySig));
+        assert_eq!(info.arch(), Some(Arch::Arm64));
+
+        let info = Guid::LinuxVarTmp.info();
+        assert_eq!(info.dps_role(), Some(DpsRole::Tmp));
+
+        let info = Guid::WindowsBasicData.info();
+        assert_eq!(info.os_family(), OsFamily::Windows);
+        assert_eq!(info.dps_role(), None);
+    }
+
+    #[test]
+    fn guid_info_sets_an_arch_for_arch_specific_roles_only() {
+        for guid in enum_iterator::all::<Guid>() {
+            let info = guid.info();
+            let is_arch_specific = info.dps_role().is_some_and(|role| role.is_arch_specific());
+
+            assert_eq!(info.arch().is_some(), is_arch_specific, "{:?}", guid);
+        }
+    }
+
+    #[test]
+    fn guid_info_has_one_root_partition_type_per_arch() {
+        for arch in enum_iterator::all::<Arch>() {
+            let root_types = enum_iterator::all::<Guid>()
+                .filter(|guid| {
+                    let info = guid.info();
+                    info.dps_role() == Some(DpsRole::Root) && info.arch() == Some(arch)
+                })
+                .count();
+
+            assert_eq!(root_types, 1, "{:?}", arch);
+        }
+    }
+}
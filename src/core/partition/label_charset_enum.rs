@@ -0,0 +1,61 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Characters allowed in a file system label, and the unit its maximum length is expressed in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum LabelCharset {
+    /// Any character except NUL, the length is counted in bytes of UTF-8 (e.g. `ext4`, `xfs`).
+    Bytes,
+    /// Any character except NUL, the length is counted in UTF-16 code units (e.g. `exfat`,
+    /// `ntfs`).
+    Utf16,
+    /// Characters of an OEM code page, except the ones reserved by FAT (`"*+,./:;<=>?[\]|`) and
+    /// lowercase letters, the length is counted in characters (e.g. `vfat`).
+    Fat,
+    /// ISO 9660 d-characters (`A-Z`, `0-9`, and `_`), the length is counted in characters.
+    Iso9660,
+}
+
+impl LabelCharset {
+    /// View this `LabelCharset` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Utf16 => "utf-16",
+            Self::Fat => "fat",
+            Self::Iso9660 => "iso9660",
+        }
+    }
+
+    /// Returns `true` if `c` is allowed in a label.
+    pub fn allows(&self, c: char) -> bool {
+        match self {
+            Self::Bytes | Self::Utf16 => c != '\0',
+            Self::Fat => !c.is_control() && !c.is_lowercase() && !"\"*+,./:;<=>?[\\]|".contains(c),
+            Self::Iso9660 => c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_',
+        }
+    }
+
+    /// Returns the length of `label` in the unit of this `LabelCharset`.
+    pub fn length(&self, label: &str) -> usize {
+        match self {
+            Self::Bytes => label.len(),
+            Self::Utf16 => label.encode_utf16().count(),
+            Self::Fat | Self::Iso9660 => label.chars().count(),
+        }
+    }
+}
+
+impl fmt::Display for LabelCharset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::partition::Codepage;
+use crate::core::partition::FileSystem;
+
+/// Character encodings of file system labels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum LabelEncoding {
+    /// UTF-8 (e.g. `ext4`, `btrfs`, `xfs`).
+    Utf8,
+    /// Little-endian UTF-16 (e.g. `exfat`, `ntfs`).
+    Utf16Le,
+    /// Big-endian UTF-16 (e.g. `hfsplus`).
+    Utf16Be,
+    /// Single-byte OEM code page (e.g. `vfat`).
+    Codepage(Codepage),
+}
+
+impl LabelEncoding {
+    /// Returns the encoding of labels stored on disk by a `file_system`.
+    ///
+    /// FAT file systems do not record the code page of their label, `codepage` is used as a
+    /// fallback. Labels of `iso9660` (UTF-16 with Joliet extensions) and `udf` (8 or 16-bit
+    /// characters) vary between volumes, `Utf8` is returned, the encoding `libblkid` converts
+    /// them to.
+    pub fn for_file_system(file_system: &FileSystem, codepage: Codepage) -> LabelEncoding {
+        match file_system {
+            FileSystem::ExFAT | FileSystem::NTFS => Self::Utf16Le,
+            FileSystem::HFSPlus => Self::Utf16Be,
+            FileSystem::MSDOS | FileSystem::VFAT => Self::Codepage(codepage),
+            _otherwise => Self::Utf8,
+        }
+    }
+
+    /// Returns `true` if the file system mandates this encoding, `false` if it was chosen by
+    /// whoever created the file system.
+    pub fn is_mandated(&self) -> bool {
+        !matches!(self, Self::Codepage(_))
+    }
+}
+
+impl fmt::Display for LabelEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Utf8 => write!(f, "utf-8"),
+            Self::Utf16Le => write!(f, "utf-16le"),
+            Self::Utf16Be => write!(f, "utf-16be"),
+            Self::Codepage(codepage) => write!(f, "{}", codepage),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::partition::Alignment;
+use crate::core::partition::Severity;
+
+/// An irregularity in the layout of a partition table, detected by
+/// [`PartitionTable::analyze`](crate::probe::PartitionTable::analyze).
+///
+/// Locations and sizes are expressed in 512-byte sectors, like the ones returned by
+/// [`Partition`](crate::probe::Partition).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum LayoutFinding {
+    /// Unallocated space, at least as large as the device's allocation grain, between partitions
+    /// or at the edges of the device.
+    Gap { location: u64, size: u64 },
+    /// Two partitions sharing `size` sectors.
+    Overlap {
+        partition: usize,
+        other: usize,
+        size: u64,
+    },
+    /// A partition extending `excess` sectors past the end of the device.
+    BeyondDeviceEnd { partition: usize, excess: u64 },
+    /// A partition whose first sector does not meet an `alignment` requirement.
+    Misaligned {
+        partition: usize,
+        location: u64,
+        alignment: Alignment,
+    },
+    /// A partition located before the partition preceding it in the table.
+    OutOfOrder { partition: usize, previous: usize },
+}
+
+impl LayoutFinding {
+    /// Returns how serious this `LayoutFinding` is.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::Gap { .. } | Self::OutOfOrder { .. } => Severity::Info,
+            Self::Misaligned {
+                alignment: Alignment::Optimal,
+                ..
+            } => Severity::Info,
+            Self::Misaligned { .. } => Severity::Warning,
+            Self::Overlap { .. } | Self::BeyondDeviceEnd { .. } => Severity::Error,
+        }
+    }
+
+    /// Returns a short identifier of this `LayoutFinding`'s kind, suitable as a metric or alert
+    /// label.
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::Gap { .. } => "gap",
+            Self::Overlap { .. } => "overlap",
+            Self::BeyondDeviceEnd { .. } => "beyond-device-end",
+            Self::Misaligned { .. } => "misaligned",
+            Self::OutOfOrder { .. } => "out-of-order",
+        }
+    }
+}
+
+impl fmt::Display for LayoutFinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Gap { location, size } => write!(
+                f,
+                "{} unallocated sectors starting at sector {}",
+                size, location
+            ),
+            Self::Overlap {
+                partition,
+                other,
+                size,
+            } => write!(
+                f,
+                "partitions {} and {} overlap on {} sectors",
+                partition, other, size
+            ),
+            Self::BeyondDeviceEnd { partition, excess } => write!(
+                f,
+                "partition {} extends {} sectors past the end of the device",
+                partition, excess
+            ),
+            Self::Misaligned {
+                partition,
+                location,
+                alignment,
+            } => write!(
+                f,
+                "partition {} starting at sector {} does not meet {} alignment",
+                partition, location, alignment
+            ),
+            Self::OutOfOrder {
+                partition,
+                previous,
+            } => write!(
+                f,
+                "partition {} is located before partition {}",
+                partition, previous
+            ),
+        }
+    }
+}
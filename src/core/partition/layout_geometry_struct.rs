@@ -0,0 +1,37 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Minimum unit of allocation, in bytes, used by `fdisk` when creating partitions.
+const DEFAULT_GRAIN: u64 = 1024 * 1024;
+
+/// Device geometry a partition table layout is checked against.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct LayoutGeometry {
+    /// Device size in 512-byte sectors.
+    pub(crate) device_size: u64,
+    /// Offset, in bytes, of the device's beginning from its physical alignment.
+    pub(crate) alignment_offset: u64,
+    /// Physical sector size, in bytes.
+    pub(crate) physical_sector_size: u64,
+    /// Optimal I/O size, in bytes (`0` if not reported).
+    pub(crate) optimal_io_size: u64,
+}
+
+impl LayoutGeometry {
+    /// Returns the smallest unallocated region worth reporting, in 512-byte sectors.
+    pub(crate) fn grain(&self) -> u64 {
+        self.optimal_io_size.max(DEFAULT_GRAIN) / 512
+    }
+
+    /// Returns `true` if a partition starting at `sector` is aligned on a multiple of `unit`
+    /// bytes, after adjusting for the device's alignment offset.
+    pub(crate) fn is_aligned(&self, sector: u64, unit: u64) -> bool {
+        unit == 0 || sector.saturating_mul(512) % unit == self.alignment_offset % unit
+    }
+}
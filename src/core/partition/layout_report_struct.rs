@@ -0,0 +1,351 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::Alignment;
+use crate::core::partition::LayoutFinding;
+use crate::core::partition::LayoutGeometry;
+use crate::core::partition::PartitionExtent;
+use crate::core::partition::Severity;
+
+/// Findings of a partition table layout analysis, produced by
+/// [`PartitionTable::analyze`](crate::probe::PartitionTable::analyze).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LayoutReport {
+    findings: Vec<LayoutFinding>,
+}
+
+impl LayoutReport {
+    #[doc(hidden)]
+    /// Checks the layout of `extents`, listed in partition table order, against a device's
+    /// `geometry`.
+    pub(crate) fn analyze(extents: &[PartitionExtent], geometry: &LayoutGeometry) -> LayoutReport {
+        let mut findings = vec![];
+
+        Self::find_gaps(extents, geometry, &mut findings);
+        Self::find_overlaps(extents, &mut findings);
+        Self::find_out_of_bounds(extents, geometry, &mut findings);
+        Self::find_misaligned(extents, geometry, &mut findings);
+        Self::find_out_of_order(extents, &mut findings);
+
+        log::debug!("LayoutReport::analyze findings: {:?}", findings);
+
+        Self { findings }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: reports unallocated space between top-level partitions (i.e. logical
+    /// partitions are ignored, their `Extended` container is not).
+    fn find_gaps(
+        extents: &[PartitionExtent],
+        geometry: &LayoutGeometry,
+        findings: &mut Vec<LayoutFinding>,
+    ) {
+        let grain = geometry.grain();
+        let mut top_level: Vec<_> = extents
+            .iter()
+            .filter(|e| !e.is_logical && e.size > 0)
+            .collect();
+        top_level.sort_by_key(|e| e.start);
+
+        // Like `fdisk`, consider the first grain of the device as reserved for partition tables.
+        let mut cursor = grain.min(geometry.device_size);
+        let ends = top_level
+            .iter()
+            .map(|e| (e.start, e.end()))
+            .chain(std::iter::once((
+                geometry.device_size,
+                geometry.device_size,
+            )));
+
+        for (start, end) in ends {
+            if start > cursor && start - cursor >= grain {
+                findings.push(LayoutFinding::Gap {
+                    location: cursor,
+                    size: start - cursor,
+                });
+            }
+
+            cursor = cursor.max(end);
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: reports partitions sharing sectors, except for logical partitions
+    /// inside an `Extended` partition.
+    fn find_overlaps(extents: &[PartitionExtent], findings: &mut Vec<LayoutFinding>) {
+        for (i, first) in extents.iter().enumerate() {
+            for second in extents.iter().skip(i + 1) {
+                let is_container = (first.is_extended && second.is_logical)
+                    || (first.is_logical && second.is_extended);
+                let shared = first
+                    .end()
+                    .min(second.end())
+                    .saturating_sub(first.start.max(second.start));
+
+                if !is_container && shared > 0 {
+                    findings.push(LayoutFinding::Overlap {
+                        partition: first.number,
+                        other: second.number,
+                        size: shared,
+                    });
+                }
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: reports partitions extending past the end of the device.
+    fn find_out_of_bounds(
+        extents: &[PartitionExtent],
+        geometry: &LayoutGeometry,
+        findings: &mut Vec<LayoutFinding>,
+    ) {
+        for extent in extents {
+            if extent.end() > geometry.device_size {
+                findings.push(LayoutFinding::BeyondDeviceEnd {
+                    partition: extent.number,
+                    excess: extent.end() - geometry.device_size,
+                });
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: reports partitions not starting on a physical sector, or optimal I/O
+    /// size boundary. `Extended` partitions only hold partition tables, and are skipped.
+    fn find_misaligned(
+        extents: &[PartitionExtent],
+        geometry: &LayoutGeometry,
+        findings: &mut Vec<LayoutFinding>,
+    ) {
+        for extent in extents.iter().filter(|e| !e.is_extended) {
+            let alignment = if !geometry.is_aligned(extent.start, geometry.physical_sector_size) {
+                Some(Alignment::Physical)
+            } else if !geometry.is_aligned(extent.start, geometry.optimal_io_size) {
+                Some(Alignment::Optimal)
+            } else {
+                None
+            };
+
+            if let Some(alignment) = alignment {
+                findings.push(LayoutFinding::Misaligned {
+                    partition: extent.number,
+                    location: extent.start,
+                    alignment,
+                });
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: reports partitions located before their predecessor in the table.
+    /// Logical partitions are only compared with each other.
+    fn find_out_of_order(extents: &[PartitionExtent], findings: &mut Vec<LayoutFinding>) {
+        for logical in [false, true] {
+            let mut previous: Option<&PartitionExtent> = None;
+
+            for extent in extents
+                .iter()
+                .filter(|e| e.is_logical == logical && e.size > 0)
+            {
+                if let Some(prev) = previous {
+                    if extent.start < prev.start {
+                        findings.push(LayoutFinding::OutOfOrder {
+                            partition: extent.number,
+                            previous: prev.number,
+                        });
+                    }
+                }
+
+                previous = Some(extent);
+            }
+        }
+    }
+
+    /// Returns all findings.
+    pub fn findings(&self) -> &[LayoutFinding] {
+        &self.findings
+    }
+
+    /// Returns an iterator over the findings at least as serious as `severity`.
+    pub fn findings_with_severity(
+        &self,
+        severity: Severity,
+    ) -> impl Iterator<Item = &LayoutFinding> {
+        self.findings
+            .iter()
+            .filter(move |f| f.severity() >= severity)
+    }
+
+    /// Returns the severity of the most serious finding, `None` if there are no findings.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity()).max()
+    }
+
+    /// Returns `true` if no finding is more serious than [`Severity::Info`].
+    pub fn is_healthy(&self) -> bool {
+        self.max_severity() <= Some(Severity::Info)
+    }
+
+    /// Returns the total number of unallocated sectors reported as gaps.
+    pub fn unallocated_sectors(&self) -> u64 {
+        self.findings
+            .iter()
+            .map(|f| match f {
+                LayoutFinding::Gap { size, .. } => *size,
+                _otherwise => 0,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    // 1 GiB device, 4K physical sectors, no optimal I/O size.
+    const GEOMETRY: LayoutGeometry = LayoutGeometry {
+        device_size: 2097152,
+        alignment_offset: 0,
+        physical_sector_size: 4096,
+        optimal_io_size: 0,
+    };
+
+    fn primary(number: usize, start: u64, size: u64) -> PartitionExtent {
+        PartitionExtent {
+            number,
+            start,
+            size,
+            is_extended: false,
+            is_logical: false,
+        }
+    }
+
+    #[test]
+    fn layout_report_finds_nothing_in_a_well_formed_layout() {
+        let extents = [primary(1, 2048, 1046528), primary(2, 1048576, 1048576)];
+        let report = LayoutReport::analyze(&extents, &GEOMETRY);
+
+        assert!(report.findings().is_empty());
+        assert_eq!(report.max_severity(), None);
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn layout_report_finds_gaps_larger_than_the_allocation_grain() {
+        let extents = [primary(1, 2048, 2048), primary(2, 8192, 2048)];
+        let report = LayoutReport::analyze(&extents, &GEOMETRY);
+
+        let expected = [
+            LayoutFinding::Gap {
+                location: 4096,
+                size: 4096,
+            },
+            LayoutFinding::Gap {
+                location: 10240,
+                size: 2086912,
+            },
+        ];
+        assert_eq!(report.findings(), &expected);
+        assert_eq!(report.unallocated_sectors(), 2091008);
+        assert!(report.is_healthy());
+    }
+
+    #[test]
+    fn layout_report_finds_overlapping_and_out_of_bounds_partitions() {
+        let extents = [primary(1, 2048, 1048576), primary(2, 1048576, 1050624)];
+        let report = LayoutReport::analyze(&extents, &GEOMETRY);
+
+        let expected = [
+            LayoutFinding::Overlap {
+                partition: 1,
+                other: 2,
+                size: 2048,
+            },
+            LayoutFinding::BeyondDeviceEnd {
+                partition: 2,
+                excess: 2048,
+            },
+        ];
+        assert_eq!(report.findings(), &expected);
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn layout_report_ignores_logical_partitions_inside_their_container() {
+        let extended = PartitionExtent {
+            number: 1,
+            start: 2048,
+            size: 2095104,
+            is_extended: true,
+            is_logical: false,
+        };
+        let logical = |number, start, size| PartitionExtent {
+            number,
+            start,
+            size,
+            is_extended: false,
+            is_logical: true,
+        };
+        let extents = [
+            extended,
+            logical(5, 4096, 1042432),
+            logical(6, 1048576, 1048576),
+        ];
+        let report = LayoutReport::analyze(&extents, &GEOMETRY);
+
+        assert!(report.findings().is_empty());
+    }
+
+    #[test]
+    fn layout_report_finds_misaligned_and_out_of_order_partitions() {
+        let geometry = LayoutGeometry {
+            optimal_io_size: 2097152,
+            ..GEOMETRY
+        };
+        let extents = [primary(1, 1050624, 1046528), primary(2, 63, 1048513)];
+        let report = LayoutReport::analyze(&extents, &geometry);
+
+        let expected = [
+            LayoutFinding::Misaligned {
+                partition: 1,
+                location: 1050624,
+                alignment: Alignment::Optimal,
+            },
+            LayoutFinding::Misaligned {
+                partition: 2,
+                location: 63,
+                alignment: Alignment::Physical,
+            },
+            LayoutFinding::OutOfOrder {
+                partition: 2,
+                previous: 1,
+            },
+        ];
+        assert_eq!(report.findings(), &expected);
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+
+        let actual: Vec<_> = report.findings_with_severity(Severity::Warning).collect();
+        assert_eq!(actual, vec![&expected[1]]);
+    }
+
+    #[test]
+    fn layout_report_takes_the_alignment_offset_into_account() {
+        let geometry = LayoutGeometry {
+            alignment_offset: 3584,
+            ..GEOMETRY
+        };
+        let extents = [primary(1, 2055, 2095097)];
+        let report = LayoutReport::analyze(&extents, &geometry);
+
+        assert!(report.findings().is_empty());
+    }
+}
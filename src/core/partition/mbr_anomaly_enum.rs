@@ -0,0 +1,116 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::partition::Severity;
+
+/// An irregularity in the Master Boot Record of a GPT disk, reported by
+/// [`GptMbrConsistency`](crate::core::partition::GptMbrConsistency).
+///
+/// Entries are numbered from `1` to `4`; locations and sizes are expressed in logical sectors.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MbrAnomaly {
+    /// The first sector does not end with the `0x55 0xAA` boot signature.
+    MissingSignature,
+    /// No entry has the GPT protective type (`0xEE`).
+    NoProtectiveEntry,
+    /// More than one entry has the GPT protective type.
+    MultipleProtectiveEntries { count: usize },
+    /// The protective entry does not start at sector `1`, where the GPT header is.
+    ProtectiveEntryWrongStart { entry: usize, start: u64 },
+    /// The protective entry of a (non-hybrid) protective MBR does not cover the whole disk.
+    ProtectiveEntryWrongSize {
+        entry: usize,
+        size: u64,
+        expected: u64,
+    },
+    /// The protective entry is marked bootable, which the UEFI specification forbids, but some
+    /// legacy BIOSes require.
+    ProtectiveEntryBootable { entry: usize },
+    /// A hybrid entry does not match the location and size of any GPT partition.
+    HybridEntryMismatch { entry: usize, start: u64, size: u64 },
+    /// An entry extends past the end of the device.
+    EntryBeyondDeviceEnd { entry: usize },
+    /// Two entries share sectors.
+    OverlappingEntries { entry: usize, other: usize },
+}
+
+impl MbrAnomaly {
+    /// Returns how serious this `MbrAnomaly` is.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::ProtectiveEntryBootable { .. } => Severity::Info,
+            Self::MultipleProtectiveEntries { .. } | Self::ProtectiveEntryWrongSize { .. } => {
+                Severity::Warning
+            }
+            Self::MissingSignature
+            | Self::NoProtectiveEntry
+            | Self::ProtectiveEntryWrongStart { .. }
+            | Self::HybridEntryMismatch { .. }
+            | Self::EntryBeyondDeviceEnd { .. }
+            | Self::OverlappingEntries { .. } => Severity::Error,
+        }
+    }
+
+    /// Returns a short identifier of this `MbrAnomaly`'s kind, suitable as a metric or alert
+    /// label.
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::MissingSignature => "missing-signature",
+            Self::NoProtectiveEntry => "no-protective-entry",
+            Self::MultipleProtectiveEntries { .. } => "multiple-protective-entries",
+            Self::ProtectiveEntryWrongStart { .. } => "protective-entry-wrong-start",
+            Self::ProtectiveEntryWrongSize { .. } => "protective-entry-wrong-size",
+            Self::ProtectiveEntryBootable { .. } => "protective-entry-bootable",
+            Self::HybridEntryMismatch { .. } => "hybrid-entry-mismatch",
+            Self::EntryBeyondDeviceEnd { .. } => "entry-beyond-device-end",
+            Self::OverlappingEntries { .. } => "overlapping-entries",
+        }
+    }
+}
+
+impl fmt::Display for MbrAnomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingSignature => write!(f, "missing MBR boot signature"),
+            Self::NoProtectiveEntry => write!(f, "no GPT protective entry"),
+            Self::MultipleProtectiveEntries { count } => {
+                write!(f, "{} GPT protective entries", count)
+            }
+            Self::ProtectiveEntryWrongStart { entry, start } => write!(
+                f,
+                "protective entry {} starts at sector {} instead of 1",
+                entry, start
+            ),
+            Self::ProtectiveEntryWrongSize {
+                entry,
+                size,
+                expected,
+            } => write!(
+                f,
+                "protective entry {} covers {} sectors instead of {}",
+                entry, size, expected
+            ),
+            Self::ProtectiveEntryBootable { entry } => {
+                write!(f, "protective entry {} is marked bootable", entry)
+            }
+            Self::HybridEntryMismatch { entry, start, size } => write!(
+                f,
+                "entry {} ({} sectors at sector {}) does not match any GPT partition",
+                entry, size, start
+            ),
+            Self::EntryBeyondDeviceEnd { entry } => {
+                write!(f, "entry {} extends past the end of the device", entry)
+            }
+            Self::OverlappingEntries { entry, other } => {
+                write!(f, "entries {} and {} overlap", entry, other)
+            }
+        }
+    }
+}
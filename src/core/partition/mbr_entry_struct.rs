@@ -0,0 +1,158 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::OSType;
+
+/// Offset of the partition entries in a Master Boot Record.
+const MBR_ENTRIES_OFFSET: usize = 446;
+
+/// Size in bytes of a Master Boot Record partition entry.
+const MBR_ENTRY_SIZE: usize = 16;
+
+/// An entry in the partition table of a Master Boot Record (MBR).
+///
+/// Locations and sizes are expressed in logical sectors (LBA).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct MbrEntry {
+    boot_indicator: u8,
+    os_type: u8,
+    start: u32,
+    size: u32,
+}
+
+impl MbrEntry {
+    /// Creates a new `MbrEntry`.
+    pub fn new(bootable: bool, os_type: u8, start: u32, size: u32) -> MbrEntry {
+        Self {
+            boot_indicator: if bootable { 0x80 } else { 0x00 },
+            os_type,
+            start,
+            size,
+        }
+    }
+
+    /// Parses a 16-byte MBR partition entry.
+    pub fn from_bytes(bytes: &[u8; 16]) -> MbrEntry {
+        Self {
+            boot_indicator: bytes[0],
+            os_type: bytes[4],
+            start: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            size: u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]),
+        }
+    }
+
+    /// Parses the four partition entries of a Master Boot Record, returns `None` if `sector` is
+    /// shorter than 512 bytes, or does not end with the `0x55 0xAA` boot signature.
+    pub fn parse_table(sector: &[u8]) -> Option<[MbrEntry; 4]> {
+        if sector.len() < 512 || sector[510..512] != [0x55, 0xaa] {
+            return None;
+        }
+
+        let mut entries = [MbrEntry::default(); 4];
+        for (i, entry) in entries.iter_mut().enumerate() {
+            let offset = MBR_ENTRIES_OFFSET + i * MBR_ENTRY_SIZE;
+            let bytes: &[u8; 16] = sector[offset..offset + MBR_ENTRY_SIZE].try_into().ok()?;
+            *entry = MbrEntry::from_bytes(bytes);
+        }
+
+        Some(entries)
+    }
+
+    /// Serializes this `MbrEntry` to its 16-byte on-disk format. CHS addresses are set to their
+    /// maximum value (`1023/254/63`), as recommended for LBA-addressed partitions.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0] = self.boot_indicator;
+
+        if !self.is_empty() {
+            bytes[1..4].copy_from_slice(&[0xfe, 0xff, 0xff]);
+            bytes[5..8].copy_from_slice(&[0xfe, 0xff, 0xff]);
+        }
+
+        bytes[4] = self.os_type;
+        bytes[8..12].copy_from_slice(&self.start.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.size.to_le_bytes());
+
+        bytes
+    }
+
+    /// Returns `true` if this entry is marked as bootable (active).
+    pub fn is_bootable(&self) -> bool {
+        self.boot_indicator & 0x80 != 0
+    }
+
+    /// Returns the raw partition type identifier.
+    pub fn os_type_id(&self) -> u8 {
+        self.os_type
+    }
+
+    /// Returns the partition type, if supported.
+    pub fn os_type(&self) -> Option<OSType> {
+        OSType::try_from(self.os_type).ok()
+    }
+
+    /// Returns the location of the partition's first sector.
+    pub fn start(&self) -> u64 {
+        self.start as u64
+    }
+
+    /// Returns the partition's size in sectors.
+    pub fn size(&self) -> u64 {
+        self.size as u64
+    }
+
+    /// Returns the sector following the partition's last sector.
+    pub fn end(&self) -> u64 {
+        self.start() + self.size()
+    }
+
+    /// Returns `true` if this entry is unused.
+    pub fn is_empty(&self) -> bool {
+        self.os_type == 0x00
+    }
+
+    /// Returns `true` if this entry is a GPT protective entry (type `0xEE`).
+    pub fn is_protective(&self) -> bool {
+        self.os_type == 0xee
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn mbr_entry_round_trips_through_bytes() {
+        let entry = MbrEntry::new(true, 0x83, 2048, 204800);
+        let bytes = entry.to_bytes();
+
+        assert_eq!(bytes[0], 0x80);
+        assert_eq!(bytes[4], 0x83);
+        assert_eq!(MbrEntry::from_bytes(&bytes), entry);
+        assert_eq!(entry.os_type(), Some(OSType::Linux));
+        assert_eq!(entry.end(), 206848);
+    }
+
+    #[test]
+    fn mbr_entry_parses_a_partition_table_with_a_boot_signature() {
+        let mut sector = [0u8; 512];
+        sector[446..462].copy_from_slice(&MbrEntry::new(false, 0xee, 1, 0xffffffff).to_bytes());
+
+        assert_eq!(MbrEntry::parse_table(&sector), None);
+
+        sector[510] = 0x55;
+        sector[511] = 0xaa;
+        let entries = MbrEntry::parse_table(&sector).unwrap();
+
+        assert!(entries[0].is_protective());
+        assert_eq!(entries[0].size(), 0xffffffff);
+        assert!(entries[1..].iter().all(|e| e.is_empty()));
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Kinds of Master Boot Record found in the first sector of a GPT disk.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MbrKind {
+    /// A protective MBR, i.e. a single `0xEE` entry shielding the GPT from legacy tools.
+    Protective,
+    /// A hybrid MBR, i.e. a `0xEE` entry alongside entries mirroring some GPT partitions for
+    /// legacy operating systems.
+    Hybrid,
+    /// A legacy MBR without any `0xEE` entry, left over or written over the GPT.
+    Foreign,
+    /// No MBR boot signature in the first sector.
+    Missing,
+}
+
+impl MbrKind {
+    /// View this `MbrKind` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Protective => "protective",
+            Self::Hybrid => "hybrid",
+            Self::Foreign => "foreign",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+impl AsRef<str> for MbrKind {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for MbrKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
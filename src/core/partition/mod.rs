@@ -8,22 +8,68 @@
 // From standard library
 
 // From this library
+pub use alignment_enum::Alignment;
+pub use arch_enum::Arch;
+pub use bsd_fs_type_enum::BsdFsType;
+pub use codepage_enum::Codepage;
+pub use decoded_label_struct::DecodedLabel;
+pub use decoding_confidence_enum::DecodingConfidence;
+pub use dps_role_enum::DpsRole;
 pub use endian_enum::Endian;
 pub use file_system_enum::FileSystem;
+pub use file_system_info_struct::FileSystemInfo;
+pub use gpt_mbr_consistency_struct::GptMbrConsistency;
 pub use guid_enum::Guid;
+pub use guid_info_struct::GuidInfo;
+pub use label_charset_enum::LabelCharset;
+pub use label_encoding_enum::LabelEncoding;
+pub use layout_finding_enum::LayoutFinding;
+pub(crate) use layout_geometry_struct::LayoutGeometry;
+pub use layout_report_struct::LayoutReport;
+pub use mbr_anomaly_enum::MbrAnomaly;
+pub use mbr_entry_struct::MbrEntry;
+pub use mbr_kind_enum::MbrKind;
+pub use os_family_enum::OsFamily;
 pub use os_type_enum::OSType;
 pub use partition_bitflags_struct::PartitionBitflags;
+pub(crate) use partition_extent_struct::PartitionExtent;
 pub use partition_table_type_enum::PartitionTableType;
 pub use partition_type_enum::PartitionType;
 pub use raw_bytes_struct::RawBytes;
+pub use severity_enum::Severity;
+pub use sgi_type_enum::SgiType;
+pub use sun_tag_enum::SunTag;
 pub use unix_timestamp_struct::UnixTimestamp;
 
+mod alignment_enum;
+mod arch_enum;
+mod bsd_fs_type_enum;
+mod codepage_enum;
+mod decoded_label_struct;
+mod decoding_confidence_enum;
+mod dps_role_enum;
 mod endian_enum;
 mod file_system_enum;
+mod file_system_info_struct;
+mod gpt_mbr_consistency_struct;
 mod guid_enum;
+mod guid_info_struct;
+mod label_charset_enum;
+mod label_encoding_enum;
+mod layout_finding_enum;
+mod layout_geometry_struct;
+mod layout_report_struct;
+mod mbr_anomaly_enum;
+mod mbr_entry_struct;
+mod mbr_kind_enum;
+mod os_family_enum;
 mod os_type_enum;
 mod partition_bitflags_struct;
+mod partition_extent_struct;
 mod partition_table_type_enum;
 mod partition_type_enum;
 mod raw_bytes_struct;
+mod severity_enum;
+mod sgi_type_enum;
+mod sun_tag_enum;
 mod unix_timestamp_struct;
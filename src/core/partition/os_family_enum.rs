@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// Operating systems, or software projects, that define partition types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum OsFamily {
+    /// Partition types shared by all operating systems (e.g. EFI System partition).
+    Generic,
+    Android,
+    AtariTos,
+    Barebox,
+    Ceph,
+    ChromeOs,
+    CoreOs,
+    FreeBsd,
+    Fuchsia,
+    Haiku,
+    HpUx,
+    Linux,
+    MacOs,
+    MidnightBsd,
+    Minix,
+    NetBsd,
+    Onie,
+    OpenBsd,
+    Os2,
+    Plan9,
+    Qnx,
+    SoftRaid,
+    Solaris,
+    Spdk,
+    UBoot,
+    VeraCrypt,
+    VMware,
+    Windows,
+}
+
+impl OsFamily {
+    /// View this `OsFamily` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Generic => "generic",
+            Self::Android => "Android",
+            Self::AtariTos => "Atari TOS",
+            Self::Barebox => "barebox",
+            Self::Ceph => "Ceph",
+            Self::ChromeOs => "ChromeOS",
+            Self::CoreOs => "CoreOS",
+            Self::FreeBsd => "FreeBSD",
+            Self::Fuchsia => "Fuchsia",
+            Self::Haiku => "Haiku",
+            Self::HpUx => "HP-UX",
+            Self::Linux => "Linux",
+            Self::MacOs => "macOS",
+            Self::MidnightBsd => "MidnightBSD",
+            Self::Minix => "Minix",
+            Self::NetBsd => "NetBSD",
+            Self::Onie => "ONIE",
+            Self::OpenBsd => "OpenBSD",
+            Self::Os2 => "OS/2",
+            Self::Plan9 => "Plan 9",
+            Self::Qnx => "QNX",
+            Self::SoftRaid => "SoftRAID",
+            Self::Solaris => "Solaris",
+            Self::Spdk => "SPDK",
+            Self::UBoot => "U-Boot",
+            Self::VeraCrypt => "VeraCrypt",
+            Self::VMware => "VMware",
+            Self::Windows => "Windows",
+        }
+    }
+}
+
+impl fmt::Display for OsFamily {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
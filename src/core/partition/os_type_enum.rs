@@ -12,6 +12,7 @@
 // From this library
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
+use crate::core::partition::FileSystem;
 
 /// Supported MBR partitions.
 #[derive(
@@ -23,7 +24,7 @@ pub enum OSType {
     /// Empty partition entry.
     EmptyPartition = 0x00,
 
-    /// XENIX root.
+    /// FAT12.
     FAT12 = 0x01,
 
     /// XENIX root.
@@ -431,6 +432,249 @@ pub fn as_str(&self) -> &str {
             Self::XenixBadBlockTable => "0xff",
         }
     }
+
+    /// Returns the name `sfdisk` and `fdisk` give to this partition type (e.g. `W95 FAT32 (LBA)`).
+    pub fn sfdisk_name(&self) -> &'static str {
+        match self {
+            Self::EmptyPartition => "Empty",
+            Self::FAT12 => "FAT12",
+            Self::XenixRoot => "XENIX root",
+            Self::XenixUser => "XENIX usr",
+            Self::FAT16 => "FAT16 <32M",
+            Self::ExtendedPartition => "Extended",
+            Self::FAT16B => "FAT16",
+            Self::HPFSNTFSExfat => "HPFS/NTFS/exFAT",
+            Self::AIX => "AIX",
+            Self::AIXBootable => "AIX bootable",
+            Self::OS2BootManager => "OS/2 Boot Manager",
+            Self::W95FAT32 => "W95 FAT32",
+            Self::W95FAT32LBA => "W95 FAT32 (LBA)",
+            Self::W95FAT16LBA => "W95 FAT16 (LBA)",
+            Self::W95ExtendedLBA => "W95 Ext'd (LBA)",
+            Self::OPUS => "OPUS",
+            Self::HiddenFAT12 => "Hidden FAT12",
+            Self::CompaqDiagnostics => "Compaq diagnostics",
+            Self::HiddenFAT16 => "Hidden FAT16 <32M",
+            Self::HiddenFAT16B => "Hidden FAT16",
+            Self::HiddenHPFSNTFSExFat => "Hidden HPFS/NTFS",
+            Self::ASTSmartSleep => "AST SmartSleep",
+            Self::HiddenW95FAT32 => "Hidden W95 FAT32",
+            Self::HiddenW95FAT32LBA => "Hidden W95 FAT32 (LBA)",
+            Self::HiddenW95FAT16LBA => "Hidden W95 FAT16 (LBA)",
+            Self::NecDOS => "NEC DOS",
+            Self::HiddenNTFSRescue => "Hidden NTFS WinRE",
+            Self::Plan9 => "Plan 9",
+            Self::PartitionMagic => "PartitionMagic recovery",
+            Self::Venix80286 => "Venix 80286",
+            Self::PPCPrepBoot => "PPC PReP Boot",
+            Self::Sfs => "SFS",
+            Self::QNX4Primary => "QNX4.x",
+            Self::QNX4Secondary => "QNX4.x 2nd part",
+            Self::QNX4Tertiary => "QNX4.x 3rd part",
+            Self::OnTrackDM => "OnTrack DM",
+            Self::OnTrackDM6Aux1 => "OnTrack DM6 Aux1",
+            Self::CPM80 => "CP/M",
+            Self::OnTrackDM6Aux3 => "OnTrack DM6 Aux3",
+            Self::OnTrackDM6Ddo => "OnTrackDM6",
+            Self::EZDrive => "EZ-Drive",
+            Self::GoldenBow => "Golden Bow",
+            Self::PriamEDisk => "Priam Edisk",
+            Self::SpeedStor => "SpeedStor",
+            Self::GNUHurdSystemV => "GNU HURD or SysV",
+            Self::NovellNetware286 => "Novell Netware 286",
+            Self::NovellNetware386 => "Novell Netware 386",
+            Self::DiskSecureMultiBoot => "DiskSecure Multi-Boot",
+            Self::PCIX => "PC/IX",
+            Self::OldMinix => "Old Minix",
+            Self::MinixOldLinux => "Minix / old Linux",
+            Self::LinuxSwap => "Linux swap / Solaris",
+            Self::Linux => "Linux",
+            Self::OS2HiddenCDrive => "OS/2 hidden or Intel hibernation",
+            Self::LinuxExtended => "Linux extended",
+            Self::FAT16VolumeSet => "NTFS volume set",
+            Self::NTFSVolumeSet => "NTFS volume set",
+            Self::LinuxPlaintext => "Linux plaintext",
+            Self::LinuxLVM => "Linux LVM",
+            Self::Amoeba => "Amoeba",
+            Self::AmoebaBadBlockTable => "Amoeba BBT",
+            Self::BSDOs => "BSD/OS",
+            Self::IBMThinkpad => "IBM Thinkpad hibernation",
+            Self::FreeBSD => "FreeBSD",
+            Self::OpenBSD => "OpenBSD",
+            Self::NextStep => "NeXTSTEP",
+            Self::DarwinUFS => "Darwin UFS",
+            Self::NetBSD => "NetBSD",
+            Self::DarwinBoot => "Darwin boot",
+            Self::HFSHFSPlus => "HFS / HFS+",
+            Self::BSDIFs => "BSDI fs",
+            Self::BSDISwap => "BSDI swap",
+            Self::BootWizardHidden => "Boot Wizard hidden",
+            Self::AcronisFAT32LBA => "Acronis FAT32 LBA",
+            Self::SolarisBoot => "Solaris boot",
+            Self::Solaris => "Solaris",
+            Self::DRDOSSecuredFAT12 => "DRDOS/sec (FAT-12)",
+            Self::DRDOSSecuredFAT16 => "DRDOS/sec (FAT-16 < 32M)",
+            Self::DRDOSSecuredFAT16B => "DRDOS/sec (FAT-16)",
+            Self::Syrinx => "Syrinx",
+            Self::NonFsData => "Non-FS data",
+            Self::CPMCtOs => "CP/M / CTOS / ...",
+            Self::DellUtilityFAT16 => "Dell Utility",
+            Self::BootIt => "BootIt",
+            Self::DOSAccess => "DOS access",
+            Self::DOSRO => "DOS R/O",
+            Self::SpeedStorFAT16 => "SpeedStor",
+            Self::FreedesktopBoot => "Linux extended boot",
+            Self::BeOSBFS => "BeOS fs",
+            Self::GPTProtectiveMBR => "GPT",
+            Self::EfiSystem => "EFI (FAT-12/16/32)",
+            Self::PARISCLinux => "Linux/PA-RISC boot",
+            Self::SDSpeedstor => "SpeedStor",
+            Self::SpeedStorFAT16B => "SpeedStor",
+            Self::DOSSecondary => "DOS secondary",
+            Self::EBBRProtective => "EBBR protective",
+            Self::VMWareVMFS => "VMware VMFS",
+            Self::VMWareVMKCORE => "VMware VMKCORE",
+            Self::LinuxRaidAuto => "Linux raid autodetect",
+            Self::LanStep => "LANstep",
+            Self::XenixBadBlockTable => "BBT",
+        }
+    }
+
+    /// Returns `true` if this is the type of an extended partition, i.e. a container of logical
+    /// partitions (`0x05`, `0x0f`, `0x85`).
+    pub fn is_extended(&self) -> bool {
+        matches!(
+            self,
+            Self::ExtendedPartition | Self::W95ExtendedLBA | Self::LinuxExtended
+        )
+    }
+
+    /// Returns `true` if this is the type of a partition hidden from DOS/Windows by setting bit
+    /// `0x10` of a visible type (e.g. `0x1b` hides `0x0b`), or the type of a hidden `NTFS`
+    /// Windows recovery partition (`0x27`).
+    pub fn is_hidden(&self) -> bool {
+        self.unhidden().is_some()
+    }
+
+    /// Returns the visible counterpart of a hidden partition type (e.g. `0x0b` for `0x1b`),
+    /// `None` if this type is not hidden.
+    ///
+    /// `0x27` is the exception, its visible counterpart `0x07` (`NTFS`) differs by more than bit
+    /// `0x10`. Conversely, [`OSType::hidden`] maps `0x07` to `0x17`.
+    pub fn unhidden(&self) -> Option<OSType> {
+        match self {
+            Self::HiddenFAT12 => Some(Self::FAT12),
+            Self::HiddenFAT16 => Some(Self::FAT16),
+            Self::HiddenFAT16B => Some(Self::FAT16B),
+            Self::HiddenHPFSNTFSExFat => Some(Self::HPFSNTFSExfat),
+            Self::HiddenW95FAT32 => Some(Self::W95FAT32),
+            Self::HiddenW95FAT32LBA => Some(Self::W95FAT32LBA),
+            Self::HiddenW95FAT16LBA => Some(Self::W95FAT16LBA),
+            Self::HiddenNTFSRescue => Some(Self::HPFSNTFSExfat),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the hidden counterpart of a visible partition type (e.g. `0x1b` for `0x0b`),
+    /// `None` if this type can not be hidden.
+    pub fn hidden(&self) -> Option<OSType> {
+        match self {
+            Self::FAT12 => Some(Self::HiddenFAT12),
+            Self::FAT16 => Some(Self::HiddenFAT16),
+            Self::FAT16B => Some(Self::HiddenFAT16B),
+            Self::HPFSNTFSExfat => Some(Self::HiddenHPFSNTFSExFat),
+            Self::W95FAT32 => Some(Self::HiddenW95FAT32),
+            Self::W95FAT32LBA => Some(Self::HiddenW95FAT32LBA),
+            Self::W95FAT16LBA => Some(Self::HiddenW95FAT16LBA),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns `true` if partitions of this type are accessed with Logical Block Addressing (LBA)
+    /// rather than Cylinder-Head-Sector (CHS) addressing.
+    pub fn is_lba(&self) -> bool {
+        matches!(
+            self,
+            Self::W95FAT32LBA
+                | Self::W95FAT16LBA
+                | Self::W95ExtendedLBA
+                | Self::HiddenW95FAT32LBA
+                | Self::HiddenW95FAT16LBA
+                | Self::AcronisFAT32LBA
+        )
+    }
+
+    /// Returns `true` if this is the type of an EFI System partition (`0xef`).
+    pub fn is_efi(&self) -> bool {
+        matches!(self, Self::EfiSystem)
+    }
+
+    /// Returns `true` if this is the type of the partition in a protective MBR, covering a disk
+    /// with a GPT partition table (`0xee`).
+    pub fn is_protective(&self) -> bool {
+        matches!(self, Self::GPTProtectiveMBR)
+    }
+
+    /// Returns `true` if this partition type is used by Linux (data, swap, LVM, RAID, extended,
+    /// and boot partitions).
+    pub fn is_linux_family(&self) -> bool {
+        matches!(
+            self,
+            Self::LinuxSwap
+                | Self::Linux
+                | Self::LinuxExtended
+                | Self::LinuxPlaintext
+                | Self::LinuxLVM
+                | Self::FreedesktopBoot
+                | Self::PARISCLinux
+                | Self::LinuxRaidAuto
+        )
+    }
+
+    /// Returns the file systems, or volume formats, partitions of this type are expected to
+    /// hold. An empty slice means the type gives no hint (e.g. `Linux` partitions can hold any
+    /// file system).
+    pub fn file_system_hints(&self) -> &'static [FileSystem] {
+        match self {
+            Self::FAT12
+            | Self::FAT16
+            | Self::FAT16B
+            | Self::W95FAT32
+            | Self::W95FAT32LBA
+            | Self::W95FAT16LBA
+            | Self::HiddenFAT12
+            | Self::CompaqDiagnostics
+            | Self::HiddenFAT16
+            | Self::HiddenFAT16B
+            | Self::HiddenW95FAT32
+            | Self::HiddenW95FAT32LBA
+            | Self::HiddenW95FAT16LBA
+            | Self::NecDOS
+            | Self::FAT16VolumeSet
+            | Self::AcronisFAT32LBA
+            | Self::DRDOSSecuredFAT12
+            | Self::DRDOSSecuredFAT16
+            | Self::DRDOSSecuredFAT16B
+            | Self::DellUtilityFAT16
+            | Self::EfiSystem => &[FileSystem::VFAT],
+            Self::HPFSNTFSExfat | Self::HiddenHPFSNTFSExFat => {
+                &[FileSystem::NTFS, FileSystem::ExFAT, FileSystem::HPFS]
+            }
+            Self::HiddenNTFSRescue => &[FileSystem::NTFS],
+            Self::NTFSVolumeSet => &[FileSystem::NTFS, FileSystem::HPFS],
+            Self::XenixRoot | Self::XenixUser => &[FileSystem::Xenix],
+            Self::GNUHurdSystemV => &[FileSystem::SYSV],
+            Self::OldMinix | Self::MinixOldLinux => &[FileSystem::Minix],
+            Self::LinuxSwap => &[FileSystem::Swap, FileSystem::SwapSuspend],
+            Self::LinuxLVM => &[FileSystem::LVM2, FileSystem::LVM1],
+            Self::LinuxRaidAuto => &[FileSystem::LinuxRaid],
+            Self::FreeBSD | Self::OpenBSD | Self::NetBSD | Self::DarwinUFS => &[FileSystem::UFS],
+            Self::HFSHFSPlus => &[FileSystem::HFSPlus, FileSystem::HFS],
+            Self::BeOSBFS => &[FileSystem::BeFS],
+            Self::VMWareVMFS => &[FileSystem::VMFS],
+            _otherwise => &[],
+        }
+    }
 }
 
 impl AsRef<OSType> for OSType {
@@ -1094,4 +1338,50 @@ fn os_type_can_parse_a_valid_device_os_type() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn os_type_can_classify_partition_types() {
+        assert!(OSType::W95ExtendedLBA.is_extended());
+        assert!(OSType::W95ExtendedLBA.is_lba());
+        assert!(!OSType::W95FAT32.is_lba());
+
+        assert!(OSType::HiddenW95FAT32.is_hidden());
+        assert_eq!(OSType::HiddenW95FAT32.unhidden(), Some(OSType::W95FAT32));
+        assert_eq!(OSType::W95FAT32.hidden(), Some(OSType::HiddenW95FAT32));
+        assert_eq!(OSType::Linux.unhidden(), None);
+
+        // Not hidden by setting bit `0x10`.
+        assert!(OSType::HiddenNTFSRescue.is_hidden());
+        assert_eq!(
+            OSType::HiddenNTFSRescue.unhidden(),
+            Some(OSType::HPFSNTFSExfat)
+        );
+        assert_eq!(
+            OSType::HPFSNTFSExfat.hidden(),
+            Some(OSType::HiddenHPFSNTFSExFat)
+        );
+
+        assert!(OSType::EfiSystem.is_efi());
+        assert!(OSType::GPTProtectiveMBR.is_protective());
+        assert!(OSType::LinuxLVM.is_linux_family());
+        assert!(!OSType::FreeBSD.is_linux_family());
+    }
+
+    #[test]
+    fn os_type_hidden_types_round_trip() {
+        for os_type in enum_iterator::all::<OSType>() {
+            if let Some(hidden) = os_type.hidden() {
+                assert_eq!(hidden.unhidden(), Some(os_type));
+                assert_eq!(u8::from(hidden), u8::from(os_type) | 0x10);
+            }
+        }
+    }
+
+    #[test]
+    fn os_type_has_sfdisk_names_and_file_system_hints() {
+        assert_eq!(OSType::W95FAT32LBA.sfdisk_name(), "W95 FAT32 (LBA)");
+        assert_eq!(OSType::LinuxRaidAuto.sfdisk_name(), "Linux raid autodetect");
+        assert_eq!(OSType::EfiSystem.file_system_hints(), &[FileSystem::VFAT]);
+        assert!(OSType::Linux.file_system_hints().is_empty());
+    }
 }
@@ -23,6 +23,11 @@ impl PartitionBitflags {
     pub fn as_str(&self) -> &str {
         &self.bitflags_str
     }
+
+    /// Returns the raw value of these `PartitionBitflags`.
+    pub fn bits(&self) -> u64 {
+        self.bitflags
+    }
 }
 
 impl AsRef<PartitionBitflags> for PartitionBitflags {
@@ -0,0 +1,25 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Location of a partition on a device, in 512-byte sectors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct PartitionExtent {
+    pub(crate) number: usize,
+    pub(crate) start: u64,
+    pub(crate) size: u64,
+    pub(crate) is_extended: bool,
+    pub(crate) is_logical: bool,
+}
+
+impl PartitionExtent {
+    /// Returns the sector following the last sector of the partition.
+    pub(crate) fn end(&self) -> u64 {
+        self.start.saturating_add(self.size)
+    }
+}
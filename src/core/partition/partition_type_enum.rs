@@ -11,8 +11,12 @@
 use crate::core::errors::ConversionError;
 use crate::core::errors::ParserError;
 
+use crate::core::partition::BsdFsType;
 use crate::core::partition::Guid;
 use crate::core::partition::OSType;
+use crate::core::partition::PartitionTableType;
+use crate::core::partition::SgiType;
+use crate::core::partition::SunTag;
 
 /// Supported partition types.
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -20,14 +24,75 @@
 pub enum PartitionType {
     MBR(OSType),
     GPT(Guid),
+    BSD(BsdFsType),
+    Sun(SunTag),
+    SGI(SgiType),
 }
 
 impl PartitionType {
+    /// Converts the raw type of an entry in a partition table of type `table_type` to a
+    /// `PartitionType`.
+    ///
+    /// `type_id` is the numerical type `libblkid` reports for `DOS`, `BSD`, `Sun`, and `SGI`
+    /// partitions, `type_string` the textual type it reports for `GPT` partitions. Returns `None`
+    /// for unknown types, or partition tables without a typed representation (e.g. `Mac`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsblkid::core::partition::{Guid, OSType, PartitionTableType, PartitionType, SunTag};
+    ///
+    /// let dos = PartitionType::for_table_entry(&PartitionTableType::DOS, 0x83, None);
+    /// assert_eq!(dos, Some(PartitionType::MBR(OSType::Linux)));
+    ///
+    /// let sun = PartitionType::for_table_entry(&PartitionTableType::Sun, 0x02, None);
+    /// assert_eq!(sun, Some(PartitionType::Sun(SunTag::Root)));
+    ///
+    /// let esp = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b";
+    /// let gpt = PartitionType::for_table_entry(&PartitionTableType::GPT, 0, Some(esp));
+    /// assert_eq!(gpt, Some(PartitionType::GPT(Guid::EfiSystem)));
+    /// ```
+    pub fn for_table_entry(
+        table_type: &PartitionTableType,
+        type_id: i32,
+        type_string: Option<&str>,
+    ) -> Option<PartitionType> {
+        match table_type {
+            PartitionTableType::DOS | PartitionTableType::Minix => u8::try_from(type_id)
+                .ok()
+                .and_then(|id| OSType::try_from(id).ok())
+                .map(Self::MBR),
+            PartitionTableType::GPT => type_string
+                .and_then(|s| Guid::from_str(s).ok())
+                .map(Self::GPT),
+            PartitionTableType::BSD
+            | PartitionTableType::FreeBSD
+            | PartitionTableType::NetBSD
+            | PartitionTableType::OpenBSD => u8::try_from(type_id)
+                .ok()
+                .and_then(|id| BsdFsType::try_from(id).ok())
+                .map(Self::BSD),
+            PartitionTableType::Sun | PartitionTableType::SolarisX86 => u16::try_from(type_id)
+                .ok()
+                .and_then(|id| SunTag::try_from(id).ok())
+                .map(Self::Sun),
+            PartitionTableType::SGI => u32::try_from(type_id)
+                .ok()
+                .and_then(|id| SgiType::try_from(id).ok())
+                .map(Self::SGI),
+            _otherwise => None,
+        }
+    }
+
     /// View this `PartitionType` as a UTF-8 `str`.
     pub fn as_str(&self) -> &str {
         match self {
             Self::MBR(value) => value.as_str(),
             Self::GPT(value) => value.as_str(),
+            Self::BSD(value) => value.as_str(),
+            Self::Sun(value) => value.as_str(),
+            Self::SGI(value) => value.as_str(),
         }
     }
 }
@@ -147,4 +212,43 @@ fn partition_type_can_convert_valid_bytes_into_a_gpt_guid() -> crate::Result<()>
 
         Ok(())
     }
+
+    #[test]
+    fn partition_type_converts_table_entries_according_to_their_table_type() {
+        let actual = PartitionType::for_table_entry(&PartitionTableType::DOS, 0x07, None);
+        let expected = Some(PartitionType::MBR(OSType::HPFSNTFSExfat));
+        assert_eq!(actual, expected);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::BSD, 0x07, None);
+        let expected = Some(PartitionType::BSD(BsdFsType::FFS));
+        assert_eq!(actual, expected);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::Sun, 0x07, None);
+        let expected = Some(PartitionType::Sun(SunTag::Var));
+        assert_eq!(actual, expected);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::SGI, 0x07, None);
+        let expected = Some(PartitionType::SGI(SgiType::EFS));
+        assert_eq!(actual, expected);
+
+        let guid = Some("0fc63daf-8483-4772-8e79-3d69d8477de4");
+        let actual = PartitionType::for_table_entry(&PartitionTableType::GPT, 0, guid);
+        let expected = Some(PartitionType::GPT(Guid::LinuxData));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn partition_type_does_not_convert_unknown_table_entries() {
+        let actual = PartitionType::for_table_entry(&PartitionTableType::DOS, 0x1ff, None);
+        assert_eq!(actual, None);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::DOS, -1, None);
+        assert_eq!(actual, None);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::GPT, 0x83, None);
+        assert_eq!(actual, None);
+
+        let actual = PartitionType::for_table_entry(&PartitionTableType::Mac, 0, Some("Apple_HFS"));
+        assert_eq!(actual, None);
+    }
 }
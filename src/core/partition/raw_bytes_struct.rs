@@ -9,6 +9,8 @@
 
 // From this library
 use crate::core::errors::ParserError;
+use crate::core::partition::DecodedLabel;
+use crate::core::partition::LabelEncoding;
 use crate::core::utils::encode;
 
 /// Raw bytes.
@@ -38,6 +40,28 @@ pub fn as_str_safe(&self) -> &str {
     pub fn as_bytes(&self) -> &[u8] {
         self.bytes.as_ref()
     }
+
+    /// Decodes this `RawBytes` as a file system label with the given `encoding` (see
+    /// [`LabelEncoding::for_file_system`]).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::partition::{Codepage, FileSystem, LabelEncoding, RawBytes};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let label_raw = RawBytes::from(b"\xe5\xe5\xe5");
+    ///     let encoding = LabelEncoding::for_file_system(&FileSystem::VFAT, Codepage::Cp850);
+    ///     let decoded = label_raw.decode_label(encoding);
+    ///
+    ///     assert_eq!(decoded.label(), "ÕÕÕ");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn decode_label(&self, encoding: LabelEncoding) -> DecodedLabel {
+        DecodedLabel::decode(&self.bytes, encoding)
+    }
 }
 
 impl AsRef<RawBytes> for RawBytes {
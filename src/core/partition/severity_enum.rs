@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// How serious a [`LayoutFinding`](crate::core::partition::LayoutFinding) is, by increasing order
+/// of gravity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum Severity {
+    /// Noteworthy, but harmless (e.g. unallocated space).
+    Info,
+    /// Degrades performance, or confuses some tools (e.g. misaligned partition).
+    Warning,
+    /// Puts data at risk (e.g. overlapping partitions).
+    Error,
+}
+
+impl Severity {
+    /// View this `Severity` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl AsRef<str> for Severity {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
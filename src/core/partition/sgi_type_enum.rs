@@ -0,0 +1,305 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::errors::ParserError;
+
+/// Partition types in an `SGI` disk label.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum SgiType {
+    /// Volume header.
+    VolumeHeader = 0x00,
+    /// Track replacement.
+    TrackReplacement = 0x01,
+    /// Sector replacement.
+    SectorReplacement = 0x02,
+    /// Raw data, or swap space.
+    Raw = 0x03,
+    /// 4.2BSD file system.
+    BSD = 0x04,
+    /// System V file system.
+    SystemV = 0x05,
+    /// Partition covering the whole disk.
+    Volume = 0x06,
+    /// Extent File System.
+    EFS = 0x07,
+    /// Logical volume.
+    LogicalVolume = 0x08,
+    /// Raw logical volume.
+    RawLogicalVolume = 0x09,
+    /// XFS file system.
+    XFS = 0x0a,
+    /// XFS log device.
+    XFSLog = 0x0b,
+    /// XLV volume.
+    XLV = 0x0c,
+    /// XVM volume.
+    XVM = 0x0d,
+    /// Linux swap space.
+    LinuxSwap = 0x82,
+    /// Linux native file system.
+    LinuxNative = 0x83,
+    /// Linux Logical Volume Manager.
+    LinuxLVM = 0x8e,
+    /// Linux RAID autodetect.
+    LinuxRaid = 0xfd,
+}
+
+impl SgiType {
+    /// View this `SgiType` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::VolumeHeader => "0x00",
+            Self::TrackReplacement => "0x01",
+            Self::SectorReplacement => "0x02",
+            Self::Raw => "0x03",
+            Self::BSD => "0x04",
+            Self::SystemV => "0x05",
+            Self::Volume => "0x06",
+            Self::EFS => "0x07",
+            Self::LogicalVolume => "0x08",
+            Self::RawLogicalVolume => "0x09",
+            Self::XFS => "0x0a",
+            Self::XFSLog => "0x0b",
+            Self::XLV => "0x0c",
+            Self::XVM => "0x0d",
+            Self::LinuxSwap => "0x82",
+            Self::LinuxNative => "0x83",
+            Self::LinuxLVM => "0x8e",
+            Self::LinuxRaid => "0xfd",
+        }
+    }
+
+    /// Returns the name `fdisk` displays for this `SgiType`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::VolumeHeader => "SGI volhdr",
+            Self::TrackReplacement => "SGI trkrepl",
+            Self::SectorReplacement => "SGI secrepl",
+            Self::Raw => "SGI raw",
+            Self::BSD => "SGI bsd",
+            Self::SystemV => "SGI sysv",
+            Self::Volume => "SGI volume",
+            Self::EFS => "SGI efs",
+            Self::LogicalVolume => "SGI lvol",
+            Self::RawLogicalVolume => "SGI rlvol",
+            Self::XFS => "SGI xfs",
+            Self::XFSLog => "SGI xfslog",
+            Self::XLV => "SGI xlv",
+            Self::XVM => "SGI xvm",
+            Self::LinuxSwap => "Linux swap",
+            Self::LinuxNative => "Linux native",
+            Self::LinuxLVM => "Linux LVM",
+            Self::LinuxRaid => "Linux RAID",
+        }
+    }
+}
+
+impl AsRef<SgiType> for SgiType {
+    #[inline]
+    fn as_ref(&self) -> &SgiType {
+        self
+    }
+}
+
+impl AsRef<str> for SgiType {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SgiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&[u8]> for SgiType {
+    type Error = ConversionError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        std::str::from_utf8(bytes)
+            .map_err(|e| {
+                ConversionError::SgiType(format!(
+                    "bytes to UTF-8 string slice conversion error. {:?}",
+                    e
+                ))
+            })
+            .and_then(|s| Self::from_str(s).map_err(|e| ConversionError::SgiType(e.to_string())))
+    }
+}
+
+impl TryFrom<Vec<u8>> for SgiType {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl FromStr for SgiType {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Remove opening opening/closing quotes/double-quotes if present
+        let err_missing_dquote = format!("missing closing double-quote in: {}", s);
+        let err_missing_quote = format!("missing closing quote in: {}", s);
+
+        let trimmed = s.trim();
+        let stripped = if trimmed.starts_with('"') {
+            trimmed
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or(ParserError::SgiType(err_missing_dquote))
+        } else if trimmed.starts_with('\'') {
+            trimmed
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .ok_or(ParserError::SgiType(err_missing_quote))
+        } else {
+            Ok(trimmed)
+        }?;
+
+        // Remove hex string prefix and convert to `SgiType`.
+        stripped
+            .trim()
+            .strip_prefix("0x")
+            .ok_or(ParserError::SgiType(format!(
+                "missing '0x' prefix in: {}",
+                s
+            )))
+            .and_then(|h| {
+                u32::from_str_radix(h, 16).map_err(|e| {
+                    let err_msg = format!("invalid hexadecimal string: {} {:?}", s, e);
+
+                    ParserError::SgiType(err_msg)
+                })
+            })
+            .and_then(|n| {
+                Self::try_from(n).map_err(|_| {
+                    let err_msg = format!("unsupported SGI partition type: {}", s);
+
+                    ParserError::SgiType(err_msg)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "missing closing double-quote")]
+    fn sgi_type_can_not_parse_a_sgi_type_string_with_an_unclosed_double_quote() {
+        let _: SgiType = r#""0x0a"#.parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing closing quote")]
+    fn sgi_type_can_not_parse_a_sgi_type_string_with_an_unclosed_quote() {
+        let _: SgiType = "'0x0a".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn sgi_type_can_not_parse_an_empty_string() {
+        let _: SgiType = "".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn sgi_type_can_not_parse_a_sgi_type_missing_its_0x_prefix() {
+        let _: SgiType = "0a".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn sgi_type_can_not_parse_a_sgi_type_string_with_an_invalid_hexadecimal() {
+        let _: SgiType = "0xDUMMY".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn sgi_type_can_not_parse_a_sgi_type_string_with_a_too_large_hexadecimal() {
+        let _: SgiType = "0xffffffffff".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported SGI partition type")]
+    fn sgi_type_can_not_parse_an_unsupported_sgi_type() {
+        let _: SgiType = "0x0e".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes to UTF-8 string slice conversion error")]
+    fn sgi_type_can_not_convert_invalid_bytes_into_a_sgi_type() {
+        // some invalid bytes, in a vector
+        let bytes: Vec<u8> = vec![0, 159, 146, 150];
+        let _ = SgiType::try_from(bytes).unwrap();
+    }
+
+    #[test]
+    fn sgi_type_can_convert_valid_bytes_into_a_sgi_type() -> crate::Result<()> {
+        let bytes: Vec<u8> = b"0x0a".to_vec();
+        let actual = SgiType::try_from(bytes)?;
+        let expected = SgiType::XFS;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sgi_type_can_parse_a_quoted_sgi_type() -> crate::Result<()> {
+        let actual: SgiType = r#""0x0a""#.parse()?;
+        let expected = SgiType::XFS;
+        assert_eq!(actual, expected);
+
+        let actual: SgiType = "'0x0a'".parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sgi_type_can_round_trip_through_its_string_representation() -> crate::Result<()> {
+        for sgi_type in enum_iterator::all::<SgiType>() {
+            let actual: SgiType = sgi_type.to_string().parse()?;
+            assert_eq!(actual, sgi_type);
+
+            let actual = SgiType::try_from(sgi_type.as_str().as_bytes())?;
+            assert_eq!(actual, sgi_type);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sgi_type_can_round_trip_through_its_numeric_value() -> crate::Result<()> {
+        for sgi_type in enum_iterator::all::<SgiType>() {
+            let value: u32 = sgi_type.into();
+            let actual = SgiType::try_from(value)?;
+            assert_eq!(actual, sgi_type);
+            assert_eq!(sgi_type.to_string(), format!("{:#04x}", value));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::errors::ParserError;
+
+/// Partition tags in a `Sun` disk label, or a `Solaris x86` VTOC.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Sequence, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u16)]
+#[non_exhaustive]
+pub enum SunTag {
+    /// Unassigned partition.
+    Unassigned = 0x00,
+    /// Boot partition.
+    Boot = 0x01,
+    /// SunOS root file system.
+    Root = 0x02,
+    /// SunOS swap space.
+    Swap = 0x03,
+    /// SunOS `/usr` file system.
+    Usr = 0x04,
+    /// Partition covering the whole disk (a.k.a. backup).
+    WholeDisk = 0x05,
+    /// SunOS `/stand` file system.
+    Stand = 0x06,
+    /// SunOS `/var` file system.
+    Var = 0x07,
+    /// SunOS `/home` file system.
+    Home = 0x08,
+    /// Alternate sectors.
+    AltSectors = 0x09,
+    /// `cachefs` cache.
+    Cache = 0x0a,
+    /// Reserved partition.
+    Reserved = 0x0b,
+    /// Linux swap space.
+    LinuxSwap = 0x82,
+    /// Linux native file system.
+    LinuxNative = 0x83,
+    /// Linux Logical Volume Manager.
+    LinuxLVM = 0x8e,
+    /// Linux RAID autodetect.
+    LinuxRaid = 0xfd,
+}
+
+impl SunTag {
+    /// View this `SunTag` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unassigned => "0x00",
+            Self::Boot => "0x01",
+            Self::Root => "0x02",
+            Self::Swap => "0x03",
+            Self::Usr => "0x04",
+            Self::WholeDisk => "0x05",
+            Self::Stand => "0x06",
+            Self::Var => "0x07",
+            Self::Home => "0x08",
+            Self::AltSectors => "0x09",
+            Self::Cache => "0x0a",
+            Self::Reserved => "0x0b",
+            Self::LinuxSwap => "0x82",
+            Self::LinuxNative => "0x83",
+            Self::LinuxLVM => "0x8e",
+            Self::LinuxRaid => "0xfd",
+        }
+    }
+
+    /// Returns the name `fdisk` displays for this `SunTag`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unassigned => "Unassigned",
+            Self::Boot => "Boot",
+            Self::Root => "SunOS root",
+            Self::Swap => "SunOS swap",
+            Self::Usr => "SunOS usr",
+            Self::WholeDisk => "Whole disk",
+            Self::Stand => "SunOS stand",
+            Self::Var => "SunOS var",
+            Self::Home => "SunOS home",
+            Self::AltSectors => "SunOS alt sectors",
+            Self::Cache => "SunOS cachefs",
+            Self::Reserved => "SunOS reserved",
+            Self::LinuxSwap => "Linux swap",
+            Self::LinuxNative => "Linux native",
+            Self::LinuxLVM => "Linux LVM",
+            Self::LinuxRaid => "Linux raid autodetect",
+        }
+    }
+}
+
+impl AsRef<SunTag> for SunTag {
+    #[inline]
+    fn as_ref(&self) -> &SunTag {
+        self
+    }
+}
+
+impl AsRef<str> for SunTag {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for SunTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&[u8]> for SunTag {
+    type Error = ConversionError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        std::str::from_utf8(bytes)
+            .map_err(|e| {
+                ConversionError::SunTag(format!(
+                    "bytes to UTF-8 string slice conversion error. {:?}",
+                    e
+                ))
+            })
+            .and_then(|s| Self::from_str(s).map_err(|e| ConversionError::SunTag(e.to_string())))
+    }
+}
+
+impl TryFrom<Vec<u8>> for SunTag {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl FromStr for SunTag {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Remove opening opening/closing quotes/double-quotes if present
+        let err_missing_dquote = format!("missing closing double-quote in: {}", s);
+        let err_missing_quote = format!("missing closing quote in: {}", s);
+
+        let trimmed = s.trim();
+        let stripped = if trimmed.starts_with('"') {
+            trimmed
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or(ParserError::SunTag(err_missing_dquote))
+        } else if trimmed.starts_with('\'') {
+            trimmed
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+                .ok_or(ParserError::SunTag(err_missing_quote))
+        } else {
+            Ok(trimmed)
+        }?;
+
+        // Remove hex string prefix and convert to `SunTag`.
+        stripped
+            .trim()
+            .strip_prefix("0x")
+            .ok_or(ParserError::SunTag(format!(
+                "missing '0x' prefix in: {}",
+                s
+            )))
+            .and_then(|h| {
+                u16::from_str_radix(h, 16).map_err(|e| {
+                    let err_msg = format!("invalid hexadecimal string: {} {:?}", s, e);
+
+                    ParserError::SunTag(err_msg)
+                })
+            })
+            .and_then(|n| {
+                Self::try_from(n).map_err(|_| {
+                    let err_msg = format!("unsupported Sun partition tag: {}", s);
+
+                    ParserError::SunTag(err_msg)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "missing closing double-quote")]
+    fn sun_tag_can_not_parse_a_sun_tag_string_with_an_unclosed_double_quote() {
+        let _: SunTag = r#""0x83"#.parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing closing quote")]
+    fn sun_tag_can_not_parse_a_sun_tag_string_with_an_unclosed_quote() {
+        let _: SunTag = "'0x83".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn sun_tag_can_not_parse_an_empty_string() {
+        let _: SunTag = "".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing '0x' prefix")]
+    fn sun_tag_can_not_parse_a_sun_tag_missing_its_0x_prefix() {
+        let _: SunTag = "83".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn sun_tag_can_not_parse_a_sun_tag_string_with_an_invalid_hexadecimal() {
+        let _: SunTag = "0xDUMMY".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hexadecimal string")]
+    fn sun_tag_can_not_parse_a_sun_tag_string_with_a_too_large_hexadecimal() {
+        let _: SunTag = "0xffffff".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "unsupported Sun partition tag")]
+    fn sun_tag_can_not_parse_an_unsupported_sun_tag() {
+        let _: SunTag = "0x0c".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "bytes to UTF-8 string slice conversion error")]
+    fn sun_tag_can_not_convert_invalid_bytes_into_a_sun_tag() {
+        // some invalid bytes, in a vector
+        let bytes: Vec<u8> = vec![0, 159, 146, 150];
+        let _ = SunTag::try_from(bytes).unwrap();
+    }
+
+    #[test]
+    fn sun_tag_can_convert_valid_bytes_into_a_sun_tag() -> crate::Result<()> {
+        let bytes: Vec<u8> = b"0x83".to_vec();
+        let actual = SunTag::try_from(bytes)?;
+        let expected = SunTag::LinuxNative;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sun_tag_can_parse_a_quoted_sun_tag() -> crate::Result<()> {
+        let actual: SunTag = r#""0x83""#.parse()?;
+        let expected = SunTag::LinuxNative;
+        assert_eq!(actual, expected);
+
+        let actual: SunTag = "'0x83'".parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sun_tag_can_round_trip_through_its_string_representation() -> crate::Result<()> {
+        for sun_tag in enum_iterator::all::<SunTag>() {
+            let actual: SunTag = sun_tag.to_string().parse()?;
+            assert_eq!(actual, sun_tag);
+
+            let actual = SunTag::try_from(sun_tag.as_str().as_bytes())?;
+            assert_eq!(actual, sun_tag);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sun_tag_can_round_trip_through_its_numeric_value() -> crate::Result<()> {
+        for sun_tag in enum_iterator::all::<SunTag>() {
+            let value: u16 = sun_tag.into();
+            let actual = SunTag::try_from(value)?;
+            assert_eq!(actual, sun_tag);
+            assert_eq!(sun_tag.to_string(), format!("{:#04x}", value));
+        }
+
+        Ok(())
+    }
+}
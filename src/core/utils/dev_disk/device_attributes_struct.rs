@@ -0,0 +1,335 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fs;
+use std::path::Path;
+
+// From this library
+use crate::core::errors::DevDiskError;
+
+/// Device attributes, from `sysfs` or the `udev` database, used to predict the names of the
+/// symlinks `udev` creates in `/dev/disk`.
+///
+/// [`DeviceAttributes::from_sysfs`] reads the attributes exposed by the kernel in `/sys`. Values
+/// computed by `udev` helpers, like `ID_PATH` (`path_id` builtin), `ID_BUS`/`ID_SERIAL` (`ata_id`,
+/// `scsi_id`), or `ID_WWN_WITH_EXTENSION`, have to be set manually.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rsblkid::core::utils::dev_disk::DeviceAttributes;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let attributes = DeviceAttributes::from_sysfs("sda1")?
+///         .with_bus("ata")
+///         .with_serial("Samsung_SSD_850_EVO_250GB_S21PNXAG000000")
+///         .with_id_path("pci-0000:00:1f.2-ata-1");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeviceAttributes {
+    kernel_name: String,
+    partition_number: Option<u32>,
+    diskseq: Option<u64>,
+    bus: Option<String>,
+    model: Option<String>,
+    serial: Option<String>,
+    wwid: Option<String>,
+    wwn: Option<String>,
+    id_path: Option<String>,
+    dm_name: Option<String>,
+    dm_uuid: Option<String>,
+}
+
+impl DeviceAttributes {
+    /// Creates a new `DeviceAttributes` for the device with the given kernel name (e.g. `vda1`).
+    pub fn new<T>(kernel_name: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        Self {
+            kernel_name: kernel_name.as_ref().to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Reads the attributes of the device with the given kernel name from `/sys`.
+    pub fn from_sysfs<T>(kernel_name: T) -> Result<DeviceAttributes, DevDiskError>
+    where
+        T: AsRef<str>,
+    {
+        Self::from_sysfs_root("/sys", kernel_name)
+    }
+
+    /// Reads the attributes of the device with the given kernel name from a `sysfs` tree mounted
+    /// at `sys_root`.
+    ///
+    /// Partitions inherit the `diskseq`, `model`, `serial`, and `wwid` attributes of their parent
+    /// disk.
+    pub fn from_sysfs_root<T, U>(
+        sys_root: T,
+        kernel_name: U,
+    ) -> Result<DeviceAttributes, DevDiskError>
+    where
+        T: AsRef<Path>,
+        U: AsRef<str>,
+    {
+        let kernel_name = kernel_name.as_ref();
+        let device_dir = fs::canonicalize(sys_root.as_ref().join("class/block").join(kernel_name))?;
+
+        let partition_number =
+            read_attribute(&device_dir.join("partition")).and_then(|n| n.parse().ok());
+
+        // A partition's directory is a sub-directory of its parent disk's.
+        let disk_dir = match (partition_number, device_dir.parent()) {
+            (Some(_), Some(parent)) => parent.to_path_buf(),
+            _otherwise => device_dir.clone(),
+        };
+
+        let diskseq = read_attribute(&disk_dir.join("diskseq")).and_then(|n| n.parse().ok());
+        let model = read_attribute(&disk_dir.join("device/model"));
+        let serial = read_attribute(&disk_dir.join("serial"))
+            .or_else(|| read_attribute(&disk_dir.join("device/serial")));
+        let wwid = read_attribute(&disk_dir.join("wwid"))
+            .or_else(|| read_attribute(&disk_dir.join("device/wwid")));
+        let dm_name = read_attribute(&device_dir.join("dm/name"));
+        let dm_uuid = read_attribute(&device_dir.join("dm/uuid"));
+
+        let attributes = Self {
+            kernel_name: kernel_name.to_owned(),
+            partition_number,
+            diskseq,
+            model,
+            serial,
+            wwid,
+            dm_name,
+            dm_uuid,
+            ..Default::default()
+        };
+        log::debug!(
+            "DeviceAttributes::from_sysfs_root read attributes {:?}",
+            attributes
+        );
+
+        Ok(attributes)
+    }
+
+    /// Sets the partition number (`sysfs` attribute `partition`).
+    pub fn with_partition_number(mut self, partition_number: u32) -> DeviceAttributes {
+        self.partition_number = Some(partition_number);
+        self
+    }
+
+    /// Sets the disk sequence number (`sysfs` attribute `diskseq`).
+    pub fn with_diskseq(mut self, diskseq: u64) -> DeviceAttributes {
+        self.diskseq = Some(diskseq);
+        self
+    }
+
+    /// Sets the bus the device is connected to (`udev` property `ID_BUS`, e.g. `ata`, `scsi`,
+    /// `usb`).
+    pub fn with_bus<T>(mut self, bus: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.bus = Some(bus.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the device's model name (`sysfs` attribute `device/model`, or `udev` property
+    /// `ID_MODEL`).
+    pub fn with_model<T>(mut self, model: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.model = Some(model.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the device's serial number (`sysfs` attribute `serial`, or `udev` property
+    /// `ID_SERIAL`).
+    pub fn with_serial<T>(mut self, serial: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.serial = Some(serial.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the device's world wide identifier (`sysfs` attribute `wwid`).
+    pub fn with_wwid<T>(mut self, wwid: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.wwid = Some(wwid.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the device's world wide name (`udev` property `ID_WWN_WITH_EXTENSION`).
+    pub fn with_wwn<T>(mut self, wwn: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.wwn = Some(wwn.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the device's physical path (`udev` property `ID_PATH`, e.g.
+    /// `pci-0000:00:1f.2-ata-1`).
+    pub fn with_id_path<T>(mut self, id_path: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.id_path = Some(id_path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the name of a device-mapper device (`sysfs` attribute `dm/name`).
+    pub fn with_dm_name<T>(mut self, dm_name: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.dm_name = Some(dm_name.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the UUID of a device-mapper device (`sysfs` attribute `dm/uuid`).
+    pub fn with_dm_uuid<T>(mut self, dm_uuid: T) -> DeviceAttributes
+    where
+        T: AsRef<str>,
+    {
+        self.dm_uuid = Some(dm_uuid.as_ref().to_owned());
+        self
+    }
+
+    /// Returns the device's kernel name.
+    pub fn kernel_name(&self) -> &str {
+        &self.kernel_name
+    }
+
+    /// Returns the partition number, if the device is a partition.
+    pub fn partition_number(&self) -> Option<u32> {
+        self.partition_number
+    }
+
+    /// Returns the disk sequence number.
+    pub fn diskseq(&self) -> Option<u64> {
+        self.diskseq
+    }
+
+    /// Returns the bus the device is connected to.
+    pub fn bus(&self) -> Option<&str> {
+        self.bus.as_deref()
+    }
+
+    /// Returns the device's model name.
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    /// Returns the device's serial number.
+    pub fn serial(&self) -> Option<&str> {
+        self.serial.as_deref()
+    }
+
+    /// Returns the device's world wide identifier.
+    pub fn wwid(&self) -> Option<&str> {
+        self.wwid.as_deref()
+    }
+
+    /// Returns the device's world wide name.
+    pub fn wwn(&self) -> Option<&str> {
+        self.wwn.as_deref()
+    }
+
+    /// Returns the device's physical path.
+    pub fn id_path(&self) -> Option<&str> {
+        self.id_path.as_deref()
+    }
+
+    /// Returns the name of a device-mapper device.
+    pub fn dm_name(&self) -> Option<&str> {
+        self.dm_name.as_deref()
+    }
+
+    /// Returns the UUID of a device-mapper device.
+    pub fn dm_uuid(&self) -> Option<&str> {
+        self.dm_uuid.as_deref()
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: reads a `sysfs` attribute, returns `None` if it does not exist or is empty.
+fn read_attribute(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn device_attributes_can_read_a_partition_from_sysfs() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let sys = root.path();
+        let disk = sys.join("devices/pci0000:00/0000:00:05.0/virtio2/block/vda");
+        fs::create_dir_all(disk.join("vda1")).unwrap();
+        fs::write(disk.join("diskseq"), "9\n").unwrap();
+        fs::write(disk.join("serial"), "SERIAL01\n").unwrap();
+        fs::write(disk.join("vda1/partition"), "1\n").unwrap();
+
+        fs::create_dir_all(sys.join("class/block")).unwrap();
+        symlink(disk.join("vda1"), sys.join("class/block/vda1")).unwrap();
+
+        let actual = DeviceAttributes::from_sysfs_root(sys, "vda1")?;
+        let expected = DeviceAttributes::new("vda1")
+            .with_partition_number(1)
+            .with_diskseq(9)
+            .with_serial("SERIAL01");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn device_attributes_can_read_an_nvme_namespace_from_sysfs() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let sys = root.path();
+        let controller = sys.join("devices/pci0000:00/0000:00:04.0/nvme/nvme0");
+        let disk = controller.join("nvme0n1");
+        fs::create_dir_all(disk.join("nvme0n1p2")).unwrap();
+        symlink(&controller, disk.join("device")).unwrap();
+        fs::write(
+            controller.join("model"),
+            "Samsung SSD 970 EVO Plus 1TB            \n",
+        )
+        .unwrap();
+        fs::write(controller.join("serial"), "S4EWNX0R000000      \n").unwrap();
+        fs::write(disk.join("wwid"), "eui.0025385b01234567\n").unwrap();
+        fs::write(disk.join("nvme0n1p2/partition"), "2\n").unwrap();
+
+        fs::create_dir_all(sys.join("class/block")).unwrap();
+        symlink(disk.join("nvme0n1p2"), sys.join("class/block/nvme0n1p2")).unwrap();
+
+        let actual = DeviceAttributes::from_sysfs_root(sys, "nvme0n1p2")?;
+        let expected = DeviceAttributes::new("nvme0n1p2")
+            .with_partition_number(2)
+            .with_model("Samsung SSD 970 EVO Plus 1TB")
+            .with_serial("S4EWNX0R000000")
+            .with_wwid("eui.0025385b01234567");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
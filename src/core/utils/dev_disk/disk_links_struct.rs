@@ -0,0 +1,378 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::errors::DevDiskError;
+
+use crate::core::utils::dev_disk::canonical_device_name;
+use crate::core::utils::dev_disk::predict_names;
+use crate::core::utils::dev_disk::resolve_link;
+use crate::core::utils::dev_disk::tag_from_link_name;
+use crate::core::utils::dev_disk::DeviceAttributes;
+use crate::core::utils::dev_disk::LinkKind;
+use crate::core::utils::dev_disk::LinkMismatch;
+
+/// Table mapping the tags encoded in the names of the symlinks in `/dev/disk` to the devices they
+/// point to.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiskLinks {
+    links: BTreeMap<Tag, PathBuf>,
+}
+
+impl DiskLinks {
+    /// Reads the symlinks in `/dev/disk`.
+    pub fn read() -> Result<DiskLinks, DevDiskError> {
+        Self::read_from("/dev/disk")
+    }
+
+    /// Reads the symlinks in the `by-*` sub-directories of `dev_disk` (e.g. a copy of
+    /// `/dev/disk` from another host).
+    ///
+    /// Missing sub-directories are ignored, as are symlinks whose name can not be converted to a
+    /// [`Tag`].
+    pub fn read_from<T>(dev_disk: T) -> Result<DiskLinks, DevDiskError>
+    where
+        T: AsRef<Path>,
+    {
+        let dev_disk = dev_disk.as_ref();
+        let mut links = BTreeMap::new();
+
+        // Symlinks in `by-diskseq` and `by-path` do not match a tag.
+        let kinds = enum_iterator::all::<LinkKind>().filter(|kind| kind.tag_name().is_some());
+
+        for kind in kinds {
+            let dir = dev_disk.join(kind.as_str());
+
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    log::debug!("DiskLinks::read_from skipping missing {:?}", dir);
+
+                    continue;
+                }
+                Err(e) => return Err(DevDiskError::from(e)),
+            };
+
+            for entry in entries {
+                let entry = entry?;
+                if !entry.file_type()?.is_symlink() {
+                    continue;
+                }
+
+                let link_name = entry.file_name();
+                match tag_from_link_name(kind, link_name.as_bytes()) {
+                    Ok(tag) => {
+                        let target = resolve_link(&entry.path())?;
+                        links.insert(tag, target);
+                    }
+                    Err(e) => {
+                        log::debug!("DiskLinks::read_from skipping {:?}. {}", entry.path(), e);
+                    }
+                }
+            }
+        }
+
+        Ok(Self { links })
+    }
+
+    /// Returns the number of symlinks in the table.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Returns `true` if the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    /// Returns the device the symlink matching `tag` points to.
+    pub fn device_with_tag(&self, tag: &Tag) -> Option<&Path> {
+        self.links.get(tag).map(PathBuf::as_path)
+    }
+
+    /// Returns the tags of all symlinks pointing to `device_name`.
+    pub fn tags_for_device<T>(&self, device_name: T) -> Vec<&Tag>
+    where
+        T: AsRef<Path>,
+    {
+        let device_name = canonical_device_name(device_name.as_ref());
+
+        self.links
+            .iter()
+            .filter_map(|(tag, target)| (target == &device_name).then_some(tag))
+            .collect()
+    }
+
+    /// Returns an iterator over the `(tag, device)` pairs of the table.
+    pub fn iter(&self) -> btree_map::Iter<'_, Tag, PathBuf> {
+        self.links.iter()
+    }
+
+    /// Compares the symlinks pointing to `device_name` with the `probed` tags of the device.
+    ///
+    /// Only tags `udev` creates a `/dev/disk` symlink for are checked, following the same rules as
+    /// [`predict_names`](crate::core::utils::dev_disk::predict_names) (e.g. no `by-uuid` or
+    /// `by-label` symlinks for RAID members, no `by-label` symlink for crypto containers). Like
+    /// `udev`, `PART_ENTRY_UUID` and `PART_ENTRY_NAME` tags reported by a
+    /// [`Probe`](crate::probe::Probe) are checked against the `by-partuuid` and `by-partlabel`
+    /// symlinks. `ID` tags in `probed` are checked as is.
+    pub fn check_device<T>(&self, device_name: T, probed: &[Tag]) -> Vec<LinkMismatch>
+    where
+        T: AsRef<Path>,
+    {
+        let device_name = canonical_device_name(device_name.as_ref());
+
+        // Without device attributes, only symlinks named after probed tags are predicted.
+        let predicted = match predict_names(probed, &DeviceAttributes::default()) {
+            Ok(names) => names,
+            Err(e) => {
+                log::debug!(
+                    "DiskLinks::check_device failed to predict symlink names. {}",
+                    e
+                );
+
+                Vec::new()
+            }
+        };
+
+        let mut probed_links: Vec<Tag> = predicted
+            .iter()
+            .filter_map(|name| tag_from_link_name(name.kind(), name.name()).ok())
+            .collect();
+
+        for tag in probed.iter().filter(|tag| tag.name() == TagName::Id) {
+            if !probed_links.contains(tag) {
+                probed_links.push(tag.clone());
+            }
+        }
+
+        let mut mismatches: Vec<LinkMismatch> = probed_links
+            .iter()
+            .filter_map(|tag| match self.links.get(tag) {
+                None => Some(LinkMismatch::Missing(tag.clone())),
+                Some(target) if target != &device_name => Some(LinkMismatch::WrongTarget {
+                    tag: tag.clone(),
+                    target: target.clone(),
+                }),
+                Some(_) => None,
+            })
+            .collect();
+
+        // `libblkid` does not report `ID` tags, so we can not tell whether they are stale.
+        let stale = self
+            .links
+            .iter()
+            .filter(|(tag, target)| {
+                *target == &device_name && tag.name() != TagName::Id && !probed_links.contains(*tag)
+            })
+            .map(|(tag, _)| LinkMismatch::Stale(tag.clone()));
+        mismatches.extend(stale);
+
+        mismatches
+    }
+}
+
+impl<'a> IntoIterator for &'a DiskLinks {
+    type Item = (&'a Tag, &'a PathBuf);
+    type IntoIter = btree_map::Iter<'a, Tag, PathBuf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::partition::RawBytes;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn disk_links_can_read_and_check_a_dev_disk_directory() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let dev = root.path();
+        fs::write(dev.join("vda1"), b"").unwrap();
+        fs::write(dev.join("vda2"), b"").unwrap();
+
+        let by_label = dev.join("disk/by-label");
+        let by_uuid = dev.join("disk/by-uuid");
+        fs::create_dir_all(&by_label).unwrap();
+        fs::create_dir_all(&by_uuid).unwrap();
+        symlink("../../vda1", by_label.join(r"my\x20disk")).unwrap();
+        symlink("../../vda1", by_label.join("old")).unwrap();
+        symlink(
+            "../../vda2",
+            by_uuid.join("ac4f36bf-191b-4fb0-b808-6d7fc9fc88be"),
+        )
+        .unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        assert_eq!(links.len(), 3);
+
+        let label = Tag::Label(RawBytes::from("my disk"));
+        let actual = links.device_with_tag(&label);
+        let vda1 = fs::canonicalize(dev.join("vda1")).unwrap();
+        let expected = Some(vda1.as_path());
+        assert_eq!(actual, expected);
+
+        let uuid: Tag = "UUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?;
+        let fs_type: Tag = "TYPE=ext4".parse()?;
+        let usage: Tag = "USAGE=filesystem".parse()?;
+        let probed = vec![label, uuid.clone(), fs_type, usage];
+
+        let actual = links.check_device(dev.join("vda1"), &probed);
+        let vda2 = fs::canonicalize(dev.join("vda2")).unwrap();
+        let expected = vec![
+            LinkMismatch::WrongTarget {
+                tag: uuid,
+                target: vda2,
+            },
+            LinkMismatch::Stale("LABEL=old".parse()?),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn disk_links_can_check_the_partition_entry_tags_of_a_probe() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let dev = root.path();
+        fs::write(dev.join("vda1"), b"").unwrap();
+
+        let by_partuuid = dev.join("disk/by-partuuid");
+        let by_partlabel = dev.join("disk/by-partlabel");
+        fs::create_dir_all(&by_partuuid).unwrap();
+        fs::create_dir_all(&by_partlabel).unwrap();
+        symlink(
+            "../../vda1",
+            by_partuuid.join("c12a7328-f81f-11d2-ba4b-00a0c93ec93b"),
+        )
+        .unwrap();
+        symlink("../../vda1", by_partlabel.join("esp")).unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        assert_eq!(links.len(), 2);
+
+        // Tags as reported by a `Probe`.
+        let probed: Vec<Tag> = vec![
+            "PART_ENTRY_SCHEME=gpt".parse()?,
+            "PART_ENTRY_UUID=c12a7328-f81f-11d2-ba4b-00a0c93ec93b".parse()?,
+            "PART_ENTRY_NAME=esp".parse()?,
+            "PART_ENTRY_NUMBER=1".parse()?,
+        ];
+
+        let actual = links.check_device(dev.join("vda1"), &probed);
+        assert!(actual.is_empty());
+
+        let probed: Vec<Tag> = vec![
+            "PART_ENTRY_SCHEME=gpt".parse()?,
+            "PART_ENTRY_UUID=c12a7328-f81f-11d2-ba4b-00a0c93ec93b".parse()?,
+            "PART_ENTRY_NAME=boot".parse()?,
+        ];
+
+        let actual = links.check_device(dev.join("vda1"), &probed);
+        let expected = vec![
+            LinkMismatch::Missing("PARTLABEL=boot".parse()?),
+            LinkMismatch::Stale("PARTLABEL=esp".parse()?),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn disk_links_does_not_expect_symlinks_for_a_raid_member() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let dev = root.path();
+        fs::write(dev.join("vda1"), b"").unwrap();
+
+        let by_uuid = dev.join("disk/by-uuid");
+        fs::create_dir_all(&by_uuid).unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        assert!(links.is_empty());
+
+        // Tags as reported by a `Probe` for a Linux RAID member.
+        let probed: Vec<Tag> = vec![
+            "UUID=3b4e8c6a-0f51-4d4e-9a57-a2f9dbd0c0d1".parse()?,
+            "LABEL=host:0".parse()?,
+            "TYPE=linux_raid_member".parse()?,
+            "USAGE=raid".parse()?,
+        ];
+
+        let actual = links.check_device(dev.join("vda1"), &probed);
+        assert!(actual.is_empty());
+
+        // `udev` does not create a `by-uuid` symlink for a RAID member.
+        symlink(
+            "../../vda1",
+            by_uuid.join("3b4e8c6a-0f51-4d4e-9a57-a2f9dbd0c0d1"),
+        )
+        .unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        let actual = links.check_device(dev.join("vda1"), &probed);
+        let expected = vec![LinkMismatch::Stale(
+            "UUID=3b4e8c6a-0f51-4d4e-9a57-a2f9dbd0c0d1".parse()?,
+        )];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn disk_links_does_not_expect_a_label_symlink_for_a_crypto_container() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let dev = root.path();
+        fs::write(dev.join("vda2"), b"").unwrap();
+
+        let by_uuid = dev.join("disk/by-uuid");
+        fs::create_dir_all(&by_uuid).unwrap();
+        symlink(
+            "../../vda2",
+            by_uuid.join("f0c46a4c-9b5e-4b44-8c11-0f2f5b0a1e9d"),
+        )
+        .unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        assert_eq!(links.len(), 1);
+
+        // Tags as reported by a `Probe` for a LUKS2 container.
+        let probed: Vec<Tag> = vec![
+            "UUID=f0c46a4c-9b5e-4b44-8c11-0f2f5b0a1e9d".parse()?,
+            "LABEL=secret".parse()?,
+            "TYPE=crypto_LUKS".parse()?,
+            "USAGE=crypto".parse()?,
+        ];
+
+        let actual = links.check_device(dev.join("vda2"), &probed);
+        assert!(actual.is_empty());
+
+        // The `by-uuid` symlink is still expected.
+        fs::remove_file(by_uuid.join("f0c46a4c-9b5e-4b44-8c11-0f2f5b0a1e9d")).unwrap();
+
+        let links = DiskLinks::read_from(dev.join("disk"))?;
+        let actual = links.check_device(dev.join("vda2"), &probed);
+        let expected = vec![LinkMismatch::Missing(
+            "UUID=f0c46a4c-9b5e-4b44-8c11-0f2f5b0a1e9d".parse()?,
+        )];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::device::TagName;
+use crate::core::errors::ParserError;
+
+/// Sub-directories of `/dev/disk` in which `udev` creates persistent symlinks to block devices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Sequence)]
+#[non_exhaustive]
+pub enum LinkKind {
+    /// `/dev/disk/by-diskseq`, symlinks named after a disk's sequence number.
+    ByDiskseq,
+    /// `/dev/disk/by-id`, symlinks named after a device's hardware ID.
+    ById,
+    /// `/dev/disk/by-label`, symlinks named after a file system's label.
+    ByLabel,
+    /// `/dev/disk/by-partlabel`, symlinks named after a partition's label.
+    ByPartLabel,
+    /// `/dev/disk/by-partuuid`, symlinks named after a partition's UUID.
+    ByPartUuid,
+    /// `/dev/disk/by-path`, symlinks named after the shortest physical path to a device.
+    ByPath,
+    /// `/dev/disk/by-uuid`, symlinks named after a file system's UUID.
+    ByUuid,
+}
+
+impl LinkKind {
+    /// View this `LinkKind` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ByDiskseq => "by-diskseq",
+            Self::ById => "by-id",
+            Self::ByLabel => "by-label",
+            Self::ByPartLabel => "by-partlabel",
+            Self::ByPartUuid => "by-partuuid",
+            Self::ByPath => "by-path",
+            Self::ByUuid => "by-uuid",
+        }
+    }
+
+    /// Returns the name of the [`Tag`](crate::core::device::Tag) matching the names of symlinks
+    /// of this kind, `None` if they do not match a tag (i.e. `by-diskseq` and `by-path`).
+    pub fn tag_name(&self) -> Option<TagName> {
+        match self {
+            Self::ByDiskseq | Self::ByPath => None,
+            Self::ById => Some(TagName::Id),
+            Self::ByLabel => Some(TagName::Label),
+            Self::ByPartLabel => Some(TagName::PartLabel),
+            Self::ByPartUuid => Some(TagName::PartUuid),
+            Self::ByUuid => Some(TagName::Uuid),
+        }
+    }
+}
+
+impl AsRef<LinkKind> for LinkKind {
+    #[inline]
+    fn as_ref(&self) -> &LinkKind {
+        self
+    }
+}
+
+impl AsRef<str> for LinkKind {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for LinkKind {
+    type Err = ParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "by-diskseq" => Ok(Self::ByDiskseq),
+            "by-id" => Ok(Self::ById),
+            "by-label" => Ok(Self::ByLabel),
+            "by-partlabel" => Ok(Self::ByPartLabel),
+            "by-partuuid" => Ok(Self::ByPartUuid),
+            "by-path" => Ok(Self::ByPath),
+            "by-uuid" => Ok(Self::ByUuid),
+            _unsupported => {
+                let err_msg = format!("unsupported /dev/disk sub-directory: {:?}", s);
+                Err(ParserError::LinkKind(err_msg))
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+use std::path::PathBuf;
+
+// From this library
+use crate::core::device::Tag;
+
+/// Discrepancy between the symlinks in `/dev/disk`, and the tags found by probing a device.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LinkMismatch {
+    /// No symlink matches a tag found by probing.
+    Missing(Tag),
+
+    /// The symlink matching a tag found by probing points to another device.
+    WrongTarget {
+        /// Tag found by probing.
+        tag: Tag,
+        /// Device the symlink points to.
+        target: PathBuf,
+    },
+
+    /// A symlink points to the device, but its tag was not found by probing.
+    Stale(Tag),
+}
+
+impl LinkMismatch {
+    /// Returns the `Tag` concerned by this mismatch.
+    pub fn tag(&self) -> &Tag {
+        match self {
+            Self::Missing(tag) | Self::WrongTarget { tag, .. } | Self::Stale(tag) => tag,
+        }
+    }
+}
+
+impl fmt::Display for LinkMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(tag) => write!(f, "missing symlink for {}", tag),
+            Self::WrongTarget { tag, target } => {
+                write!(f, "symlink for {} points to {}", tag, target.display())
+            }
+            Self::Stale(tag) => write!(f, "stale symlink for {}", tag),
+        }
+    }
+}
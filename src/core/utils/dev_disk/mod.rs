@@ -0,0 +1,553 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Map the persistent symlinks `udev` creates in `/dev/disk` to device tags.
+//!
+//! `udev` names symlinks in `/dev/disk/by-label`, `/dev/disk/by-uuid`, etc. after the values of a
+//! device's tags, encoded by [`encode_string`](crate::core::utils::encode::encode_string) (e.g.
+//! `/dev/disk/by-label/my\x20disk` for a file system labelled `my disk`). The functions in this
+//! module revert this transformation, to compare the symlinks with the tags found by probing a
+//! device, or apply it, to predict the names `udev` should create for a device (see
+//! [`predict_names`] and [`verify_names`]).
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use rsblkid::core::utils::dev_disk::DiskLinks;
+//! use rsblkid::probe::Probe;
+//!
+//! fn main() -> rsblkid::Result<()> {
+//!     let links = DiskLinks::read()?;
+//!
+//!     let mut probe = Probe::builder().scan_device("/dev/vda1").build()?;
+//!     probe.find_device_properties();
+//!     let tags: Vec<_> = probe.iter_device_properties().collect();
+//!
+//!     for mismatch in links.check_device("/dev/vda1", &tags) {
+//!         eprintln!("{}", mismatch);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+// From dependency library
+
+// From standard library
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::errors::DevDiskError;
+use crate::core::utils::encode::decode_string;
+
+pub use device_attributes_struct::DeviceAttributes;
+pub use disk_links_struct::DiskLinks;
+pub use link_kind_enum::LinkKind;
+pub use link_mismatch_enum::LinkMismatch;
+pub use name_mismatch_enum::NameMismatch;
+pub use persistent_name_struct::PersistentName;
+
+mod device_attributes_struct;
+mod disk_links_struct;
+mod link_kind_enum;
+mod link_mismatch_enum;
+mod name_mismatch_enum;
+mod persistent_name_struct;
+
+/// Converts the name of a symlink in the `/dev/disk` sub-directory matching `kind` to a [`Tag`].
+///
+/// # Examples
+///
+/// ```ignore
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::device::Tag;
+/// use rsblkid::core::utils::dev_disk;
+/// use rsblkid::core::utils::dev_disk::LinkKind;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let actual = dev_disk::tag_from_link_name(LinkKind::ByLabel, r"my\x20disk")?;
+///     let expected: Tag = r#"LABEL="my disk""#.parse()?;
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn tag_from_link_name<T>(kind: LinkKind, link_name: T) -> Result<Tag, DevDiskError>
+where
+    T: AsRef<[u8]>,
+{
+    let tag_name = kind.tag_name().ok_or_else(|| {
+        let err_msg = format!("symlinks in /dev/disk/{} do not match a tag", kind);
+        DevDiskError::NoMatchingTag(err_msg)
+    })?;
+
+    let value = decode_string(link_name)?;
+    let tag = Tag::try_from((tag_name, value))?;
+
+    Ok(tag)
+}
+
+/// Converts the path to a symlink in a `/dev/disk` sub-directory (e.g.
+/// `/dev/disk/by-uuid/ac4f36bf-191b-4fb0-b808-6d7fc9fc88be`) to a [`Tag`].
+///
+/// The kind of the symlink is deduced from the name of its parent directory.
+pub fn tag_from_link_path<T>(path: T) -> Result<Tag, DevDiskError>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let parent_name = path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+    let kind = LinkKind::from_str(parent_name)?;
+
+    let link_name = path.file_name().map(OsStr::as_bytes).unwrap_or_default();
+
+    tag_from_link_name(kind, link_name)
+}
+
+#[doc(hidden)]
+/// Helper function: converts `tags` to the properties `udev`'s `blkid` builtin would set (see
+/// [`Tag::to_udev_format`]).
+fn udev_environment(tags: &[Tag]) -> Result<HashMap<String, String>, DevDiskError> {
+    let mut environment = HashMap::new();
+
+    for tag in tags {
+        for line in tag.to_udev_format()?.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                environment.insert(key.to_owned(), value.to_owned());
+            }
+        }
+    }
+
+    Ok(environment)
+}
+
+#[doc(hidden)]
+/// Helper function: replaces white space, and characters `udev` does not allow in symlink names,
+/// with `_`.
+fn replace_unsafe_chars(value: &str) -> String {
+    let mut replaced = String::with_capacity(value.len());
+    let mut previous_is_space = false;
+
+    for ch in value.trim().chars() {
+        if ch.is_whitespace() {
+            // Runs of white space are replaced by a single `_`.
+            if !previous_is_space {
+                replaced.push('_');
+            }
+            previous_is_space = true;
+
+            continue;
+        }
+        previous_is_space = false;
+
+        if ch.is_ascii_alphanumeric() || "#+-.:=@_".contains(ch) || !ch.is_ascii() {
+            replaced.push(ch);
+        } else {
+            replaced.push('_');
+        }
+    }
+
+    replaced
+}
+
+/// Predicts the names of the symlinks `udev` creates in `/dev/disk` for a device, from the `tags`
+/// found by probing the device, and its `attributes`.
+///
+/// Predictions follow the rules in `systemd`'s `60-persistent-storage.rules`:
+/// - `by-uuid`: file systems, crypto containers, and other non-RAID members with a `UUID`,
+/// - `by-label`: file systems, and other non-RAID, non-crypto members with a `LABEL`,
+/// - `by-partuuid`: partitions with a `PART_ENTRY_UUID`,
+/// - `by-partlabel`: `GPT` partitions with a `PART_ENTRY_NAME`,
+/// - `by-id`: `virtio-<serial>`, `nvme-<wwid>`, `nvme-<model>_<serial>`, `<bus>-<serial>`,
+/// `wwn-<wwn>`, `dm-name-<name>`, and `dm-uuid-<uuid>`,
+/// - `by-path`: `<ID_PATH>`, and the legacy `virtio-<ID_PATH>` for `virtio` devices,
+/// - `by-diskseq`: `<diskseq>`.
+///
+/// Names derived from the parent disk of a partition end with `-part<N>`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rsblkid::core::utils::dev_disk;
+/// use rsblkid::core::utils::dev_disk::DeviceAttributes;
+/// use rsblkid::probe::Probe;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let mut probe = Probe::builder().scan_device("/dev/vda1").build()?;
+///     probe.find_device_properties();
+///     let tags: Vec<_> = probe.iter_device_properties().collect();
+///
+///     let attributes = DeviceAttributes::from_sysfs("vda1")?;
+///     for name in dev_disk::predict_names(&tags, &attributes)? {
+///         println!("/dev/{}", name);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn predict_names(
+    tags: &[Tag],
+    attributes: &DeviceAttributes,
+) -> Result<Vec<PersistentName>, DevDiskError> {
+    let environment = udev_environment(tags)?;
+    let property = |key: &str| {
+        environment
+            .get(key)
+            .map(String::as_str)
+            .filter(|value| !value.is_empty())
+    };
+
+    let partition_number = attributes
+        .partition_number()
+        .or_else(|| property("ID_PART_ENTRY_NUMBER").and_then(|number| number.parse().ok()));
+    let suffix = partition_number
+        .map(|number| format!("-part{}", number))
+        .unwrap_or_default();
+    let is_virtio = attributes.kernel_name().starts_with("vd");
+    let is_nvme = attributes.kernel_name().starts_with("nvme");
+
+    let mut names = Vec::new();
+    let mut push = |kind: LinkKind, name: String| names.push(PersistentName::new(kind, name));
+
+    // by-uuid, by-label
+    let usage = property("ID_FS_USAGE");
+    if let (Some("filesystem" | "other" | "crypto"), Some(uuid)) =
+        (usage, property("ID_FS_UUID_ENC"))
+    {
+        push(LinkKind::ByUuid, uuid.to_owned());
+    }
+
+    if let (Some("filesystem" | "other"), Some(label)) = (usage, property("ID_FS_LABEL_ENC")) {
+        push(LinkKind::ByLabel, label.to_owned());
+    }
+
+    // by-partuuid, by-partlabel
+    if let Some(uuid) = property("ID_PART_ENTRY_UUID") {
+        push(LinkKind::ByPartUuid, uuid.to_owned());
+    }
+
+    if let (Some("gpt"), Some(name)) = (
+        property("ID_PART_ENTRY_SCHEME"),
+        property("ID_PART_ENTRY_NAME"),
+    ) {
+        push(LinkKind::ByPartLabel, name.to_owned());
+    }
+
+    // by-id
+    let serial = attributes.serial().map(replace_unsafe_chars);
+    match (is_virtio, is_nvme, attributes.bus(), serial) {
+        (true, _, _, Some(serial)) => push(LinkKind::ById, format!("virtio-{}{}", serial, suffix)),
+        (_, true, _, serial) => {
+            if let Some(wwid) = attributes.wwid() {
+                let wwid = replace_unsafe_chars(wwid);
+                push(LinkKind::ById, format!("nvme-{}{}", wwid, suffix));
+            }
+
+            // `udev` sets `ID_SERIAL` to `<model>_<serial>`, white space replaced by `_`.
+            if let (Some(model), Some(serial)) = (attributes.model(), serial) {
+                let model = replace_unsafe_chars(model);
+                push(
+                    LinkKind::ById,
+                    format!("nvme-{}_{}{}", model, serial, suffix),
+                );
+            }
+        }
+        (_, _, Some(bus), Some(serial)) => {
+            let bus = replace_unsafe_chars(bus);
+            push(LinkKind::ById, format!("{}-{}{}", bus, serial, suffix));
+        }
+        _otherwise => {}
+    }
+
+    if let Some(wwn) = attributes.wwn() {
+        let wwn = replace_unsafe_chars(wwn);
+        push(LinkKind::ById, format!("wwn-{}{}", wwn, suffix));
+    }
+
+    if let Some(dm_name) = attributes.dm_name() {
+        push(
+            LinkKind::ById,
+            format!("dm-name-{}", replace_unsafe_chars(dm_name)),
+        );
+    }
+
+    if let Some(dm_uuid) = attributes.dm_uuid() {
+        push(
+            LinkKind::ById,
+            format!("dm-uuid-{}", replace_unsafe_chars(dm_uuid)),
+        );
+    }
+
+    // by-path
+    if let Some(id_path) = attributes.id_path() {
+        let id_path = replace_unsafe_chars(id_path);
+
+        if is_virtio && id_path.starts_with("pci-") {
+            push(LinkKind::ByPath, format!("virtio-{}{}", id_path, suffix));
+        }
+
+        push(LinkKind::ByPath, format!("{}{}", id_path, suffix));
+    }
+
+    // by-diskseq
+    if let Some(diskseq) = attributes.diskseq() {
+        push(LinkKind::ByDiskseq, format!("{}{}", diskseq, suffix));
+    }
+
+    names.sort();
+    names.dedup();
+    log::debug!("dev_disk::predict_names predicted names {:?}", names);
+
+    Ok(names)
+}
+
+/// Compares the persistent names predicted by [`predict_names`] with the symlinks in the `/dev`
+/// directory at `dev_root`.
+///
+/// Reports predicted symlinks that are missing, or point to another device, as well as stale
+/// symlinks in `dev_root/disk/by-*` that point to the device but were not predicted.
+pub fn verify_names<T>(
+    dev_root: T,
+    tags: &[Tag],
+    attributes: &DeviceAttributes,
+) -> Result<Vec<NameMismatch>, DevDiskError>
+where
+    T: AsRef<Path>,
+{
+    let dev_root = dev_root.as_ref();
+    let predicted = predict_names(tags, attributes)?;
+    let device_name = canonical_device_name(&dev_root.join(attributes.kernel_name()));
+
+    let mut mismatches = Vec::new();
+
+    for name in predicted.iter() {
+        match resolve_link(&name.path_in(dev_root)) {
+            Ok(target) if target == device_name => {}
+            Ok(target) => mismatches.push(NameMismatch::WrongTarget {
+                name: name.clone(),
+                target,
+            }),
+            // `read_link` returns `InvalidInput` if the file is not a symlink.
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::InvalidInput
+                ) =>
+            {
+                mismatches.push(NameMismatch::Missing(name.clone()))
+            }
+            Err(e) => return Err(DevDiskError::from(e)),
+        }
+    }
+
+    for kind in enum_iterator::all::<LinkKind>() {
+        let dir = dev_root.join("disk").join(kind.as_str());
+
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(DevDiskError::from(e)),
+        };
+
+        for entry in entries {
+            let entry = entry?;
+            if !entry.file_type()?.is_symlink() || resolve_link(&entry.path())? != device_name {
+                continue;
+            }
+
+            let name = PersistentName::new(kind, entry.file_name().to_string_lossy());
+            if !predicted.contains(&name) {
+                mismatches.push(NameMismatch::Stale(name));
+            }
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[doc(hidden)]
+/// Helper function: returns the absolute path to the target of the symlink at `link`.
+///
+/// Falls back to a lexically normalized path when the target does not exist.
+fn resolve_link(link: &Path) -> io::Result<PathBuf> {
+    let target = fs::read_link(link)?;
+    let target = match link.parent() {
+        Some(parent) if target.is_relative() => parent.join(target),
+        _otherwise => target,
+    };
+
+    Ok(fs::canonicalize(&target).unwrap_or_else(|_| normalize_path(&target)))
+}
+
+#[doc(hidden)]
+/// Helper function: removes `.` and `..` components from `path`, without accessing the file
+/// system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+#[doc(hidden)]
+/// Helper function: returns the canonical path to a device, or the lexically normalized path if
+/// the device does not exist.
+fn canonical_device_name(device_name: &Path) -> PathBuf {
+    fs::canonicalize(device_name).unwrap_or_else(|_| normalize_path(device_name))
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::partition::RawBytes;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::os::unix::fs::symlink;
+
+    #[test]
+    fn tag_from_link_name_decodes_encoded_names() -> crate::Result<()> {
+        let actual = tag_from_link_name(LinkKind::ByLabel, r"my\x20disk")?;
+        let expected = Tag::Label(RawBytes::from("my disk"));
+        assert_eq!(actual, expected);
+
+        let actual =
+            tag_from_link_name(LinkKind::ByPartUuid, "ac4f36bf-191b-4fb0-b808-6d7fc9fc88be")?;
+        let expected: Tag = "PARTUUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_from_link_path_deduces_the_link_kind() -> crate::Result<()> {
+        let actual = tag_from_link_path("/dev/disk/by-label/nixos")?;
+        let expected: Tag = "LABEL=nixos".parse()?;
+        assert_eq!(actual, expected);
+
+        let actual = tag_from_link_path("/dev/disk/by-path/pci-0000:00:1f.2-ata-1");
+        assert!(actual.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn predict_names_follows_persistent_storage_rules() -> crate::Result<()> {
+        let tags = vec![
+            Tag::Label(RawBytes::from("my disk")),
+            "UUID=1234-ABCD".parse()?,
+            "USAGE=filesystem".parse()?,
+            "PART_ENTRY_SCHEME=gpt".parse()?,
+            "PART_ENTRY_NAME=EFI".parse()?,
+            "PART_ENTRY_UUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be".parse()?,
+        ];
+        let attributes = DeviceAttributes::new("vda1")
+            .with_partition_number(1)
+            .with_diskseq(9)
+            .with_serial("SERIAL 01")
+            .with_id_path("pci-0000:00:05.0");
+
+        let actual = predict_names(&tags, &attributes)?;
+        let expected = vec![
+            PersistentName::new(LinkKind::ByDiskseq, "9-part1"),
+            PersistentName::new(LinkKind::ById, "virtio-SERIAL_01-part1"),
+            PersistentName::new(LinkKind::ByLabel, r"my\x20disk"),
+            PersistentName::new(LinkKind::ByPartLabel, "EFI"),
+            PersistentName::new(LinkKind::ByPartUuid, "ac4f36bf-191b-4fb0-b808-6d7fc9fc88be"),
+            PersistentName::new(LinkKind::ByPath, "pci-0000:00:05.0-part1"),
+            PersistentName::new(LinkKind::ByPath, "virtio-pci-0000:00:05.0-part1"),
+            PersistentName::new(LinkKind::ByUuid, "1234-ABCD"),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn predict_names_combines_the_model_and_serial_of_an_nvme_device() -> crate::Result<()> {
+        let attributes = DeviceAttributes::new("nvme0n1p2")
+            .with_partition_number(2)
+            .with_model("Samsung SSD 970 EVO Plus 1TB")
+            .with_serial("S4EWNX0R000000")
+            .with_wwid("eui.0025385b01234567");
+
+        let actual = predict_names(&[], &attributes)?;
+        let expected = vec![
+            PersistentName::new(
+                LinkKind::ById,
+                "nvme-Samsung_SSD_970_EVO_Plus_1TB_S4EWNX0R000000-part2",
+            ),
+            PersistentName::new(LinkKind::ById, "nvme-eui.0025385b01234567-part2"),
+        ];
+        assert_eq!(actual, expected);
+
+        // Without a model, `udev` does not create a serial number based symlink.
+        let attributes = DeviceAttributes::new("nvme0n1").with_serial("S4EWNX0R000000");
+        let actual = predict_names(&[], &attributes)?;
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_names_reports_missing_and_stale_symlinks() -> crate::Result<()> {
+        let root = tempfile::tempdir().unwrap();
+        let dev = root.path();
+        fs::write(dev.join("vda1"), b"").unwrap();
+        fs::write(dev.join("vda2"), b"").unwrap();
+
+        for kind in ["by-uuid", "by-partuuid", "by-label"] {
+            fs::create_dir_all(dev.join("disk").join(kind)).unwrap();
+        }
+        let uuid = "ac4f36bf-191b-4fb0-b808-6d7fc9fc88be";
+        symlink("../../vda1", dev.join("disk/by-uuid/1234-ABCD")).unwrap();
+        symlink("../../vda2", dev.join("disk/by-partuuid").join(uuid)).unwrap();
+        symlink("../../vda1", dev.join("disk/by-label/old")).unwrap();
+
+        let tags = vec![
+            "UUID=1234-ABCD".parse()?,
+            "USAGE=filesystem".parse()?,
+            format!("PART_ENTRY_UUID={}", uuid).parse()?,
+        ];
+        let attributes = DeviceAttributes::new("vda1").with_diskseq(9);
+
+        let actual = verify_names(dev, &tags, &attributes)?;
+        let expected = vec![
+            NameMismatch::Missing(PersistentName::new(LinkKind::ByDiskseq, "9")),
+            NameMismatch::WrongTarget {
+                name: PersistentName::new(LinkKind::ByPartUuid, uuid),
+                target: fs::canonicalize(dev.join("vda2")).unwrap(),
+            },
+            NameMismatch::Stale(PersistentName::new(LinkKind::ByLabel, "old")),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_path_removes_parent_directory_components() {
+        let actual = normalize_path(Path::new("/dev/disk/by-label/../../vda1"));
+        let expected = PathBuf::from("/dev/vda1");
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+use std::path::PathBuf;
+
+// From this library
+use crate::core::utils::dev_disk::PersistentName;
+
+/// Discrepancy between the predicted persistent names of a device, and the symlinks in a `/dev`
+/// directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NameMismatch {
+    /// A predicted symlink does not exist.
+    Missing(PersistentName),
+
+    /// A predicted symlink points to another device.
+    WrongTarget {
+        /// Predicted name.
+        name: PersistentName,
+        /// Device the symlink points to.
+        target: PathBuf,
+    },
+
+    /// A symlink points to the device, but was not predicted.
+    Stale(PersistentName),
+}
+
+impl NameMismatch {
+    /// Returns the `PersistentName` concerned by this mismatch.
+    pub fn name(&self) -> &PersistentName {
+        match self {
+            Self::Missing(name) | Self::WrongTarget { name, .. } | Self::Stale(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for NameMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "missing symlink {}", name),
+            Self::WrongTarget { name, target } => {
+                write!(f, "symlink {} points to {}", name, target.display())
+            }
+            Self::Stale(name) => write!(f, "stale symlink {}", name),
+        }
+    }
+}
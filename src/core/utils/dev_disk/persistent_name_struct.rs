@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::utils::dev_disk::LinkKind;
+
+/// Name of a persistent symlink `udev` creates in a `/dev/disk` sub-directory.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PersistentName {
+    kind: LinkKind,
+    name: String,
+}
+
+impl PersistentName {
+    /// Creates a new `PersistentName`.
+    pub fn new<T>(kind: LinkKind, name: T) -> PersistentName
+    where
+        T: AsRef<str>,
+    {
+        Self {
+            kind,
+            name: name.as_ref().to_owned(),
+        }
+    }
+
+    /// Returns the kind of symlink.
+    pub fn kind(&self) -> LinkKind {
+        self.kind
+    }
+
+    /// Returns the symlink's file name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path to the symlink in the `/dev` directory at `dev_root`.
+    pub fn path_in<T>(&self, dev_root: T) -> PathBuf
+    where
+        T: AsRef<Path>,
+    {
+        dev_root
+            .as_ref()
+            .join("disk")
+            .join(self.kind.as_str())
+            .join(&self.name)
+    }
+}
+
+impl fmt::Display for PersistentName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "disk/{}/{}", self.kind, self.name)
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright (c) 2023 Nick Piaddo
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-//! Encode strings to a safe, udev-compatible format.
+//! Encode strings to a safe, udev-compatible format, and decode them back.
 
 // From dependency library
 
@@ -58,6 +58,57 @@ pub fn encode_string<T>(string: T) -> Result<String, EncodeError>
     }
 }
 
+/// Decodes all `\xNN` hex sequences in a string encoded by [`encode_string`] back to the
+/// characters they represent.
+///
+/// This is the function to use to recover a device label from the name of its symlink in
+/// `/dev/disk/by-label` (e.g. `my\x20disk` is decoded to `my disk`). The decoded value is
+/// returned as raw bytes, since labels are not guaranteed to be valid UTF-8.
+pub fn decode_string<T>(string: T) -> Result<Vec<u8>, EncodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let string = string.as_ref();
+    log::debug!("encode::decode_string decoding {:?}", string);
+
+    let mut decoded = Vec::with_capacity(string.len());
+    let mut i = 0;
+
+    while i < string.len() {
+        if string[i..].starts_with(b"\\x") {
+            let byte = string
+                .get(i + 2..i + 4)
+                .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| {
+                    let err_msg = format!(
+                        "invalid hex sequence at index {} in string: {:?}",
+                        i,
+                        String::from_utf8_lossy(string)
+                    );
+                    log::debug!("encode::decode_string {}", err_msg);
+
+                    EncodeError::StringDecoding(err_msg)
+                })?;
+
+            decoded.push(byte);
+            i += 4;
+        } else {
+            decoded.push(string[i]);
+            i += 1;
+        }
+    }
+
+    log::debug!(
+        "encode::decode_string decoded {:?} to {:?}",
+        string,
+        decoded
+    );
+
+    Ok(decoded)
+}
+
 /// Processes white-space characters. Keeps all valid ASCII and UTF-8 characters, then replaces everything else with `_`.
 pub fn to_safe_string<T>(bytes: T) -> String
 where
@@ -106,6 +157,25 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
     use std::ffi::CString;
 
+    #[test]
+    fn decode_string_correctly_decodes_hex_sequences() -> crate::Result<()> {
+        let actual = decode_string(r"my\x20disk\x5c")?;
+        let expected = b"my disk\\".to_vec();
+        assert_eq!(actual, expected);
+
+        let actual = decode_string(r"caf\xc3\xa9")?;
+        let expected = "caf\u{e9}".as_bytes().to_vec();
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_string_rejects_invalid_hex_sequences() {
+        assert!(decode_string(r"my\x2").is_err());
+        assert!(decode_string(r"my\xZZdisk").is_err());
+    }
+
     #[test]
     fn to_safe_string_correctly_processes_an_empty_byte_string() {
         let bytes = b"".to_vec();
@@ -0,0 +1,76 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::path::{Path, PathBuf};
+
+// From this library
+use crate::core::device::Tag;
+
+/// Tags of a single device, as printed by one record of `blkid`'s `export` or `full` output
+/// formats.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeviceTags {
+    device_name: Option<PathBuf>,
+    tags: Vec<Tag>,
+}
+
+impl DeviceTags {
+    /// Creates a new `DeviceTags` for an unnamed device.
+    pub fn new<I>(tags: I) -> DeviceTags
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        Self {
+            device_name: None,
+            tags: tags.into_iter().collect(),
+        }
+    }
+
+    /// Creates a new `DeviceTags` for the device named `device_name`.
+    pub fn with_device_name<T, I>(device_name: T, tags: I) -> DeviceTags
+    where
+        T: AsRef<Path>,
+        I: IntoIterator<Item = Tag>,
+    {
+        Self {
+            device_name: Some(device_name.as_ref().to_path_buf()),
+            tags: tags.into_iter().collect(),
+        }
+    }
+
+    /// Returns the device's name, if set.
+    pub fn device_name(&self) -> Option<&Path> {
+        self.device_name.as_deref()
+    }
+
+    /// Returns the device's tags.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Consumes this `DeviceTags`, returning the device's tags.
+    pub fn into_tags(self) -> Vec<Tag> {
+        self.tags
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns `true` if this `DeviceTags` has neither a name, nor tags.
+    pub(super) fn is_empty(&self) -> bool {
+        self.device_name.is_none() && self.tags.is_empty()
+    }
+
+    #[doc(hidden)]
+    /// Helper function: sets the device's name.
+    pub(super) fn set_device_name(&mut self, device_name: PathBuf) {
+        self.device_name = Some(device_name);
+    }
+
+    #[doc(hidden)]
+    /// Helper function: adds a tag to the device.
+    pub(super) fn push(&mut self, tag: Tag) {
+        self.tags.push(tag);
+    }
+}
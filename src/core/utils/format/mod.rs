@@ -0,0 +1,577 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Convert device tags to, and from, the output formats of the `blkid` command-line tool.
+//!
+//! | Format   | `blkid` option | Sample output                            |
+//! | -------- | -------------- | ---------------------------------------- |
+//! | `value`  | `-o value`     | `ext4`                                   |
+//! | `export` | `-o export`    | `DEVNAME=/dev/vda1`<br>`TYPE=ext4`       |
+//! | `full`   | `-o full`      | `/dev/vda1: LABEL="nixos" TYPE="ext4"`   |
+//! | `udev`   | `-o udev`      | `ID_FS_LABEL=nixos`<br>`ID_FS_TYPE=ext4` |
+//!
+//! Like `blkid`, the `export` and `full` serializers replace non-printable characters with
+//! their `^` and `M-` notations (e.g. `^I` for a tab, `M-CM-)` for `é`), and backslash-escape
+//! shell meta-characters.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! # use pretty_assertions::assert_eq;
+//! use rsblkid::core::device::Tag;
+//! use rsblkid::core::utils::format;
+//! use rsblkid::core::utils::format::DeviceTags;
+//!
+//! fn main() -> rsblkid::Result<()> {
+//!     let label: Tag = "LABEL=my disk".parse()?;
+//!     let fs_type: Tag = "TYPE=ext4".parse()?;
+//!     let device = DeviceTags::with_device_name("/dev/vda1", vec![label, fs_type]);
+//!
+//!     let actual = format::to_export_format(&[device.clone()]);
+//!     let expected = "DEVNAME=/dev/vda1\nLABEL=my\\ disk\nTYPE=ext4\n";
+//!     assert_eq!(actual, expected);
+//!
+//!     let actual = format::parse_export_format(expected)?;
+//!     let expected = vec![device];
+//!     assert_eq!(actual, expected);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+// From dependency library
+
+// From standard library
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::errors::EncodeError;
+use crate::core::errors::FormatError;
+use crate::core::utils::encode::decode_string;
+
+pub use device_tags_struct::DeviceTags;
+
+mod device_tags_struct;
+
+/// Characters `blkid` escapes with a backslash in the `export` format.
+const EXPORT_ESCAPED_CHARS: &[u8] = b" \\\"'$`<>";
+
+/// Characters `blkid` escapes with a backslash in the `full` format.
+const FULL_ESCAPED_CHARS: &[u8] = b"\"\\";
+
+#[doc(hidden)]
+/// Helper function: returns a `Tag`'s value, without replacing white space or invalid UTF-8
+/// characters.
+fn raw_value(tag: &Tag) -> &[u8] {
+    match tag {
+        Tag::Label(value)
+        | Tag::Uuid(value)
+        | Tag::PtMagic(value)
+        | Tag::SbMagic(value)
+        | Tag::LabelRaw(value)
+        | Tag::UuidRaw(value)
+        | Tag::UuidSub(value)
+        | Tag::LabelFatboot(value) => value.as_bytes(),
+        _otherwise => tag.value().as_bytes(),
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: converts `bytes` to a printable string, escaping the characters in
+/// `escaped_chars` with a backslash (for more information, see the function
+/// [`safe_print`](https://github.com/util-linux/util-linux/blob/stable/v2.39/misc-utils/blkid.c#L131)
+/// from `blkid`).
+fn safe_print(bytes: &[u8], escaped_chars: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len());
+
+    for &byte in bytes {
+        let mut ch = byte;
+
+        if ch >= 128 {
+            output.push_str("M-");
+            ch -= 128;
+        }
+
+        if ch < 32 || ch == 0x7f {
+            output.push('^');
+            ch ^= 0x40;
+        }
+
+        if escaped_chars.contains(&ch) {
+            output.push('\\');
+        }
+
+        output.push(ch as char);
+    }
+
+    output
+}
+
+#[doc(hidden)]
+/// Helper function: returns the character at index `i`, and the number of bytes it spans
+/// (i.e. 2 if it is escaped by a backslash).
+fn next_char(bytes: &[u8], i: usize) -> Option<(u8, usize)> {
+    match bytes.get(i) {
+        Some(b'\\') if i + 1 < bytes.len() => Some((bytes[i + 1], 2)),
+        Some(&ch) => Some((ch, 1)),
+        None => None,
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: reverts the transformations applied by [`safe_print`].
+///
+/// `blkid`'s `M-` notation is ambiguous, a value like `TEAM-A` is printed as-is. Since labels are
+/// almost always valid UTF-8, `M-` sequences are decoded only if the result is valid UTF-8.
+fn unescape(value: &str) -> Vec<u8> {
+    let decoded = unescape_with(value.as_bytes(), true);
+
+    if std::str::from_utf8(&decoded).is_ok() {
+        decoded
+    } else {
+        unescape_with(value.as_bytes(), false)
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: reverts the transformations applied by [`safe_print`], decoding `M-`
+/// sequences only if `decode_meta` is `true`.
+fn unescape_with(bytes: &[u8], decode_meta: bool) -> Vec<u8> {
+    let is_caret_notation = |ch: u8| (b'@'..=b'_').contains(&ch) || ch == b'?';
+
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let mut meta = 0;
+        if decode_meta && bytes[i..].starts_with(b"M-") && i + 2 < bytes.len() {
+            meta = 128;
+            i += 2;
+        }
+
+        let (ch, length) = match bytes[i] {
+            b'^' => match next_char(bytes, i + 1) {
+                Some((ch, length)) if is_caret_notation(ch) => (ch ^ 0x40, length + 1),
+                _otherwise => (b'^', 1),
+            },
+            _otherwise => next_char(bytes, i).unwrap_or((bytes[i], 1)),
+        };
+
+        decoded.push(ch | meta);
+        i += length;
+    }
+
+    decoded
+}
+
+#[doc(hidden)]
+/// Helper function: converts a tag name and its raw value to a `Tag`.
+fn to_tag(name: &str, value: Vec<u8>) -> Result<Tag, FormatError> {
+    let tag_name = TagName::from_str(name)?;
+    let tag = Tag::try_from((tag_name, value))?;
+
+    Ok(tag)
+}
+
+/// Serializes `tags` to `blkid`'s `value` output format, i.e. one raw tag value per line.
+pub fn to_value_format(tags: &[Tag]) -> String {
+    tags.iter()
+        .map(|tag| format!("{}\n", String::from_utf8_lossy(raw_value(tag))))
+        .collect()
+}
+
+/// Serializes `devices` to `blkid`'s `export` output format, i.e. one `NAME=value` pair per
+/// line, with records separated by an empty line.
+///
+/// Each record starts with a `DEVNAME=` line, if the device's name is set.
+pub fn to_export_format(devices: &[DeviceTags]) -> String {
+    devices
+        .iter()
+        .map(|device| {
+            let mut record = String::new();
+
+            if let Some(device_name) = device.device_name() {
+                record.push_str(&format!("DEVNAME={}\n", device_name.display()));
+            }
+
+            for tag in device.tags() {
+                let value = safe_print(raw_value(tag), EXPORT_ESCAPED_CHARS);
+                record.push_str(&format!("{}={}\n", tag.name(), value));
+            }
+
+            record
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Serializes `devices` to `blkid`'s `full` output format, i.e. one line per device, starting with
+/// the device's name followed by a list of `NAME="value"` pairs.
+///
+/// Like `blkid`, skips devices without tags.
+pub fn to_full_format(devices: &[DeviceTags]) -> String {
+    devices
+        .iter()
+        .filter(|device| !device.tags().is_empty())
+        .map(|device| {
+            let mut line = device
+                .device_name()
+                .map(|device_name| format!("{}:", device_name.display()))
+                .unwrap_or_default();
+
+            for tag in device.tags() {
+                let value = safe_print(raw_value(tag), FULL_ESCAPED_CHARS);
+                line.push_str(&format!(r#" {}="{}""#, tag.name(), value));
+            }
+
+            line.push('\n');
+
+            line
+        })
+        .collect()
+}
+
+/// Serializes `tags` to `blkid`'s `udev` output format, i.e. one `ID_*=value` pair per line.
+///
+/// See [`Tag::to_udev_format`].
+pub fn to_udev_format(tags: &[Tag]) -> Result<String, EncodeError> {
+    tags.iter()
+        .map(|tag| tag.to_udev_format().map(|line| format!("{}\n", line)))
+        .collect()
+}
+
+/// Parses `blkid`'s `value` output format, assigning each line to the matching tag name in
+/// `tag_names`.
+///
+/// The `value` format does not include tag names. Callers have to supply them, in the order they
+/// were requested from `blkid` (e.g. `blkid -o value -s TYPE -s UUID`).
+pub fn parse_value_format(s: &str, tag_names: &[TagName]) -> Result<Vec<Tag>, FormatError> {
+    let values: Vec<&str> = s.lines().collect();
+
+    if values.len() != tag_names.len() {
+        let err_msg = format!(
+            "expected {} values, found {}",
+            tag_names.len(),
+            values.len()
+        );
+
+        return Err(FormatError::Syntax(err_msg));
+    }
+
+    tag_names
+        .iter()
+        .zip(values)
+        .map(|(&tag_name, value)| {
+            let tag = Tag::try_from((tag_name, value))?;
+
+            Ok(tag)
+        })
+        .collect()
+}
+
+/// Parses `blkid`'s `export` output format.
+///
+/// Records are separated by empty lines, or start with a `DEVNAME=` line.
+pub fn parse_export_format(s: &str) -> Result<Vec<DeviceTags>, FormatError> {
+    let mut devices = Vec::new();
+    let mut current = DeviceTags::new(Vec::new());
+
+    for (line_number, line) in s.lines().enumerate() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                devices.push(std::mem::replace(&mut current, DeviceTags::new(Vec::new())));
+            }
+
+            continue;
+        }
+
+        let (name, value) = line.split_once('=').ok_or_else(|| {
+            let err_msg = format!("line {}: missing `=` sign in {:?}", line_number + 1, line);
+            FormatError::Syntax(err_msg)
+        })?;
+
+        if name == "DEVNAME" {
+            if !current.is_empty() {
+                devices.push(std::mem::replace(&mut current, DeviceTags::new(Vec::new())));
+            }
+
+            current.set_device_name(PathBuf::from(value));
+        } else {
+            let tag = to_tag(name, unescape(value))?;
+            current.push(tag);
+        }
+    }
+
+    if !current.is_empty() {
+        devices.push(current);
+    }
+
+    Ok(devices)
+}
+
+/// Parses `blkid`'s `full` output format.
+pub fn parse_full_format(s: &str) -> Result<Vec<DeviceTags>, FormatError> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_number, line)| {
+            parse_full_format_line(line).map_err(|e| match e {
+                FormatError::Syntax(err_msg) => {
+                    FormatError::Syntax(format!("line {}: {}", line_number + 1, err_msg))
+                }
+                e => e,
+            })
+        })
+        .collect()
+}
+
+#[doc(hidden)]
+/// Helper function: parses a single line of `blkid`'s `full` output format.
+fn parse_full_format_line(line: &str) -> Result<DeviceTags, FormatError> {
+    // Device names may include `:` (e.g. `/dev/disk/by-path/pci-0000:00:1f.2-ata-1`), but are
+    // always followed by `: ` or the end of the line.
+    let (device_name, mut rest) = if line.starts_with(' ') {
+        (None, line)
+    } else if let Some((device_name, rest)) = line.split_once(": ") {
+        (Some(device_name), rest)
+    } else if let Some(device_name) = line.strip_suffix(':') {
+        (Some(device_name), "")
+    } else {
+        let err_msg = format!("missing device name in {:?}", line);
+        return Err(FormatError::Syntax(err_msg));
+    };
+
+    let mut device = DeviceTags::new(Vec::new());
+    if let Some(device_name) = device_name {
+        device.set_device_name(PathBuf::from(device_name));
+    }
+
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        let (name, value) = rest
+            .split_once("=\"")
+            .ok_or_else(|| FormatError::Syntax(format!("missing `=\"` in {:?}", rest)))?;
+
+        // Find the closing double-quote, skipping backslash-escaped characters.
+        let bytes = value.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && bytes[i] != b'"' {
+            i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+
+        if i >= bytes.len() {
+            let err_msg = format!("missing closing double-quote in {:?}", rest);
+            return Err(FormatError::Syntax(err_msg));
+        }
+
+        let tag = to_tag(name, unescape(&value[..i]))?;
+        device.push(tag);
+
+        rest = &value[i + 1..];
+    }
+
+    Ok(device)
+}
+
+#[doc(hidden)]
+/// Helper function: converts a `udev` property key to the name of the matching `Tag`, `None` if
+/// the key does not match any tag.
+fn udev_key_to_tag_name(key: &str) -> Option<TagName> {
+    let name = match key {
+        "ID_PART_TABLE_TYPE" => Some("PTTYPE"),
+        "ID_PART_TABLE_UUID" => Some("PTUUID"),
+        // Keys set by udev's `blkid` builtin.
+        "ID_FS_SIZE" => Some("FSSIZE"),
+        "ID_FS_LASTBLOCK" => Some("FSLASTBLOCK"),
+        "ID_FS_BLOCKSIZE" => Some("FSBLOCKSIZE"),
+        key => key
+            .strip_prefix("ID_FS_")
+            .or_else(|| key.strip_prefix("ID_IOLIMIT_"))
+            .or_else(|| {
+                key.strip_prefix("ID_")
+                    .filter(|name| name.starts_with("PART_ENTRY_"))
+            }),
+    }?;
+
+    TagName::from_str(name).ok()
+}
+
+/// Parses `blkid`'s `udev` output format.
+///
+/// Lines with keys that do not match a [`Tag`] (e.g. `ID_MODEL` when parsing the output of
+/// `udevadm info`) are ignored.
+///
+/// `ID_FS_LABEL`, `ID_FS_UUID`, and `ID_FS_UUID_SUB` hold safe strings, in which white space is
+/// replaced by `_`. When present, their encoded counterparts (e.g. `ID_FS_LABEL_ENC`) are decoded
+/// with [`decode_string`] and take precedence.
+pub fn parse_udev_format(s: &str) -> Result<Vec<Tag>, FormatError> {
+    let mut tags: Vec<Tag> = Vec::new();
+    let mut decoded_tag_names = Vec::new();
+
+    for (line_number, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            let err_msg = format!("line {}: missing `=` sign in {:?}", line_number + 1, line);
+            FormatError::Syntax(err_msg)
+        })?;
+
+        if let Some(key) = key.strip_suffix("_ENC") {
+            if let Some(tag_name) = udev_key_to_tag_name(key) {
+                let tag = Tag::try_from((tag_name, decode_string(value)?))?;
+
+                match tags.iter_mut().find(|t| t.name() == tag_name) {
+                    Some(safe_tag) => *safe_tag = tag,
+                    None => tags.push(tag),
+                }
+
+                decoded_tag_names.push(tag_name);
+            }
+        } else if let Some(tag_name) = udev_key_to_tag_name(key) {
+            if !decoded_tag_names.contains(&tag_name) {
+                let tag = Tag::from_str(&format!("{}={}", tag_name, value))?;
+                tags.push(tag);
+            }
+        }
+    }
+
+    Ok(tags)
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::partition::RawBytes;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn safe_print_escapes_non_printable_characters() {
+        let actual = safe_print(b"a\tb\x7f", FULL_ESCAPED_CHARS);
+        let expected = "a^Ib^?";
+        assert_eq!(actual, expected);
+
+        let actual = safe_print("é".as_bytes(), FULL_ESCAPED_CHARS);
+        let expected = "M-CM-)";
+        assert_eq!(actual, expected);
+
+        let actual = safe_print(br#"my "disk""#, EXPORT_ESCAPED_CHARS);
+        let expected = r#"my\ \"disk\""#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unescape_reverts_safe_print() {
+        let values: [&[u8]; 5] = [
+            b"plain",
+            b"with white space",
+            "caf\u{e9}".as_bytes(),
+            b"\x1c\"$`<>'",
+            b"TEAM-A^b",
+        ];
+
+        for value in values {
+            for escaped_chars in [EXPORT_ESCAPED_CHARS, FULL_ESCAPED_CHARS] {
+                let escaped = safe_print(value, escaped_chars);
+                let actual = unescape(&escaped);
+                assert_eq!(actual, value);
+            }
+        }
+    }
+
+    #[test]
+    fn format_can_serialize_and_parse_the_export_format() -> crate::Result<()> {
+        let devices = vec![
+            DeviceTags::with_device_name(
+                "/dev/vda1",
+                vec![
+                    Tag::Label(RawBytes::from("my disk")),
+                    Tag::from_str("TYPE=ext4")?,
+                ],
+            ),
+            DeviceTags::with_device_name("/dev/vda2", vec![Tag::from_str("TYPE=swap")?]),
+        ];
+
+        let actual = to_export_format(&devices);
+        let expected =
+            "DEVNAME=/dev/vda1\nLABEL=my\\ disk\nTYPE=ext4\n\nDEVNAME=/dev/vda2\nTYPE=swap\n";
+        assert_eq!(actual, expected);
+
+        let actual = parse_export_format(&actual)?;
+        assert_eq!(actual, devices);
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_can_serialize_and_parse_the_full_format() -> crate::Result<()> {
+        let devices = vec![
+            DeviceTags::with_device_name(
+                "/dev/disk/by-path/pci-0000:00:1f.2-ata-1",
+                vec![
+                    Tag::Label(RawBytes::from(r#"say "hi""#)),
+                    Tag::from_str("TYPE=vfat")?,
+                ],
+            ),
+            DeviceTags::with_device_name("/dev/vda2", vec![]),
+        ];
+
+        let actual = to_full_format(&devices);
+        // `/dev/vda2` has no tags, it is left out.
+        let expected =
+            "/dev/disk/by-path/pci-0000:00:1f.2-ata-1: LABEL=\"say \\\"hi\\\"\" TYPE=\"vfat\"\n";
+        assert_eq!(actual, expected);
+
+        let actual = parse_full_format(&actual)?;
+        assert_eq!(actual, devices[..1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_can_serialize_and_parse_the_value_format() -> crate::Result<()> {
+        let tags = vec![Tag::from_str("TYPE=ext4")?, Tag::from_str("VERSION=1.0")?];
+
+        let actual = to_value_format(&tags);
+        let expected = "ext4\n1.0\n";
+        assert_eq!(actual, expected);
+
+        let actual = parse_value_format(&actual, &[TagName::Type, TagName::Version])?;
+        assert_eq!(actual, tags);
+
+        let actual = parse_value_format("ext4\n", &[TagName::Type, TagName::Version]);
+        assert!(actual.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_can_parse_the_udev_format() -> crate::Result<()> {
+        let udev = "ID_FS_LABEL=my_disk\nID_FS_LABEL_ENC=my\\x20disk\nID_FS_TYPE=ext4\nID_FS_BLOCKSIZE=4096\nID_PART_TABLE_TYPE=gpt\nID_PART_ENTRY_NUMBER=1\nID_MODEL=QEMU\n";
+
+        let actual = parse_udev_format(udev)?;
+        let expected = vec![
+            Tag::Label(RawBytes::from("my disk")),
+            Tag::from_str("TYPE=ext4")?,
+            Tag::from_str("FSBLOCKSIZE=4096")?,
+            Tag::from_str("PTTYPE=gpt")?,
+            Tag::from_str("PART_ENTRY_NUMBER=1")?,
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = parse_udev_format("ID_FS_TYPE");
+        assert!(actual.is_err());
+
+        Ok(())
+    }
+}
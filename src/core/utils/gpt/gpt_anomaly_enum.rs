@@ -0,0 +1,203 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::partition::Severity;
+use crate::core::utils::gpt::GptLocation;
+
+/// An integrity problem in a GUID Partition Table, reported by [`verify`](crate::core::utils::gpt::verify).
+///
+/// Locations are expressed in logical sectors (LBA).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum GptAnomaly {
+    /// The header, or its partition entry array, could not be read.
+    Unreadable { location: GptLocation },
+    /// The header does not start with the `"EFI PART"` signature.
+    BadSignature { location: GptLocation },
+    /// The header's revision is not `1.x`.
+    UnsupportedRevision {
+        location: GptLocation,
+        revision: u32,
+    },
+    /// The header's size is smaller than 92 bytes, or larger than a sector.
+    BadHeaderSize { location: GptLocation, size: u32 },
+    /// The CRC32 stored in the header does not match its content.
+    HeaderCrcMismatch {
+        location: GptLocation,
+        stored: u32,
+        computed: u32,
+    },
+    /// The partition entry array CRC32 stored in the header does not match the array's content.
+    EntriesCrcMismatch {
+        location: GptLocation,
+        stored: u32,
+        computed: u32,
+    },
+    /// The header's `MyLBA` field does not match the sector it was read from.
+    WrongMyLba {
+        location: GptLocation,
+        expected: u64,
+        found: u64,
+    },
+    /// The header's `AlternateLBA` field points to the wrong sector.
+    WrongAlternateLba {
+        location: GptLocation,
+        expected: u64,
+        found: u64,
+    },
+    /// The backup header is not on the device's last sector (e.g. after enlarging a disk image).
+    BackupNotAtDeviceEnd { expected: u64, found: u64 },
+    /// The first and last usable sectors are out of order, or overlap a header or a partition
+    /// entry array.
+    BadUsableRange {
+        location: GptLocation,
+        first: u64,
+        last: u64,
+    },
+    /// The partition entry array has an invalid entry size, or does not fit on the device.
+    BadEntryArray {
+        location: GptLocation,
+        count: u32,
+        size: u32,
+    },
+    /// Primary and backup headers disagree on the value of `field`.
+    HeadersDisagree { field: &'static str },
+}
+
+impl GptAnomaly {
+    /// Returns the GPT copy this anomaly concerns, `None` if it concerns both.
+    pub fn location(&self) -> Option<GptLocation> {
+        match self {
+            Self::Unreadable { location }
+            | Self::BadSignature { location }
+            | Self::UnsupportedRevision { location, .. }
+            | Self::BadHeaderSize { location, .. }
+            | Self::HeaderCrcMismatch { location, .. }
+            | Self::EntriesCrcMismatch { location, .. }
+            | Self::WrongMyLba { location, .. }
+            | Self::WrongAlternateLba { location, .. }
+            | Self::BadUsableRange { location, .. }
+            | Self::BadEntryArray { location, .. } => Some(*location),
+            Self::BackupNotAtDeviceEnd { .. } => Some(GptLocation::Backup),
+            Self::HeadersDisagree { .. } => None,
+        }
+    }
+
+    /// Returns how serious this `GptAnomaly` is.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::BackupNotAtDeviceEnd { .. } | Self::UnsupportedRevision { .. } => {
+                Severity::Warning
+            }
+            _otherwise => Severity::Error,
+        }
+    }
+
+    /// Returns a short identifier of this `GptAnomaly`'s kind, suitable as a metric or alert
+    /// label.
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::Unreadable { .. } => "unreadable",
+            Self::BadSignature { .. } => "bad-signature",
+            Self::UnsupportedRevision { .. } => "unsupported-revision",
+            Self::BadHeaderSize { .. } => "bad-header-size",
+            Self::HeaderCrcMismatch { .. } => "header-crc-mismatch",
+            Self::EntriesCrcMismatch { .. } => "entries-crc-mismatch",
+            Self::WrongMyLba { .. } => "wrong-my-lba",
+            Self::WrongAlternateLba { .. } => "wrong-alternate-lba",
+            Self::BackupNotAtDeviceEnd { .. } => "backup-not-at-device-end",
+            Self::BadUsableRange { .. } => "bad-usable-range",
+            Self::BadEntryArray { .. } => "bad-entry-array",
+            Self::HeadersDisagree { .. } => "headers-disagree",
+        }
+    }
+}
+
+impl fmt::Display for GptAnomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unreadable { location } => write!(f, "{} GPT is unreadable", location),
+            Self::BadSignature { location } => {
+                write!(f, "{} GPT header has an invalid signature", location)
+            }
+            Self::UnsupportedRevision { location, revision } => write!(
+                f,
+                "{} GPT header has an unsupported revision: {:#010x}",
+                location, revision
+            ),
+            Self::BadHeaderSize { location, size } => write!(
+                f,
+                "{} GPT header has an invalid size: {} bytes",
+                location, size
+            ),
+            Self::HeaderCrcMismatch {
+                location,
+                stored,
+                computed,
+            } => write!(
+                f,
+                "{} GPT header CRC32 mismatch: stored {:#010x}, computed {:#010x}",
+                location, stored, computed
+            ),
+            Self::EntriesCrcMismatch {
+                location,
+                stored,
+                computed,
+            } => write!(
+                f,
+                "{} GPT partition entries CRC32 mismatch: stored {:#010x}, computed {:#010x}",
+                location, stored, computed
+            ),
+            Self::WrongMyLba {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} GPT header MyLBA is {} instead of {}",
+                location, found, expected
+            ),
+            Self::WrongAlternateLba {
+                location,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} GPT header AlternateLBA is {} instead of {}",
+                location, found, expected
+            ),
+            Self::BackupNotAtDeviceEnd { expected, found } => write!(
+                f,
+                "backup GPT header is at sector {} instead of the last sector {}",
+                found, expected
+            ),
+            Self::BadUsableRange {
+                location,
+                first,
+                last,
+            } => write!(
+                f,
+                "{} GPT header has an invalid usable range: {}-{}",
+                location, first, last
+            ),
+            Self::BadEntryArray {
+                location,
+                count,
+                size,
+            } => write!(
+                f,
+                "{} GPT header has an invalid partition entry array: {} entries of {} bytes",
+                location, count, size
+            ),
+            Self::HeadersDisagree { field } => {
+                write!(f, "primary and backup GPT headers disagree on {}", field)
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::errors::GptError;
+use crate::core::partition::Guid;
+use crate::core::partition::PartitionBitflags;
+use crate::core::utils::gpt;
+
+/// An entry in a GUID Partition Table's partition entry array.
+///
+/// Locations are expressed in logical sectors (LBA).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GptEntry {
+    type_guid: [u8; 16],
+    unique_guid: [u8; 16],
+    first_lba: u64,
+    last_lba: u64,
+    attributes: u64,
+    name: [u16; 36],
+}
+
+impl GptEntry {
+    #[doc(hidden)]
+    /// Creates a new `GptEntry`, with its `name` truncated to 36 UTF-16 code units.
+    pub(crate) fn new(
+        type_guid: [u8; 16],
+        unique_guid: [u8; 16],
+        first_lba: u64,
+        last_lba: u64,
+        attributes: u64,
+        name: &str,
+    ) -> GptEntry {
+        let mut units = [0u16; 36];
+        for (unit, c) in units.iter_mut().zip(name.encode_utf16()) {
+            *unit = c;
+        }
+
+        Self {
+            type_guid,
+            unique_guid,
+            first_lba,
+            last_lba,
+            attributes,
+            name: units,
+        }
+    }
+
+    /// Parses a GPT partition entry from the first 128 bytes of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<GptEntry, GptError> {
+        if bytes.len() < gpt::ENTRY_SIZE {
+            let err_msg = format!(
+                "GPT partition entry too short: expected at least {} bytes, found {}",
+                gpt::ENTRY_SIZE,
+                bytes.len()
+            );
+
+            return Err(GptError::Parse(err_msg));
+        }
+
+        let u64_at = |i: usize| {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(&bytes[i..i + 8]);
+            u64::from_le_bytes(buffer)
+        };
+
+        let mut type_guid = [0u8; 16];
+        type_guid.copy_from_slice(&bytes[0..16]);
+        let mut unique_guid = [0u8; 16];
+        unique_guid.copy_from_slice(&bytes[16..32]);
+
+        let mut name = [0u16; 36];
+        for (i, unit) in name.iter_mut().enumerate() {
+            *unit = u16::from_le_bytes([bytes[56 + 2 * i], bytes[57 + 2 * i]]);
+        }
+
+        let entry = Self {
+            type_guid,
+            unique_guid,
+            first_lba: u64_at(32),
+            last_lba: u64_at(40),
+            attributes: u64_at(48),
+            name,
+        };
+
+        Ok(entry)
+    }
+
+    /// Serializes this entry to its 128-byte on-disk format.
+    pub fn to_bytes(&self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+
+        bytes[0..16].copy_from_slice(&self.type_guid);
+        bytes[16..32].copy_from_slice(&self.unique_guid);
+        bytes[32..40].copy_from_slice(&self.first_lba.to_le_bytes());
+        bytes[40..48].copy_from_slice(&self.last_lba.to_le_bytes());
+        bytes[48..56].copy_from_slice(&self.attributes.to_le_bytes());
+        for (i, unit) in self.name.iter().enumerate() {
+            bytes[56 + 2 * i..58 + 2 * i].copy_from_slice(&unit.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Returns `true` if this entry is unused (i.e. its partition type GUID is all zeroes).
+    pub fn is_empty(&self) -> bool {
+        self.type_guid == [0u8; 16]
+    }
+
+    /// Returns the partition type GUID, as a lowercase string.
+    pub fn type_guid_string(&self) -> String {
+        gpt::guid_to_string(&self.type_guid)
+    }
+
+    /// Returns the partition type, if supported.
+    pub fn partition_type(&self) -> Option<Guid> {
+        self.type_guid_string().parse().ok()
+    }
+
+    /// Returns the partition's unique GUID, as a lowercase string.
+    pub fn unique_guid_string(&self) -> String {
+        gpt::guid_to_string(&self.unique_guid)
+    }
+
+    /// Returns the partition's first sector.
+    pub fn first_lba(&self) -> u64 {
+        self.first_lba
+    }
+
+    /// Returns the partition's last sector (inclusive).
+    pub fn last_lba(&self) -> u64 {
+        self.last_lba
+    }
+
+    /// Returns the partition's size in sectors.
+    pub fn size_in_sectors(&self) -> u64 {
+        (self.last_lba + 1).saturating_sub(self.first_lba)
+    }
+
+    /// Returns the partition's attributes.
+    pub fn attributes(&self) -> PartitionBitflags {
+        PartitionBitflags::from(self.attributes)
+    }
+
+    /// Returns the partition's name, decoded from UTF-16LE.
+    pub fn name(&self) -> String {
+        let length = self.name.iter().position(|&u| u == 0).unwrap_or(36);
+
+        String::from_utf16_lossy(&self.name[..length])
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Uuid;
+use crate::core::errors::GptError;
+use crate::core::utils::gpt;
+
+/// A GUID Partition Table header.
+///
+/// All locations are expressed in logical sectors (LBA).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GptHeader {
+    signature: [u8; 8],
+    revision: u32,
+    header_size: u32,
+    header_crc32: u32,
+    my_lba: u64,
+    alternate_lba: u64,
+    first_usable_lba: u64,
+    last_usable_lba: u64,
+    disk_guid: [u8; 16],
+    partition_entry_lba: u64,
+    number_of_entries: u32,
+    entry_size: u32,
+    entries_crc32: u32,
+}
+
+impl GptHeader {
+    #[doc(hidden)]
+    /// Creates a new revision 1.0 `GptHeader`, with a computed header CRC32.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        my_lba: u64,
+        alternate_lba: u64,
+        first_usable_lba: u64,
+        last_usable_lba: u64,
+        disk_guid: [u8; 16],
+        partition_entry_lba: u64,
+        number_of_entries: u32,
+        entries_crc32: u32,
+    ) -> GptHeader {
+        let mut header = Self {
+            signature: *gpt::SIGNATURE,
+            revision: gpt::REVISION,
+            header_size: gpt::HEADER_SIZE as u32,
+            header_crc32: 0,
+            my_lba,
+            alternate_lba,
+            first_usable_lba,
+            last_usable_lba,
+            disk_guid,
+            partition_entry_lba,
+            number_of_entries,
+            entry_size: gpt::ENTRY_SIZE as u32,
+            entries_crc32,
+        };
+        header.header_crc32 = header.compute_crc32();
+
+        header
+    }
+
+    /// Parses a GPT header from the first 92 bytes of `bytes`, without checking its validity.
+    pub fn from_bytes(bytes: &[u8]) -> Result<GptHeader, GptError> {
+        if bytes.len() < gpt::HEADER_SIZE {
+            let err_msg = format!(
+                "GPT header too short: expected at least {} bytes, found {}",
+                gpt::HEADER_SIZE,
+                bytes.len()
+            );
+
+            return Err(GptError::Parse(err_msg));
+        }
+
+        let u32_at =
+            |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let u64_at = |i: usize| {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(&bytes[i..i + 8]);
+            u64::from_le_bytes(buffer)
+        };
+
+        let mut signature = [0u8; 8];
+        signature.copy_from_slice(&bytes[0..8]);
+        let mut disk_guid = [0u8; 16];
+        disk_guid.copy_from_slice(&bytes[56..72]);
+
+        let header = Self {
+            signature,
+            revision: u32_at(8),
+            header_size: u32_at(12),
+            header_crc32: u32_at(16),
+            my_lba: u64_at(24),
+            alternate_lba: u64_at(32),
+            first_usable_lba: u64_at(40),
+            last_usable_lba: u64_at(48),
+            disk_guid,
+            partition_entry_lba: u64_at(72),
+            number_of_entries: u32_at(80),
+            entry_size: u32_at(84),
+            entries_crc32: u32_at(88),
+        };
+
+        Ok(header)
+    }
+
+    /// Serializes this header to its 92-byte on-disk format, with the stored header CRC32.
+    pub fn to_bytes(&self) -> [u8; 92] {
+        let mut bytes = [0u8; 92];
+
+        bytes[0..8].copy_from_slice(&self.signature);
+        bytes[8..12].copy_from_slice(&self.revision.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.header_size.to_le_bytes());
+        bytes[16..20].copy_from_slice(&self.header_crc32.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.my_lba.to_le_bytes());
+        bytes[32..40].copy_from_slice(&self.alternate_lba.to_le_bytes());
+        bytes[40..48].copy_from_slice(&self.first_usable_lba.to_le_bytes());
+        bytes[48..56].copy_from_slice(&self.last_usable_lba.to_le_bytes());
+        bytes[56..72].copy_from_slice(&self.disk_guid);
+        bytes[72..80].copy_from_slice(&self.partition_entry_lba.to_le_bytes());
+        bytes[80..84].copy_from_slice(&self.number_of_entries.to_le_bytes());
+        bytes[84..88].copy_from_slice(&self.entry_size.to_le_bytes());
+        bytes[88..92].copy_from_slice(&self.entries_crc32.to_le_bytes());
+
+        bytes
+    }
+
+    /// Returns the header's signature, `"EFI PART"` for a valid header.
+    pub fn signature(&self) -> &[u8; 8] {
+        &self.signature
+    }
+
+    /// Returns `true` if the header starts with the `"EFI PART"` signature.
+    pub fn has_valid_signature(&self) -> bool {
+        &self.signature == gpt::SIGNATURE
+    }
+
+    /// Returns the GPT revision (`0x00010000` for version 1.0).
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    /// Returns the header's size in bytes.
+    pub fn header_size(&self) -> u32 {
+        self.header_size
+    }
+
+    /// Returns the header CRC32 stored on disk.
+    pub fn header_crc32(&self) -> u32 {
+        self.header_crc32
+    }
+
+    /// Returns the location of this header.
+    pub fn my_lba(&self) -> u64 {
+        self.my_lba
+    }
+
+    /// Returns the location of the other copy of this header.
+    pub fn alternate_lba(&self) -> u64 {
+        self.alternate_lba
+    }
+
+    /// Returns the first sector partitions may use.
+    pub fn first_usable_lba(&self) -> u64 {
+        self.first_usable_lba
+    }
+
+    /// Returns the last sector partitions may use.
+    pub fn last_usable_lba(&self) -> u64 {
+        self.last_usable_lba
+    }
+
+    /// Returns the disk's GUID, in its on-disk mixed-endian byte order.
+    pub fn disk_guid_bytes(&self) -> &[u8; 16] {
+        &self.disk_guid
+    }
+
+    /// Returns the disk's GUID.
+    pub fn disk_guid(&self) -> Uuid {
+        gpt::guid_to_string(&self.disk_guid)
+            .parse()
+            .expect("a GUID formatted by gpt::guid_to_string is a valid UUID")
+    }
+
+    /// Returns the location of the first sector of the partition entry array.
+    pub fn partition_entry_lba(&self) -> u64 {
+        self.partition_entry_lba
+    }
+
+    /// Returns the number of entries in the partition entry array.
+    pub fn number_of_entries(&self) -> u32 {
+        self.number_of_entries
+    }
+
+    /// Returns the size in bytes of a partition entry.
+    pub fn entry_size(&self) -> u32 {
+        self.entry_size
+    }
+
+    /// Returns the size in bytes of the partition entry array.
+    pub fn entries_size(&self) -> u64 {
+        self.number_of_entries as u64 * self.entry_size as u64
+    }
+
+    /// Returns the partition entry array CRC32 stored in the header.
+    pub fn entries_crc32(&self) -> u32 {
+        self.entries_crc32
+    }
+
+    /// Computes the CRC32 of this header, as if it were serialized with a zeroed CRC field.
+    pub fn compute_crc32(&self) -> u32 {
+        let mut bytes = self.to_bytes();
+        bytes[16..20].fill(0);
+
+        gpt::crc32(&bytes)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns a copy of this header moved to `my_lba`, with its partition
+    /// entry array at `partition_entry_lba`, and a recomputed header CRC32.
+    pub(crate) fn relocate(
+        &self,
+        my_lba: u64,
+        alternate_lba: u64,
+        partition_entry_lba: u64,
+        last_usable_lba: u64,
+    ) -> GptHeader {
+        let mut header = Self {
+            header_size: gpt::HEADER_SIZE as u32,
+            my_lba,
+            alternate_lba,
+            last_usable_lba,
+            partition_entry_lba,
+            ..self.clone()
+        };
+        header.header_crc32 = header.compute_crc32();
+
+        header
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::utils::gpt::GptLocation;
+
+/// A write operation planned by [`plan_repair`](crate::core::utils::gpt::plan_repair).
+///
+/// Locations are expressed in logical sectors (LBA).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum GptRepairAction {
+    /// Write the `location` partition entry array, spanning `sectors` sectors from `lba`.
+    WriteEntries {
+        location: GptLocation,
+        lba: u64,
+        sectors: u64,
+    },
+    /// Write the `location` header on sector `lba`.
+    WriteHeader { location: GptLocation, lba: u64 },
+    /// Erase a stale backup header left on sector `lba` (e.g. after enlarging a disk image).
+    EraseHeader { lba: u64 },
+}
+
+impl GptRepairAction {
+    /// Returns a short identifier of this `GptRepairAction`'s kind.
+    pub fn kind(&self) -> &str {
+        match self {
+            Self::WriteEntries { .. } => "write-entries",
+            Self::WriteHeader { .. } => "write-header",
+            Self::EraseHeader { .. } => "erase-header",
+        }
+    }
+}
+
+impl fmt::Display for GptRepairAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WriteEntries {
+                location,
+                lba,
+                sectors,
+            } => write!(
+                f,
+                "write {} GPT partition entries on sectors {}-{}",
+                location,
+                lba,
+                lba + sectors - 1
+            ),
+            Self::WriteHeader { location, lba } => {
+                write!(f, "write {} GPT header on sector {}", location, lba)
+            }
+            Self::EraseHeader { lba } => write!(f, "erase stale GPT header on sector {}", lba),
+        }
+    }
+}
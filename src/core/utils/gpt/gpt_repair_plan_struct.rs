@@ -0,0 +1,63 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::utils::gpt::GptHeader;
+use crate::core::utils::gpt::GptLocation;
+use crate::core::utils::gpt::GptRepairAction;
+
+/// Writes needed to rebuild a GUID Partition Table from one of its copies, produced by
+/// [`plan_repair`](crate::core::utils::gpt::plan_repair).
+///
+/// A `GptRepairPlan` doubles as a dry-run preview: nothing is written to the device until it is
+/// passed to [`apply_repair`](crate::core::utils::gpt::apply_repair).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GptRepairPlan {
+    pub(super) source: GptLocation,
+    pub(super) device_size: u64,
+    pub(super) sector_size: u64,
+    pub(super) primary: GptHeader,
+    pub(super) backup: GptHeader,
+    pub(super) actions: Vec<GptRepairAction>,
+    /// Byte offsets, and data to write at each offset (one item per action).
+    pub(super) writes: Vec<(u64, Vec<u8>)>,
+}
+
+impl GptRepairPlan {
+    /// Returns the copy the GPT is rebuilt from.
+    pub fn source(&self) -> GptLocation {
+        self.source
+    }
+
+    /// Returns the size of the device, in logical sectors.
+    pub fn device_size(&self) -> u64 {
+        self.device_size
+    }
+
+    /// Returns the size of a logical sector, in bytes.
+    pub fn sector_size(&self) -> u64 {
+        self.sector_size
+    }
+
+    /// Returns the `location` header, as it will be once the repair is applied.
+    pub fn header(&self, location: GptLocation) -> &GptHeader {
+        match location {
+            GptLocation::Primary => &self.primary,
+            GptLocation::Backup => &self.backup,
+        }
+    }
+
+    /// Returns the planned write operations, in the order they will be performed.
+    pub fn actions(&self) -> &[GptRepairAction] {
+        &self.actions
+    }
+
+    /// Returns `true` if the GPT needs no repair.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::partition::Severity;
+use crate::core::utils::gpt::GptAnomaly;
+use crate::core::utils::gpt::GptEntry;
+use crate::core::utils::gpt::GptHeader;
+use crate::core::utils::gpt::GptLocation;
+
+/// Result of the verification of both copies of a GUID Partition Table, produced by
+/// [`verify`](crate::core::utils::gpt::verify).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GptReport {
+    pub(super) device_size: u64,
+    pub(super) sector_size: u64,
+    pub(super) primary: Option<GptHeader>,
+    pub(super) primary_entries: Vec<GptEntry>,
+    pub(super) backup: Option<GptHeader>,
+    pub(super) backup_entries: Vec<GptEntry>,
+    pub(super) anomalies: Vec<GptAnomaly>,
+}
+
+impl GptReport {
+    /// Returns the size of the verified device, in logical sectors.
+    pub fn device_size(&self) -> u64 {
+        self.device_size
+    }
+
+    /// Returns the size of a logical sector, in bytes.
+    pub fn sector_size(&self) -> u64 {
+        self.sector_size
+    }
+
+    /// Returns the header of the `location` copy, if it has a valid signature and size.
+    pub fn header(&self, location: GptLocation) -> Option<&GptHeader> {
+        match location {
+            GptLocation::Primary => self.primary.as_ref(),
+            GptLocation::Backup => self.backup.as_ref(),
+        }
+    }
+
+    /// Returns the partition entry array of the `location` copy (empty if unreadable).
+    pub fn entries(&self, location: GptLocation) -> &[GptEntry] {
+        match location {
+            GptLocation::Primary => &self.primary_entries,
+            GptLocation::Backup => &self.backup_entries,
+        }
+    }
+
+    /// Returns all anomalies detected.
+    pub fn anomalies(&self) -> &[GptAnomaly] {
+        &self.anomalies
+    }
+
+    /// Returns the severity of the most serious anomaly, `None` if there are no anomalies.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.anomalies.iter().map(|a| a.severity()).max()
+    }
+
+    /// Returns `true` if the `location` copy was found, and is free of errors.
+    pub fn is_valid(&self, location: GptLocation) -> bool {
+        self.header(location).is_some()
+            && !self
+                .anomalies
+                .iter()
+                .any(|a| a.location() == Some(location) && a.severity() == Severity::Error)
+    }
+
+    /// Returns `true` if both copies are valid, and agree with each other.
+    pub fn is_consistent(&self) -> bool {
+        self.max_severity() < Some(Severity::Error)
+    }
+}
@@ -0,0 +1,1007 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Read and verify GUID Partition Tables (GPT), independently of `libblkid`.
+//!
+//! When one copy of a GPT is damaged, `libblkid` silently falls back to the other. The
+//! [`verify`] function checks both the primary and the backup copies, and reports every
+//! [`GptAnomaly`] it finds:
+//! - header signature, revision, size, and CRC32,
+//! - partition entry array size, and CRC32,
+//! - `MyLBA` and `AlternateLBA` fields,
+//! - first and last usable sectors,
+//! - disagreements between the primary and backup headers.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use std::fs::File;
+//! use rsblkid::core::utils::gpt;
+//! use rsblkid::core::utils::gpt::GptLocation;
+//!
+//! fn main() -> rsblkid::Result<()> {
+//!     let device = File::open("/dev/vda")?;
+//!     let report = gpt::verify(&device, 512)?;
+//!
+//!     if !report.is_valid(GptLocation::Primary) {
+//!         eprintln!("primary GPT is damaged");
+//!     }
+//!
+//!     for anomaly in report.anomalies() {
+//!         eprintln!("{}: {}", anomaly.severity(), anomaly);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+// From dependency library
+
+// From standard library
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::os::unix::fs::FileExt;
+
+// From this library
+use crate::core::errors::GptError;
+
+pub use gpt_anomaly_enum::GptAnomaly;
+pub use gpt_entry_struct::GptEntry;
+pub use gpt_header_struct::GptHeader;
+pub use gpt_location_enum::GptLocation;
+pub use gpt_repair_action_enum::GptRepairAction;
+pub use gpt_repair_plan_struct::GptRepairPlan;
+pub use gpt_report_struct::GptReport;
+
+mod gpt_anomaly_enum;
+mod gpt_entry_struct;
+mod gpt_header_struct;
+mod gpt_location_enum;
+mod gpt_repair_action_enum;
+mod gpt_repair_plan_struct;
+mod gpt_report_struct;
+
+/// Signature at the beginning of a GPT header.
+pub const SIGNATURE: &[u8; 8] = b"EFI PART";
+
+/// GPT revision 1.0.
+pub const REVISION: u32 = 0x0001_0000;
+
+/// Size in bytes of a GPT header.
+pub const HEADER_SIZE: usize = 92;
+
+/// Minimum size in bytes of a GPT partition entry.
+pub const ENTRY_SIZE: usize = 128;
+
+/// Upper bound of a partition entry array's size, to guard against corrupted headers.
+const MAX_ENTRIES_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Lookup table of the CRC32 (IEEE 802.3) algorithm used by GPT.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+#[doc(hidden)]
+/// Helper function: computes the lookup table of the reflected CRC32 polynomial `0xEDB88320`.
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
+
+/// Computes the CRC32 checksum of `bytes`, as used by GPT headers and partition entry arrays.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::utils::gpt;
+///
+/// assert_eq!(gpt::crc32(b"123456789"), 0xcbf43926);
+/// ```
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Converts a GUID in its on-disk mixed-endian byte order to a lowercase string.
+pub fn guid_to_string(bytes: &[u8; 16]) -> String {
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        u16::from_le_bytes([bytes[4], bytes[5]]),
+        u16::from_le_bytes([bytes[6], bytes[7]]),
+        bytes[8],
+        bytes[9],
+        bytes[10],
+        bytes[11],
+        bytes[12],
+        bytes[13],
+        bytes[14],
+        bytes[15]
+    )
+}
+
+/// Converts a GUID string (e.g. `"0fc63daf-8483-4772-8e79-3d69d8477de4"`) to its on-disk
+/// mixed-endian byte order, returns `None` if `guid` is not a valid GUID.
+pub fn guid_from_str(guid: &str) -> Option<[u8; 16]> {
+    let hex: String = guid.split('-').collect();
+    let is_canonical =
+        guid.len() == 36 && [8, 13, 18, 23].iter().all(|&i| guid.as_bytes()[i] == b'-');
+
+    if !is_canonical || hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
+    }
+
+    // The first three fields are stored little-endian.
+    bytes[0..4].reverse();
+    bytes[4..6].reverse();
+    bytes[6..8].reverse();
+
+    Some(bytes)
+}
+
+/// Generates a random (version 4) GUID, in its on-disk mixed-endian byte order.
+pub fn random_guid() -> Result<[u8; 16], GptError> {
+    let mut bytes = [0u8; 16];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+
+    // Set the version (4), and variant (RFC 4122) bits.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    // The first three fields are stored little-endian.
+    bytes[0..4].reverse();
+    bytes[4..6].reverse();
+    bytes[6..8].reverse();
+
+    Ok(bytes)
+}
+
+#[doc(hidden)]
+/// Helper function: returns the size of `file` in bytes.
+pub(crate) fn file_size(file: &File) -> Result<u64, GptError> {
+    let mut handle = file;
+    let size = handle.seek(SeekFrom::End(0))?;
+
+    Ok(size)
+}
+
+#[doc(hidden)]
+/// Helper function: checks that `sector_size` is a power of two, at least 512 bytes.
+pub(crate) fn check_sector_size(sector_size: u64) -> Result<(), GptError> {
+    if sector_size < 512 || !sector_size.is_power_of_two() {
+        let err_msg = format!("invalid sector size: {}", sector_size);
+
+        return Err(GptError::Config(err_msg));
+    }
+
+    Ok(())
+}
+
+#[doc(hidden)]
+/// Helper function: reads one copy of a GPT with its header at `lba`, and records its
+/// anomalies in `anomalies`.
+fn read_copy(
+    file: &File,
+    location: GptLocation,
+    lba: u64,
+    device_size: u64,
+    sector_size: u64,
+    anomalies: &mut Vec<GptAnomaly>,
+) -> (Option<GptHeader>, Vec<GptEntry>) {
+    let mut sector = vec![0u8; sector_size as usize];
+    let offset = lba.saturating_mul(sector_size);
+
+    if lba >= device_size || file.read_exact_at(&mut sector, offset).is_err() {
+        anomalies.push(GptAnomaly::Unreadable { location });
+
+        return (None, vec![]);
+    }
+
+    let header = match GptHeader::from_bytes(&sector) {
+        Ok(header) if header.has_valid_signature() => header,
+        _otherwise => {
+            anomalies.push(GptAnomaly::BadSignature { location });
+
+            return (None, vec![]);
+        }
+    };
+
+    let header_size = header.header_size() as usize;
+    if header_size < HEADER_SIZE || header_size > sector.len() {
+        anomalies.push(GptAnomaly::BadHeaderSize {
+            location,
+            size: header.header_size(),
+        });
+
+        return (None, vec![]);
+    }
+
+    // The CRC covers `header_size` bytes, with the CRC field zeroed.
+    sector[16..20].fill(0);
+    let computed = crc32(&sector[..header_size]);
+    if computed != header.header_crc32() {
+        anomalies.push(GptAnomaly::HeaderCrcMismatch {
+            location,
+            stored: header.header_crc32(),
+            computed,
+        });
+    }
+
+    if header.revision() >> 16 != REVISION >> 16 {
+        anomalies.push(GptAnomaly::UnsupportedRevision {
+            location,
+            revision: header.revision(),
+        });
+    }
+
+    if header.my_lba() != lba {
+        anomalies.push(GptAnomaly::WrongMyLba {
+            location,
+            expected: lba,
+            found: header.my_lba(),
+        });
+    }
+
+    if location == GptLocation::Backup && header.alternate_lba() != 1 {
+        anomalies.push(GptAnomaly::WrongAlternateLba {
+            location,
+            expected: 1,
+            found: header.alternate_lba(),
+        });
+    }
+
+    let entries_size = header.entries_size();
+    let entries_sectors = (entries_size + sector_size - 1) / sector_size;
+    let entries_end = header.partition_entry_lba().saturating_add(entries_sectors);
+    let is_array_valid = header.entry_size() as usize >= ENTRY_SIZE
+        && header.entry_size().is_power_of_two()
+        && entries_size <= MAX_ENTRIES_SIZE
+        && header.partition_entry_lba() > 0
+        && entries_end <= device_size;
+
+    if !is_array_valid {
+        anomalies.push(GptAnomaly::BadEntryArray {
+            location,
+            count: header.number_of_entries(),
+            size: header.entry_size(),
+        });
+    }
+
+    let first = header.first_usable_lba();
+    let last = header.last_usable_lba();
+    let overlaps = |start: u64, end: u64| start <= last && first < end;
+    if first > last
+        || last >= device_size
+        || overlaps(header.my_lba(), header.my_lba().saturating_add(1))
+        || (is_array_valid && overlaps(header.partition_entry_lba(), entries_end))
+    {
+        anomalies.push(GptAnomaly::BadUsableRange {
+            location,
+            first,
+            last,
+        });
+    }
+
+    if !is_array_valid {
+        return (Some(header), vec![]);
+    }
+
+    let mut array = vec![0u8; entries_size as usize];
+    let offset = header.partition_entry_lba() * sector_size;
+    if file.read_exact_at(&mut array, offset).is_err() {
+        anomalies.push(GptAnomaly::Unreadable { location });
+
+        return (Some(header), vec![]);
+    }
+
+    let computed = crc32(&array);
+    if computed != header.entries_crc32() {
+        anomalies.push(GptAnomaly::EntriesCrcMismatch {
+            location,
+            stored: header.entries_crc32(),
+            computed,
+        });
+    }
+
+    let entries = array
+        .chunks_exact(header.entry_size() as usize)
+        .filter_map(|bytes| GptEntry::from_bytes(bytes).ok())
+        .collect();
+
+    (Some(header), entries)
+}
+
+/// Reads and verifies both copies of the GUID Partition Table on `file`, a device with
+/// `sector_size`-byte logical sectors.
+///
+/// The backup header is looked for on the device's last sector. If it is not there, and the
+/// primary header points to an earlier sector (e.g. after enlarging a disk image), it is read
+/// from there, and reported as [`GptAnomaly::BackupNotAtDeviceEnd`].
+pub fn verify(file: &File, sector_size: u64) -> Result<GptReport, GptError> {
+    check_sector_size(sector_size)?;
+
+    let device_size = file_size(file)? / sector_size;
+    let last_lba = device_size.saturating_sub(1);
+    log::debug!(
+        "gpt::verify device size: {:?} sectors of {:?} bytes",
+        device_size,
+        sector_size
+    );
+
+    let mut anomalies = vec![];
+
+    let (primary, primary_entries) = read_copy(
+        file,
+        GptLocation::Primary,
+        1,
+        device_size,
+        sector_size,
+        &mut anomalies,
+    );
+
+    let mut backup_anomalies = vec![];
+    let (mut backup, mut backup_entries) = read_copy(
+        file,
+        GptLocation::Backup,
+        last_lba,
+        device_size,
+        sector_size,
+        &mut backup_anomalies,
+    );
+
+    let alternate = primary.as_ref().map(|header| header.alternate_lba());
+    match alternate {
+        Some(alternate) if backup.is_none() && alternate > 1 && alternate < last_lba => {
+            let mut relocated_anomalies = vec![];
+            let (relocated, relocated_entries) = read_copy(
+                file,
+                GptLocation::Backup,
+                alternate,
+                device_size,
+                sector_size,
+                &mut relocated_anomalies,
+            );
+
+            if relocated.is_some() {
+                backup = relocated;
+                backup_entries = relocated_entries;
+                backup_anomalies = relocated_anomalies;
+                backup_anomalies.push(GptAnomaly::BackupNotAtDeviceEnd {
+                    expected: last_lba,
+                    found: alternate,
+                });
+            }
+        }
+        Some(alternate) if backup.is_some() && alternate != last_lba => {
+            anomalies.push(GptAnomaly::WrongAlternateLba {
+                location: GptLocation::Primary,
+                expected: last_lba,
+                found: alternate,
+            });
+        }
+        Some(alternate) if alternate > last_lba => {
+            anomalies.push(GptAnomaly::WrongAlternateLba {
+                location: GptLocation::Primary,
+                expected: last_lba,
+                found: alternate,
+            });
+        }
+        _otherwise => {}
+    }
+
+    anomalies.append(&mut backup_anomalies);
+
+    if let (Some(p), Some(b)) = (&primary, &backup) {
+        let fields = [
+            ("disk GUID", p.disk_guid_bytes() == b.disk_guid_bytes()),
+            (
+                "first usable LBA",
+                p.first_usable_lba() == b.first_usable_lba(),
+            ),
+            (
+                "last usable LBA",
+                p.last_usable_lba() == b.last_usable_lba(),
+            ),
+            (
+                "number of entries",
+                p.number_of_entries() == b.number_of_entries(),
+            ),
+            ("entry size", p.entry_size() == b.entry_size()),
+            ("partition entries", p.entries_crc32() == b.entries_crc32()),
+        ];
+
+        for (field, _) in fields.iter().filter(|(_, agree)| !agree) {
+            anomalies.push(GptAnomaly::HeadersDisagree { field });
+        }
+    }
+
+    log::debug!("gpt::verify anomalies: {:?}", anomalies);
+
+    let report = GptReport {
+        device_size,
+        sector_size,
+        primary,
+        primary_entries,
+        backup,
+        backup_entries,
+        anomalies,
+    };
+
+    Ok(report)
+}
+
+#[doc(hidden)]
+/// Helper function: adds to `plan` a write of `data` on sector `lba`, unless the device
+/// already holds the same bytes there.
+fn plan_write(
+    file: &File,
+    plan: &mut GptRepairPlan,
+    action: GptRepairAction,
+    lba: u64,
+    data: Vec<u8>,
+) {
+    let offset = lba * plan.sector_size;
+    let mut current = vec![0u8; data.len()];
+    let is_up_to_date = file.read_exact_at(&mut current, offset).is_ok() && current == data;
+
+    if !is_up_to_date {
+        plan.actions.push(action);
+        plan.writes.push((offset, data));
+    }
+}
+
+/// Plans the repair of the GUID Partition Table on `file`, a device with `sector_size`-byte
+/// logical sectors, by rebuilding both copies from the `source` copy.
+///
+/// The rebuilt copies have the same disk GUID, usable range, and partition entries as `source`,
+/// with recomputed CRC32s. If the backup header is not on the device's last sector (e.g. after
+/// enlarging a disk image), it is moved there, the last usable sector is moved up to the new
+/// backup partition entry array, and the stale backup header is erased.
+///
+/// The returned [`GptRepairPlan`] only describes the writes to perform; call [`apply_repair`]
+/// to carry them out.
+///
+/// # Errors
+///
+/// Returns [`GptError::Config`] if the `source` copy is missing or damaged, or if a rebuilt
+/// copy would overlap a partition.
+pub fn plan_repair(
+    file: &File,
+    sector_size: u64,
+    source: GptLocation,
+) -> Result<GptRepairPlan, GptError> {
+    log::debug!("gpt::plan_repair planning GPT repair from {} copy", source);
+
+    let report = verify(file, sector_size)?;
+    let header = match report.header(source) {
+        Some(header) if report.is_valid(source) => header,
+        _otherwise => {
+            let err_msg = format!(
+                "can not repair GPT from its {} copy: it is missing or damaged",
+                source
+            );
+
+            return Err(GptError::Config(err_msg));
+        }
+    };
+
+    let device_size = report.device_size();
+    let last_lba = device_size - 1;
+    let entries_size = header.entries_size();
+    let entries_sectors = (entries_size + sector_size - 1) / sector_size;
+
+    let mut entries = vec![0u8; entries_size as usize];
+    file.read_exact_at(&mut entries, header.partition_entry_lba() * sector_size)?;
+
+    let backup_lba = match source {
+        GptLocation::Primary => header.alternate_lba(),
+        GptLocation::Backup => header.my_lba(),
+    };
+    let is_relocated = backup_lba != last_lba;
+
+    let primary_entry_lba = match source {
+        GptLocation::Primary => header.partition_entry_lba(),
+        GptLocation::Backup => 2,
+    };
+    let backup_entry_lba = match source {
+        GptLocation::Backup if !is_relocated => header.partition_entry_lba(),
+        _otherwise => last_lba.saturating_sub(entries_sectors),
+    };
+    let last_usable_lba = if is_relocated {
+        backup_entry_lba.saturating_sub(1)
+    } else {
+        header.last_usable_lba()
+    };
+    let first_usable_lba = header.first_usable_lba();
+
+    if first_usable_lba < primary_entry_lba + entries_sectors
+        || last_usable_lba >= backup_entry_lba
+        || first_usable_lba > last_usable_lba
+    {
+        let err_msg = format!(
+            "can not repair GPT: usable sectors {}-{} overlap a rebuilt partition entry array",
+            first_usable_lba, last_usable_lba
+        );
+
+        return Err(GptError::Config(err_msg));
+    }
+
+    let outside = report.entries(source).iter().position(|e| {
+        !e.is_empty() && (e.first_lba() < first_usable_lba || e.last_lba() > last_usable_lba)
+    });
+
+    if let Some(index) = outside {
+        let err_msg = format!(
+            "can not repair GPT: partition {} lies outside usable sectors {}-{}",
+            index + 1,
+            first_usable_lba,
+            last_usable_lba
+        );
+
+        return Err(GptError::Config(err_msg));
+    }
+
+    let primary = header.relocate(1, last_lba, primary_entry_lba, last_usable_lba);
+    let backup = header.relocate(last_lba, 1, backup_entry_lba, last_usable_lba);
+
+    let mut plan = GptRepairPlan {
+        source,
+        device_size,
+        sector_size,
+        primary,
+        backup,
+        actions: vec![],
+        writes: vec![],
+    };
+
+    // Rebuild the damaged copy first, so that the device always holds one valid copy.
+    let locations = match source {
+        GptLocation::Primary => [GptLocation::Backup, GptLocation::Primary],
+        GptLocation::Backup => [GptLocation::Primary, GptLocation::Backup],
+    };
+
+    for location in locations {
+        let target = plan.header(location).clone();
+
+        let action = GptRepairAction::WriteEntries {
+            location,
+            lba: target.partition_entry_lba(),
+            sectors: entries_sectors,
+        };
+        let mut data = entries.clone();
+        data.resize((entries_sectors * sector_size) as usize, 0);
+        plan_write(file, &mut plan, action, target.partition_entry_lba(), data);
+
+        let action = GptRepairAction::WriteHeader {
+            location,
+            lba: target.my_lba(),
+        };
+        let mut data = target.to_bytes().to_vec();
+        data.resize(sector_size as usize, 0);
+        plan_write(file, &mut plan, action, target.my_lba(), data);
+    }
+
+    // Erase the stale backup header left behind by a relocation.
+    if is_relocated && backup_lba > 1 && backup_lba < last_lba {
+        let mut sector = vec![0u8; sector_size as usize];
+        let is_header = file
+            .read_exact_at(&mut sector, backup_lba * sector_size)
+            .is_ok()
+            && sector.starts_with(SIGNATURE);
+
+        if is_header {
+            let action = GptRepairAction::EraseHeader { lba: backup_lba };
+            plan_write(
+                file,
+                &mut plan,
+                action,
+                backup_lba,
+                vec![0u8; sector_size as usize],
+            );
+        }
+    }
+
+    log::debug!("gpt::plan_repair planned actions: {:?}", plan.actions);
+
+    Ok(plan)
+}
+
+/// Performs the writes described by `plan` on `file`, then flushes them to the device.
+///
+/// # Errors
+///
+/// Returns [`GptError::Config`] if the device's size changed since `plan` was made.
+pub fn apply_repair(file: &File, plan: &GptRepairPlan) -> Result<(), GptError> {
+    log::debug!("gpt::apply_repair applying {} actions", plan.actions.len());
+
+    let device_size = file_size(file)? / plan.sector_size;
+    if device_size != plan.device_size {
+        let err_msg = format!(
+            "can not repair GPT: device size changed from {} to {} sectors since the repair was planned",
+            plan.device_size, device_size
+        );
+
+        return Err(GptError::Config(err_msg));
+    }
+
+    for (action, (offset, data)) in plan.actions.iter().zip(plan.writes.iter()) {
+        log::debug!("gpt::apply_repair {}", action);
+        file.write_all_at(data, *offset)?;
+    }
+
+    file.sync_data()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::core::partition::Severity;
+
+    const SECTOR: u64 = 512;
+    const LINUX_DATA: &str = "0fc63daf-8483-4772-8e79-3d69d8477de4";
+
+    /// Returns a GPT header sector, with valid CRCs.
+    fn header_sector(
+        my_lba: u64,
+        alternate_lba: u64,
+        entry_lba: u64,
+        last_usable: u64,
+        entries: &[u8],
+    ) -> Vec<u8> {
+        let mut sector = vec![0u8; SECTOR as usize];
+        sector[0..8].copy_from_slice(SIGNATURE);
+        sector[8..12].copy_from_slice(&REVISION.to_le_bytes());
+        sector[12..16].copy_from_slice(&(HEADER_SIZE as u32).to_le_bytes());
+        sector[24..32].copy_from_slice(&my_lba.to_le_bytes());
+        sector[32..40].copy_from_slice(&alternate_lba.to_le_bytes());
+        sector[40..48].copy_from_slice(&34u64.to_le_bytes());
+        sector[48..56].copy_from_slice(&last_usable.to_le_bytes());
+        sector[56..72]
+            .copy_from_slice(&guid_from_str("8e2a2a8d-ba8c-4e5c-9b4f-5b3e0c0d3a11").unwrap());
+        sector[72..80].copy_from_slice(&entry_lba.to_le_bytes());
+        sector[80..84].copy_from_slice(&128u32.to_le_bytes());
+        sector[84..88].copy_from_slice(&(ENTRY_SIZE as u32).to_le_bytes());
+        sector[88..92].copy_from_slice(&crc32(entries).to_le_bytes());
+        let crc = crc32(&sector[..HEADER_SIZE]);
+        sector[16..20].copy_from_slice(&crc.to_le_bytes());
+
+        sector
+    }
+
+    /// Returns an array of 128 partition entries, with a single Linux data partition.
+    fn entry_array(last_usable: u64) -> Vec<u8> {
+        let mut entries = vec![0u8; 128 * ENTRY_SIZE];
+        entries[0..16].copy_from_slice(&guid_from_str(LINUX_DATA).unwrap());
+        entries[16..32]
+            .copy_from_slice(&guid_from_str("3b8f8425-20e0-4f3b-907f-1a25a76f98e8").unwrap());
+        entries[32..40].copy_from_slice(&2048u64.to_le_bytes());
+        entries[40..48].copy_from_slice(&last_usable.to_le_bytes());
+        for (i, c) in "data".encode_utf16().enumerate() {
+            entries[56 + 2 * i..58 + 2 * i].copy_from_slice(&c.to_le_bytes());
+        }
+
+        entries
+    }
+
+    /// Creates a disk image of `size` sectors, with a GPT whose backup header is on its last sector.
+    fn gpt_image(size: u64) -> File {
+        let last = size - 1;
+        let last_usable = last - 33;
+        let entries = entry_array(last_usable);
+
+        let file = tempfile::tempfile().unwrap();
+        file.set_len(size * SECTOR).unwrap();
+        file.write_all_at(&header_sector(1, last, 2, last_usable, &entries), SECTOR)
+            .unwrap();
+        file.write_all_at(&entries, 2 * SECTOR).unwrap();
+        file.write_all_at(&entries, (last - 32) * SECTOR).unwrap();
+        file.write_all_at(
+            &header_sector(last, 1, last - 32, last_usable, &entries),
+            last * SECTOR,
+        )
+        .unwrap();
+
+        file
+    }
+
+    #[test]
+    fn crc32_computes_the_ieee_checksum() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+
+    #[test]
+    fn guid_from_str_and_guid_to_string_round_trip() {
+        let bytes = guid_from_str(LINUX_DATA).unwrap();
+        assert_eq!(&bytes[..4], &[0xaf, 0x3d, 0xc6, 0x0f]);
+        assert_eq!(guid_to_string(&bytes), LINUX_DATA);
+
+        let actual =
+            guid_from_str("0FC63DAF-8483-4772-8E79-3D69D8477DE4").map(|b| guid_to_string(&b));
+        assert_eq!(actual.as_deref(), Some(LINUX_DATA));
+
+        assert_eq!(guid_from_str("0fc63daf84834772-8e79-3d69d8477de4-"), None);
+        assert_eq!(guid_from_str("0fc63daf-8483-4772-8e79-3d69d8477dez"), None);
+    }
+
+    #[test]
+    fn random_guid_generates_a_version_4_guid() {
+        let guid = guid_to_string(&random_guid().unwrap());
+
+        assert_eq!(&guid[14..15], "4");
+        assert!(matches!(&guid[19..20], "8" | "9" | "a" | "b"));
+        assert_ne!(guid_to_string(&random_guid().unwrap()), guid);
+    }
+
+    #[test]
+    fn verify_rejects_an_invalid_sector_size() {
+        let file = tempfile::tempfile().unwrap();
+
+        assert!(matches!(verify(&file, 0), Err(GptError::Config(_))));
+        assert!(matches!(verify(&file, 1000), Err(GptError::Config(_))));
+    }
+
+    #[test]
+    fn verify_accepts_a_valid_gpt() {
+        let file = gpt_image(4096);
+        let report = verify(&file, SECTOR).unwrap();
+
+        assert!(report.anomalies().is_empty());
+        assert!(report.is_valid(GptLocation::Primary));
+        assert!(report.is_valid(GptLocation::Backup));
+        assert!(report.is_consistent());
+        assert_eq!(report.device_size(), 4096);
+
+        let primary = report.header(GptLocation::Primary).unwrap();
+        assert_eq!(primary.alternate_lba(), 4095);
+        assert_eq!(primary.last_usable_lba(), 4062);
+
+        let entries = report.entries(GptLocation::Backup);
+        assert_eq!(entries.len(), 128);
+        assert_eq!(entries[0].type_guid_string(), LINUX_DATA);
+        assert_eq!(entries[0].first_lba(), 2048);
+        assert_eq!(entries[0].name(), "data");
+        assert!(entries[1].is_empty());
+    }
+
+    #[test]
+    fn verify_detects_a_corrupted_primary_header() {
+        let file = gpt_image(4096);
+        // Change the primary header's first usable LBA without updating its CRC.
+        file.write_all_at(&[35], SECTOR + 40).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+
+        assert!(!report.is_valid(GptLocation::Primary));
+        assert!(report.is_valid(GptLocation::Backup));
+        assert!(matches!(
+            report.anomalies()[0],
+            GptAnomaly::HeaderCrcMismatch {
+                location: GptLocation::Primary,
+                ..
+            }
+        ));
+        assert!(report.anomalies().contains(&GptAnomaly::HeadersDisagree {
+            field: "first usable LBA"
+        }));
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+    }
+
+    #[test]
+    fn verify_detects_corrupted_primary_entries() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[0xff], 2 * SECTOR + 32).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+
+        assert!(!report.is_valid(GptLocation::Primary));
+        assert!(report.is_valid(GptLocation::Backup));
+        assert!(matches!(
+            report.anomalies()[0],
+            GptAnomaly::EntriesCrcMismatch {
+                location: GptLocation::Primary,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn verify_finds_a_backup_gpt_before_the_end_of_an_enlarged_image() {
+        let file = gpt_image(4096);
+        file.set_len(8192 * SECTOR).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+
+        assert_eq!(
+            report.anomalies(),
+            &[GptAnomaly::BackupNotAtDeviceEnd {
+                expected: 8191,
+                found: 4095
+            }]
+        );
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+        assert!(report.is_valid(GptLocation::Primary));
+        assert!(report.is_valid(GptLocation::Backup));
+        assert_eq!(report.header(GptLocation::Backup).unwrap().my_lba(), 4095);
+    }
+
+    #[test]
+    fn verify_detects_a_missing_backup_gpt() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[0u8; SECTOR as usize], 4095 * SECTOR)
+            .unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+
+        assert_eq!(
+            report.anomalies(),
+            &[GptAnomaly::BadSignature {
+                location: GptLocation::Backup
+            }]
+        );
+        assert!(report.is_valid(GptLocation::Primary));
+        assert!(!report.is_valid(GptLocation::Backup));
+        assert!(report.header(GptLocation::Backup).is_none());
+    }
+
+    #[test]
+    fn plan_repair_does_nothing_on_a_valid_gpt() {
+        let file = gpt_image(4096);
+        let plan = plan_repair(&file, SECTOR, GptLocation::Primary).unwrap();
+
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn plan_repair_refuses_a_damaged_source() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[35], SECTOR + 40).unwrap();
+
+        let actual = plan_repair(&file, SECTOR, GptLocation::Primary);
+
+        assert!(matches!(actual, Err(GptError::Config(_))));
+    }
+
+    #[test]
+    fn repair_rebuilds_the_primary_gpt_from_its_backup() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[35], SECTOR + 40).unwrap();
+        file.write_all_at(&[0xff], 2 * SECTOR + 32).unwrap();
+
+        let plan = plan_repair(&file, SECTOR, GptLocation::Backup).unwrap();
+        assert_eq!(
+            plan.actions(),
+            &[
+                GptRepairAction::WriteEntries {
+                    location: GptLocation::Primary,
+                    lba: 2,
+                    sectors: 32
+                },
+                GptRepairAction::WriteHeader {
+                    location: GptLocation::Primary,
+                    lba: 1
+                },
+            ]
+        );
+
+        // Planning is a dry run.
+        assert!(!verify(&file, SECTOR).unwrap().is_consistent());
+
+        apply_repair(&file, &plan).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+        assert_eq!(
+            report
+                .header(GptLocation::Primary)
+                .unwrap()
+                .first_usable_lba(),
+            34
+        );
+    }
+
+    #[test]
+    fn repair_rebuilds_the_backup_gpt_from_the_primary() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[0u8; SECTOR as usize], 4095 * SECTOR)
+            .unwrap();
+
+        let plan = plan_repair(&file, SECTOR, GptLocation::Primary).unwrap();
+        assert_eq!(
+            plan.actions(),
+            &[GptRepairAction::WriteHeader {
+                location: GptLocation::Backup,
+                lba: 4095
+            }]
+        );
+
+        apply_repair(&file, &plan).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+    }
+
+    #[test]
+    fn repair_moves_the_backup_gpt_to_the_end_of_an_enlarged_image() {
+        let file = gpt_image(4096);
+        file.set_len(8192 * SECTOR).unwrap();
+
+        let plan = plan_repair(&file, SECTOR, GptLocation::Primary).unwrap();
+        assert_eq!(
+            plan.actions(),
+            &[
+                GptRepairAction::WriteEntries {
+                    location: GptLocation::Backup,
+                    lba: 8159,
+                    sectors: 32
+                },
+                GptRepairAction::WriteHeader {
+                    location: GptLocation::Backup,
+                    lba: 8191
+                },
+                GptRepairAction::WriteHeader {
+                    location: GptLocation::Primary,
+                    lba: 1
+                },
+                GptRepairAction::EraseHeader { lba: 4095 },
+            ]
+        );
+        assert_eq!(plan.header(GptLocation::Primary).last_usable_lba(), 8158);
+
+        apply_repair(&file, &plan).unwrap();
+
+        let report = verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+        assert_eq!(
+            report
+                .header(GptLocation::Backup)
+                .unwrap()
+                .last_usable_lba(),
+            8158
+        );
+        assert_eq!(report.entries(GptLocation::Backup)[0].first_lba(), 2048);
+    }
+
+    #[test]
+    fn apply_repair_refuses_a_resized_device() {
+        let file = gpt_image(4096);
+        file.write_all_at(&[0u8; SECTOR as usize], 4095 * SECTOR)
+            .unwrap();
+
+        let plan = plan_repair(&file, SECTOR, GptLocation::Primary).unwrap();
+        file.set_len(8192 * SECTOR).unwrap();
+
+        let actual = apply_repair(&file, &plan);
+
+        assert!(matches!(actual, Err(GptError::Config(_))));
+    }
+}
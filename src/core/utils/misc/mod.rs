@@ -11,6 +11,7 @@
 use std::path::Path;
 
 // From this library
+use crate::core::errors::DeviceError;
 use crate::core::errors::MiscError;
 use crate::ffi_utils;
 
@@ -50,10 +51,12 @@ pub fn send_uevent<T>(device_path: T, action: UEventAction) -> Result<(), MiscEr
         device_path.as_ref()
     );
 
-    let absolute_dev_path = fs::canonicalize(&device_path)?;
+    let absolute_dev_path = fs::canonicalize(&device_path)
+        .map_err(|e| DeviceError::from_io(device_path.as_ref(), e))?;
     let dev_path_cstr = ffi_utils::as_ref_path_to_c_string(absolute_dev_path)?;
     let action_cstr = action.to_c_string();
 
+    ffi_utils::clear_errno();
     let result =
         unsafe { libblkid::blkid_send_uevent(dev_path_cstr.as_ptr(), action_cstr.as_ptr()) };
 
@@ -68,13 +71,10 @@ pub fn send_uevent<T>(device_path: T, action: UEventAction) -> Result<(), MiscEr
             Ok(())
         }
         code => {
+            let err = DeviceError::libblkid_failure("blkid_send_uevent", code);
             log::debug!("misc::send_uevent failed to send ACTION={:?} to {:?}. libblkid::blkid_send_uevent returned error code {:?}", action, device_path.as_ref(), code);
 
-            Err(MiscError::SendUEvent(format!(
-                "error sending udev event {:?} to {:?}",
-                action,
-                device_path.as_ref()
-            )))
+            Err(MiscError::from(err))
         }
     }
 }
@@ -8,6 +8,10 @@
 // From standard library
 
 // From this library
+pub mod dev_disk;
 pub mod encode;
 pub mod evaluation;
+pub mod format;
+pub mod gpt;
 pub mod misc;
+pub mod table_writer;
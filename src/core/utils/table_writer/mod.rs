@@ -0,0 +1,58 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Create, and edit, GPT and DOS partition tables, independently of `libblkid`.
+//!
+//! A [`PartitionTableWriter`] holds an in-memory copy of a partition table, either empty or
+//! loaded from a device, on which you add, update, or delete [`PartitionEntry`] items before
+//! writing the result back to the device. When writing a GUID Partition Table, the writer also
+//! writes a protective MBR, and the backup GPT at the end of the device.
+//!
+//! **Note:** the kernel is not notified of the new partition table; run `partprobe`, or
+//! `blockdev --rereadpt`, after writing it to a block device.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use rsblkid::core::device::Label;
+//! use rsblkid::core::partition::{Guid, PartitionTableType, PartitionType};
+//! use rsblkid::core::utils::table_writer::PartitionEntry;
+//! use rsblkid::probe::Probe;
+//!
+//! fn main() -> rsblkid::Result<()> {
+//!     let mut probe = Probe::builder()
+//!         .scan_device("/dev/vda")
+//!         // Open device in read/write mode.
+//!         .allow_writes()
+//!         .build()?;
+//!
+//!     // Partitions are aligned on the device's topology.
+//!     let mut table = probe.new_partition_table(PartitionTableType::GPT)?;
+//!
+//!     // 512 MiB EFI System partition.
+//!     let esp = PartitionEntry::new(PartitionType::GPT(Guid::EfiSystem))
+//!         .with_size(1024 * 1024)
+//!         .with_name("esp".parse::<Label>()?);
+//!     table.add_partition(esp)?;
+//!
+//!     // Linux root partition, on the rest of the device.
+//!     let root = PartitionEntry::new(PartitionType::GPT(Guid::LinuxData))
+//!         .with_name("root".parse::<Label>()?);
+//!     table.add_partition(root)?;
+//!
+//!     probe.write_partition_table(&table)?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+// From dependency library
+
+// From standard library
+
+// From this library
+pub use partition_entry_struct::PartitionEntry;
+pub use partition_table_writer_struct::PartitionTableWriter;
+
+mod partition_entry_struct;
+mod partition_table_writer_struct;
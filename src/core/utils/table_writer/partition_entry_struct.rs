@@ -0,0 +1,155 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Label;
+use crate::core::device::Uuid;
+use crate::core::partition::PartitionBitflags;
+use crate::core::partition::PartitionType;
+
+/// A partition in a table edited by a
+/// [`PartitionTableWriter`](crate::core::utils::table_writer::PartitionTableWriter).
+///
+/// Locations and sizes are expressed in logical sectors. Optional values left unset when the
+/// entry is added to a table are filled in by the writer: the first free partition number, the
+/// first aligned free sector, the rest of the free space after the partition's start, and, for
+/// GPT, a random unique GUID.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsblkid::core::device::Label;
+/// use rsblkid::core::partition::{Guid, PartitionType};
+/// use rsblkid::core::utils::table_writer::PartitionEntry;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let name: Label = "root".parse()?;
+///     let entry = PartitionEntry::new(PartitionType::GPT(Guid::LinuxData))
+///         .with_size(2048)
+///         .with_name(name);
+///
+///     assert_eq!(entry.start(), None);
+///     assert_eq!(entry.size(), Some(2048));
+///     assert_eq!(entry.name().map(|name| name.as_str()), Some("root"));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartitionEntry {
+    partition_type: PartitionType,
+    number: Option<usize>,
+    start: Option<u64>,
+    size: Option<u64>,
+    uuid: Option<Uuid>,
+    name: Option<Label>,
+    flags: Option<PartitionBitflags>,
+}
+
+impl PartitionEntry {
+    /// Creates a new `PartitionEntry` of type `partition_type`:
+    /// - [`PartitionType::GPT`] for a GUID Partition Table,
+    /// - [`PartitionType::MBR`] for a DOS partition table.
+    pub fn new(partition_type: PartitionType) -> PartitionEntry {
+        Self {
+            partition_type,
+            number: None,
+            start: None,
+            size: None,
+            uuid: None,
+            name: None,
+            flags: None,
+        }
+    }
+
+    /// Sets the partition type.
+    pub fn with_partition_type(mut self, partition_type: PartitionType) -> PartitionEntry {
+        self.partition_type = partition_type;
+        self
+    }
+
+    /// Sets the partition number (i.e. the entry's slot in the table, starting at `1`).
+    pub fn with_number(mut self, number: usize) -> PartitionEntry {
+        self.number = Some(number);
+        self
+    }
+
+    /// Sets the partition's first sector.
+    pub fn with_start(mut self, start: u64) -> PartitionEntry {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the partition's size in sectors.
+    pub fn with_size(mut self, size: u64) -> PartitionEntry {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the partition's unique GUID (GPT only).
+    pub fn with_uuid(mut self, uuid: Uuid) -> PartitionEntry {
+        self.uuid = Some(uuid);
+        self
+    }
+
+    /// Sets the partition's name (GPT only, at most 36 UTF-16 code units).
+    pub fn with_name(mut self, name: Label) -> PartitionEntry {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the partition's flags: the attributes of a GPT entry, or the boot indicator of a
+    /// DOS entry (`0x80` for a bootable partition).
+    pub fn with_flags(mut self, flags: PartitionBitflags) -> PartitionEntry {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Returns the partition type.
+    pub fn partition_type(&self) -> &PartitionType {
+        &self.partition_type
+    }
+
+    /// Returns the partition number, if set.
+    pub fn number(&self) -> Option<usize> {
+        self.number
+    }
+
+    /// Returns the partition's first sector, if set.
+    pub fn start(&self) -> Option<u64> {
+        self.start
+    }
+
+    /// Returns the partition's size in sectors, if set.
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Returns the partition's last sector, if its location and size are set.
+    pub fn end(&self) -> Option<u64> {
+        match (self.start, self.size) {
+            (Some(start), Some(size)) if size > 0 => Some(start + size - 1),
+            _otherwise => None,
+        }
+    }
+
+    /// Returns the partition's unique GUID, if set.
+    pub fn uuid(&self) -> Option<&Uuid> {
+        self.uuid.as_ref()
+    }
+
+    /// Returns the partition's name, if set.
+    pub fn name(&self) -> Option<&Label> {
+        self.name.as_ref()
+    }
+
+    /// Returns the partition's flags, if set.
+    pub fn flags(&self) -> Option<&PartitionBitflags> {
+        self.flags.as_ref()
+    }
+}
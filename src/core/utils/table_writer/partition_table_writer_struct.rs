@@ -0,0 +1,1159 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fs::File;
+use std::os::unix::fs::FileExt;
+
+// From this library
+use crate::core::device::Label;
+use crate::core::device::Uuid;
+use crate::core::errors::TableWriterError;
+use crate::core::partition::Guid;
+use crate::core::partition::MbrEntry;
+use crate::core::partition::OSType;
+use crate::core::partition::PartitionBitflags;
+use crate::core::partition::PartitionTableType;
+use crate::core::partition::PartitionType;
+use crate::core::utils::gpt;
+use crate::core::utils::gpt::GptEntry;
+use crate::core::utils::gpt::GptHeader;
+use crate::core::utils::gpt::GptLocation;
+use crate::core::utils::table_writer::PartitionEntry;
+
+/// Default alignment of partitions, in bytes (same as `fdisk`).
+const DEFAULT_GRAIN: u64 = 1024 * 1024;
+
+/// Number of entries in a GPT partition entry array.
+const GPT_ENTRIES: usize = 128;
+
+/// Number of entries in a DOS partition table.
+const DOS_ENTRIES: usize = 4;
+
+/// Size in bytes of the boot code, disk signature, and reserved bytes preceding the partition
+/// entries in a Master Boot Record.
+const MBR_BOOT_CODE_SIZE: usize = 446;
+
+/// In-memory model of a GPT, or DOS, partition table to create, or edit, then write to a device.
+///
+/// New partitions are aligned on 1 MiB boundaries by default, or on the boundaries set by
+/// [`PartitionTableWriter::set_alignment`]. Locations and sizes are expressed in logical sectors.
+///
+/// When writing a GPT, the writer also writes a protective MBR, and the backup GPT at the end of
+/// the device. DOS partition tables are limited to four primary partitions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartitionTableWriter {
+    table_type: PartitionTableType,
+    device_size: u64,
+    sector_size: u64,
+    grain: u64,
+    alignment_offset: u64,
+    /// Number of entries in a GPT partition entry array.
+    gpt_entries: usize,
+    /// First and last usable sectors read from the header of a loaded GPT.
+    usable_range: Option<(u64, u64)>,
+    /// GPT disk GUID in its on-disk byte order, or DOS disk signature in the first 4 bytes.
+    disk_id: [u8; 16],
+    boot_code: Vec<u8>,
+    partitions: Vec<PartitionEntry>,
+}
+
+impl PartitionTableWriter {
+    /// Creates an empty partition table of type `table_type` (`GPT` or `DOS`) for a device of
+    /// `device_size` sectors of `sector_size` bytes, with a random disk identifier.
+    pub fn new(
+        table_type: PartitionTableType,
+        device_size: u64,
+        sector_size: u64,
+    ) -> Result<PartitionTableWriter, TableWriterError> {
+        log::debug!(
+            "PartitionTableWriter::new creating a {} partition table for {} sectors of {} bytes",
+            table_type,
+            device_size,
+            sector_size
+        );
+
+        if sector_size < 512 || !sector_size.is_power_of_two() {
+            let err_msg = format!("invalid sector size: {}", sector_size);
+
+            return Err(TableWriterError::Config(err_msg));
+        }
+
+        let mut disk_id = [0u8; 16];
+        match &table_type {
+            PartitionTableType::GPT => disk_id = gpt::random_guid()?,
+            PartitionTableType::DOS => disk_id[..4].copy_from_slice(&gpt::random_guid()?[..4]),
+            _otherwise => {
+                let err_msg = format!("unsupported partition table type: {}", table_type);
+
+                return Err(TableWriterError::Unsupported(err_msg));
+            }
+        }
+
+        let writer = Self {
+            table_type,
+            device_size,
+            sector_size,
+            grain: DEFAULT_GRAIN,
+            alignment_offset: 0,
+            gpt_entries: GPT_ENTRIES,
+            usable_range: None,
+            disk_id,
+            boot_code: vec![0u8; MBR_BOOT_CODE_SIZE],
+            partitions: vec![],
+        };
+
+        if writer.first_usable_lba() > writer.last_usable_lba() {
+            let err_msg = format!(
+                "device too small for a {} partition table: {} sectors",
+                writer.table_type, device_size
+            );
+
+            return Err(TableWriterError::Config(err_msg));
+        }
+
+        Ok(writer)
+    }
+
+    /// Loads the GPT, or DOS partition table, on `file`, a device with `sector_size`-byte
+    /// logical sectors.
+    ///
+    /// A GPT is loaded from its primary copy, or from its backup if the primary is damaged, with
+    /// the usable sectors, and number of partition entries, recorded in its header. Like
+    /// `libblkid`, a GPT is only trusted if the device's first sector holds a protective, or
+    /// hybrid, MBR (or no MBR at all); otherwise, the DOS partition table in the MBR is loaded,
+    /// and any leftover GPT ignored. The boot code in the device's first sector is preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableWriterError::Unsupported`] if the table has a partition of unknown type,
+    /// a DOS extended partition, or if a GPT comes with a hybrid MBR, whose entries
+    /// [`PartitionTableWriter::write`] would replace with a protective MBR.
+    pub fn from_device(
+        file: &File,
+        sector_size: u64,
+    ) -> Result<PartitionTableWriter, TableWriterError> {
+        log::debug!("PartitionTableWriter::from_device loading partition table");
+
+        let report = gpt::verify(file, sector_size)?;
+        let mut sector = vec![0u8; sector_size as usize];
+        file.read_exact_at(&mut sector, 0)?;
+        let mbr = MbrEntry::parse_table(&sector);
+        let has_protective_mbr = mbr
+            .as_ref()
+            .map(|entries| entries.iter().any(|e| e.is_protective()))
+            .unwrap_or(true);
+        let is_hybrid_mbr = mbr.as_ref().is_some_and(|entries| {
+            entries.iter().any(|e| e.is_protective())
+                && entries.iter().any(|e| !e.is_empty() && !e.is_protective())
+        });
+
+        // A GPT next to a DOS MBR is a leftover from a previous partitioning.
+        let location = [GptLocation::Primary, GptLocation::Backup]
+            .into_iter()
+            .find(|&location| has_protective_mbr && report.is_valid(location));
+
+        if location.is_some() && is_hybrid_mbr {
+            let err_msg =
+                "can not edit a GPT with a hybrid MBR: its hybrid entries would be lost".to_owned();
+
+            return Err(TableWriterError::Unsupported(err_msg));
+        }
+
+        let mut writer = match (location, mbr) {
+            (Some(location), _) => {
+                Self::load_gpt(&report, location, report.device_size(), sector_size)?
+            }
+            (None, Some(_)) if has_protective_mbr => {
+                let err_msg = "can not edit a damaged GPT: repair it before editing it".to_owned();
+
+                return Err(TableWriterError::Config(err_msg));
+            }
+            (None, Some(entries)) => {
+                Self::load_dos(&entries, &sector, report.device_size(), sector_size)?
+            }
+            (None, None) => {
+                let err_msg = "no GPT, or DOS partition table found".to_owned();
+
+                return Err(TableWriterError::Config(err_msg));
+            }
+        };
+
+        writer.boot_code = sector[..MBR_BOOT_CODE_SIZE].to_vec();
+
+        Ok(writer)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: creates a `PartitionTableWriter` from the `location` copy of a GPT.
+    fn load_gpt(
+        report: &gpt::GptReport,
+        location: GptLocation,
+        device_size: u64,
+        sector_size: u64,
+    ) -> Result<PartitionTableWriter, TableWriterError> {
+        let mut writer = Self::new(PartitionTableType::GPT, device_size, sector_size)?;
+        if let Some(header) = report.header(location) {
+            if header.entry_size() as usize != gpt::ENTRY_SIZE {
+                let err_msg = format!(
+                    "unsupported GPT partition entry size: {} bytes",
+                    header.entry_size()
+                );
+
+                return Err(TableWriterError::Unsupported(err_msg));
+            }
+
+            writer.disk_id = *header.disk_guid_bytes();
+            writer.gpt_entries = header.number_of_entries() as usize;
+            writer.usable_range = Some((header.first_usable_lba(), header.last_usable_lba()));
+
+            // The partition entry arrays are rewritten right after the primary header, and
+            // right before the backup header.
+            let reserved = 2 + writer.gpt_entries_sectors();
+            if writer.first_usable_lba() < reserved
+                || writer.last_usable_lba() > device_size.saturating_sub(reserved)
+                || writer.first_usable_lba() > writer.last_usable_lba()
+            {
+                let err_msg = format!(
+                    "unsupported GPT layout: usable sectors {}-{} overlap the partition entry arrays",
+                    header.first_usable_lba(),
+                    header.last_usable_lba()
+                );
+
+                return Err(TableWriterError::Unsupported(err_msg));
+            }
+        }
+
+        for (i, e) in report.entries(location).iter().enumerate() {
+            if e.is_empty() {
+                continue;
+            }
+
+            let guid = e.partition_type().ok_or_else(|| {
+                let err_msg = format!(
+                    "partition {}: unsupported partition type GUID: {}",
+                    i + 1,
+                    e.type_guid_string()
+                );
+
+                TableWriterError::Unsupported(err_msg)
+            })?;
+
+            let uuid: Uuid = e
+                .unique_guid_string()
+                .parse()
+                .map_err(|e| TableWriterError::Config(format!("partition {}: {}", i + 1, e)))?;
+
+            let mut entry = PartitionEntry::new(PartitionType::GPT(guid))
+                .with_number(i + 1)
+                .with_start(e.first_lba())
+                .with_size(e.size_in_sectors())
+                .with_uuid(uuid)
+                .with_flags(e.attributes());
+
+            let name = e.name();
+            if !name.is_empty() {
+                let name: Label = name
+                    .parse()
+                    .map_err(|e| TableWriterError::Config(format!("partition {}: {}", i + 1, e)))?;
+                entry = entry.with_name(name);
+            }
+
+            writer.add_partition(entry)?;
+        }
+
+        Ok(writer)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: creates a `PartitionTableWriter` from the entries of a DOS partition
+    /// table, read from the device's first `sector`.
+    fn load_dos(
+        entries: &[MbrEntry; 4],
+        sector: &[u8],
+        device_size: u64,
+        sector_size: u64,
+    ) -> Result<PartitionTableWriter, TableWriterError> {
+        let mut writer = Self::new(PartitionTableType::DOS, device_size, sector_size)?;
+        writer.disk_id = [0u8; 16];
+        writer.disk_id[..4].copy_from_slice(&sector[440..444]);
+
+        for (i, e) in entries.iter().enumerate() {
+            if e.is_empty() {
+                continue;
+            }
+
+            let os_type = e.os_type().ok_or_else(|| {
+                let err_msg = format!(
+                    "partition {}: unsupported partition type: {:#04x}",
+                    i + 1,
+                    e.os_type_id()
+                );
+
+                TableWriterError::Unsupported(err_msg)
+            })?;
+
+            let flags = if e.is_bootable() { 0x80 } else { 0x00 };
+            let entry = PartitionEntry::new(PartitionType::MBR(os_type))
+                .with_number(i + 1)
+                .with_start(e.start())
+                .with_size(e.size())
+                .with_flags(PartitionBitflags::from(flags));
+
+            writer.add_partition(entry)?;
+        }
+
+        Ok(writer)
+    }
+
+    /// Sets the boundaries new partitions are aligned on: multiples of `grain` bytes, shifted by
+    /// `alignment_offset` bytes (see [`Topology`](crate::probe::Topology)).
+    pub fn set_alignment(&mut self, grain: u64, alignment_offset: u64) {
+        log::debug!(
+            "PartitionTableWriter::set_alignment grain: {:?} alignment offset: {:?}",
+            grain,
+            alignment_offset
+        );
+
+        self.grain = grain.max(self.sector_size);
+        self.alignment_offset = alignment_offset;
+    }
+
+    /// Returns the partition table's type.
+    pub fn table_type(&self) -> &PartitionTableType {
+        &self.table_type
+    }
+
+    /// Returns the device's size in sectors.
+    pub fn device_size(&self) -> u64 {
+        self.device_size
+    }
+
+    /// Returns the size of a logical sector in bytes.
+    pub fn sector_size(&self) -> u64 {
+        self.sector_size
+    }
+
+    /// Returns the disk identifier: a GUID for a GPT, an 8-digit hexadecimal number for a DOS
+    /// partition table.
+    pub fn disk_id(&self) -> String {
+        match &self.table_type {
+            PartitionTableType::GPT => gpt::guid_to_string(&self.disk_id),
+            _otherwise => format!(
+                "{:08x}",
+                u32::from_le_bytes([
+                    self.disk_id[0],
+                    self.disk_id[1],
+                    self.disk_id[2],
+                    self.disk_id[3]
+                ])
+            ),
+        }
+    }
+
+    /// Returns the first sector partitions may use.
+    pub fn first_usable_lba(&self) -> u64 {
+        match (&self.table_type, self.usable_range) {
+            (PartitionTableType::GPT, Some((first, _))) => first,
+            (PartitionTableType::GPT, None) => 2 + self.gpt_entries_sectors(),
+            _otherwise => 1,
+        }
+    }
+
+    /// Returns the last sector partitions may use.
+    pub fn last_usable_lba(&self) -> u64 {
+        match (&self.table_type, self.usable_range) {
+            (PartitionTableType::GPT, Some((_, last))) => last,
+            (PartitionTableType::GPT, None) => self
+                .device_size
+                .saturating_sub(2 + self.gpt_entries_sectors()),
+            _otherwise => self.device_size.saturating_sub(1).min(u32::MAX as u64),
+        }
+    }
+
+    /// Returns the table's partitions, ordered by number.
+    pub fn partitions(&self) -> &[PartitionEntry] {
+        &self.partitions
+    }
+
+    /// Returns the partition numbered `number`.
+    pub fn partition(&self, number: usize) -> Option<&PartitionEntry> {
+        self.partitions.iter().find(|p| p.number() == Some(number))
+    }
+
+    /// Adds a partition to the table, and returns its number.
+    ///
+    /// Values left unset in `entry` are filled in: the first free partition number, the first
+    /// aligned free sector, the rest of the free space after the partition's start, and for a
+    /// GPT a random unique GUID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TableWriterError::Config`] if `entry`'s type does not match the table's, if its
+    /// number is already used, or if it does not fit in free space.
+    pub fn add_partition(&mut self, entry: PartitionEntry) -> Result<usize, TableWriterError> {
+        log::debug!("PartitionTableWriter::add_partition adding {:?}", entry);
+
+        let entry = self.place(entry, None)?;
+        let number = entry.number().unwrap_or_default();
+        self.insert(entry);
+
+        Ok(number)
+    }
+
+    /// Replaces the partition numbered `number` by `entry`, e.g. to change its type, name, or
+    /// size. Values left unset in `entry` are filled in as in
+    /// [`PartitionTableWriter::add_partition`].
+    pub fn update_partition(
+        &mut self,
+        number: usize,
+        entry: PartitionEntry,
+    ) -> Result<(), TableWriterError> {
+        log::debug!(
+            "PartitionTableWriter::update_partition updating partition {} with {:?}",
+            number,
+            entry
+        );
+
+        if self.partition(number).is_none() {
+            let err_msg = format!("partition {} does not exist", number);
+
+            return Err(TableWriterError::Config(err_msg));
+        }
+
+        let entry = match entry.number() {
+            Some(_) => entry,
+            None => entry.with_number(number),
+        };
+        let entry = self.place(entry, Some(number))?;
+
+        self.partitions.retain(|p| p.number() != Some(number));
+        self.insert(entry);
+
+        Ok(())
+    }
+
+    /// Removes the partition numbered `number` from the table, and returns it.
+    pub fn delete_partition(&mut self, number: usize) -> Result<PartitionEntry, TableWriterError> {
+        log::debug!(
+            "PartitionTableWriter::delete_partition deleting partition {}",
+            number
+        );
+
+        match self
+            .partitions
+            .iter()
+            .position(|p| p.number() == Some(number))
+        {
+            Some(index) => Ok(self.partitions.remove(index)),
+            None => {
+                let err_msg = format!("partition {} does not exist", number);
+
+                Err(TableWriterError::Config(err_msg))
+            }
+        }
+    }
+
+    /// Writes the partition table to `file`, then flushes it to the device.
+    ///
+    /// For a GPT, also writes a protective MBR, and the backup GPT. For a DOS partition table,
+    /// erases the headers of any GPT previously on the device.
+    pub fn write(&self, file: &File) -> Result<(), TableWriterError> {
+        log::debug!(
+            "PartitionTableWriter::write writing {} partition table",
+            self.table_type
+        );
+
+        let device_size = gpt::file_size(file)? / self.sector_size;
+        if device_size != self.device_size {
+            let err_msg = format!(
+                "device size changed from {} to {} sectors",
+                self.device_size, device_size
+            );
+
+            return Err(TableWriterError::Config(err_msg));
+        }
+
+        let mut writes = match &self.table_type {
+            PartitionTableType::GPT => self.gpt_sectors(),
+            _otherwise => vec![(0, self.mbr_sector())],
+        };
+
+        if self.table_type != PartitionTableType::GPT {
+            let last_lba = self.device_size - 1;
+            for lba in [1, last_lba] {
+                let mut sector = vec![0u8; self.sector_size as usize];
+                file.read_exact_at(&mut sector, lba * self.sector_size)?;
+
+                if sector.starts_with(gpt::SIGNATURE) {
+                    writes.push((lba, vec![0u8; self.sector_size as usize]));
+                }
+            }
+        }
+
+        for (lba, data) in writes.iter() {
+            log::debug!(
+                "PartitionTableWriter::write writing {} bytes on sector {}",
+                data.len(),
+                lba
+            );
+            file.write_all_at(data, lba * self.sector_size)?;
+        }
+
+        file.sync_data()?;
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the number of sectors occupied by a GPT partition entry array.
+    fn gpt_entries_sectors(&self) -> u64 {
+        let size = (self.gpt_entries * gpt::ENTRY_SIZE) as u64;
+
+        (size + self.sector_size - 1) / self.sector_size
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the first aligned sector at, or after, `lba`. Like `fdisk`,
+    /// keeps the device's first grain free for boot loaders.
+    fn align_up(&self, lba: u64) -> u64 {
+        let grain = (self.grain / self.sector_size).max(1);
+        let offset = (self.alignment_offset / self.sector_size) % grain;
+        let lba = lba.max(grain);
+
+        if lba <= offset {
+            offset
+        } else {
+            offset + (lba - offset + grain - 1) / grain * grain
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the free regions of the usable sectors (first and last sector,
+    /// inclusive), ignoring the partition numbered `ignore`.
+    fn free_regions(&self, ignore: Option<usize>) -> Vec<(u64, u64)> {
+        let mut used: Vec<_> = self
+            .partitions
+            .iter()
+            .filter(|p| p.number() != ignore)
+            .filter_map(|p| Some((p.start()?, p.end()?)))
+            .collect();
+        used.sort_unstable();
+
+        let mut regions = vec![];
+        let mut next = self.first_usable_lba();
+        for (start, end) in used {
+            if start > next {
+                regions.push((next, start - 1));
+            }
+            next = next.max(end + 1);
+        }
+
+        if next <= self.last_usable_lba() {
+            regions.push((next, self.last_usable_lba()));
+        }
+
+        regions
+    }
+
+    #[doc(hidden)]
+    /// Helper function: checks `entry`, and fills in its unset values, ignoring the partition
+    /// numbered `replacing`.
+    fn place(
+        &self,
+        entry: PartitionEntry,
+        replacing: Option<usize>,
+    ) -> Result<PartitionEntry, TableWriterError> {
+        let config = |err_msg: String| Err(TableWriterError::Config(err_msg));
+        let is_gpt = self.table_type == PartitionTableType::GPT;
+
+        // Partition type.
+        match entry.partition_type() {
+            PartitionType::GPT(guid) if is_gpt && *guid != Guid::EmptyPartition => {}
+            PartitionType::MBR(os_type) if !is_gpt && os_type.is_extended() => {
+                let err_msg = format!(
+                    "extended partitions are not supported: {}",
+                    entry.partition_type()
+                );
+
+                return Err(TableWriterError::Unsupported(err_msg));
+            }
+            PartitionType::MBR(os_type) if !is_gpt && *os_type != OSType::EmptyPartition => {}
+            partition_type => {
+                return config(format!(
+                    "invalid partition type in a {} partition table: {}",
+                    self.table_type, partition_type
+                ))
+            }
+        }
+
+        // Attributes specific to GPT.
+        if !is_gpt && (entry.name().is_some() || entry.uuid().is_some()) {
+            return config("DOS partitions have neither a name, nor a UUID".to_owned());
+        }
+
+        if let Some(name) = entry.name() {
+            if name.as_str().encode_utf16().count() > 36 {
+                return config(format!(
+                    "partition name longer than 36 UTF-16 code units: {}",
+                    name
+                ));
+            }
+        }
+
+        let uuid = match entry.uuid() {
+            Some(uuid) if gpt::guid_from_str(uuid.as_str()).is_none() => {
+                return config(format!("partition UUID is not an RFC 4122 UUID: {}", uuid));
+            }
+            Some(uuid) => Some(uuid.clone()),
+            None if is_gpt => {
+                let guid = gpt::guid_to_string(&gpt::random_guid()?);
+                let uuid: Uuid = guid
+                    .parse()
+                    .map_err(|e| TableWriterError::Config(format!("{}", e)))?;
+
+                Some(uuid)
+            }
+            None => None,
+        };
+
+        let flags = entry.flags().map(|f| f.bits()).unwrap_or_default();
+        if !is_gpt && flags & !0x80 != 0 {
+            return config(format!("invalid DOS partition flags: {:#04x}", flags));
+        }
+
+        // Partition number.
+        let max_entries = if is_gpt {
+            self.gpt_entries
+        } else {
+            DOS_ENTRIES
+        };
+        let is_free = |n: usize| Some(n) == replacing || self.partition(n).is_none();
+        let number = match entry.number() {
+            Some(n) if n == 0 || n > max_entries => {
+                return config(format!(
+                    "partition number out of range 1-{}: {}",
+                    max_entries, n
+                ));
+            }
+            Some(n) if !is_free(n) => {
+                return config(format!("partition {} already exists", n));
+            }
+            Some(n) => n,
+            None => match (1..=max_entries).find(|&n| is_free(n)) {
+                Some(n) => n,
+                None => return config(format!("partition table full: {} partitions", max_entries)),
+            },
+        };
+
+        // Partition location and size.
+        if entry.size() == Some(0) {
+            return config("partition size must be greater than zero".to_owned());
+        }
+
+        let regions = self.free_regions(replacing);
+        let fits = |start: u64, end: u64| match entry.size() {
+            Some(size) => start.checked_add(size - 1).is_some_and(|last| last <= end),
+            None => start <= end,
+        };
+
+        let (start, end) = match entry.start() {
+            Some(start) => regions
+                .iter()
+                .find(|(first, last)| *first <= start && start <= *last)
+                .filter(|(_, last)| fits(start, *last))
+                .map(|(_, last)| (start, *last)),
+            None => regions
+                .iter()
+                .map(|&(first, last)| (self.align_up(first), last))
+                .chain(regions.iter().copied())
+                .find(|&(start, last)| start <= last && fits(start, last)),
+        }
+        .ok_or_else(|| {
+            let err_msg = format!(
+                "not enough free space for partition {} at sector {:?} of size {:?}",
+                number,
+                entry.start(),
+                entry.size()
+            );
+
+            TableWriterError::Config(err_msg)
+        })?;
+
+        let size = entry.size().unwrap_or(end - start + 1);
+
+        if !is_gpt && (start > u32::MAX as u64 || size > u32::MAX as u64) {
+            return config(format!(
+                "partition {} beyond the 2 TiB limit of DOS partition tables",
+                number
+            ));
+        }
+
+        let mut entry = entry
+            .with_number(number)
+            .with_start(start)
+            .with_size(size)
+            .with_flags(PartitionBitflags::from(flags));
+
+        if let Some(uuid) = uuid {
+            entry = entry.with_uuid(uuid);
+        }
+
+        Ok(entry)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: inserts `entry` in the list of partitions, ordered by number.
+    fn insert(&mut self, entry: PartitionEntry) {
+        let index = self
+            .partitions
+            .iter()
+            .position(|p| p.number() > entry.number())
+            .unwrap_or(self.partitions.len());
+
+        self.partitions.insert(index, entry);
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the content of the first sector, with the MBR partition entries.
+    fn mbr_sector(&self) -> Vec<u8> {
+        let mut sector = vec![0u8; self.sector_size as usize];
+        sector[..MBR_BOOT_CODE_SIZE].copy_from_slice(&self.boot_code);
+
+        match &self.table_type {
+            PartitionTableType::GPT => {
+                let size = (self.device_size - 1).min(u32::MAX as u64) as u32;
+                let entry = MbrEntry::new(false, u8::from(OSType::GPTProtectiveMBR), 1, size);
+                sector[446..462].copy_from_slice(&entry.to_bytes());
+            }
+            _otherwise => {
+                sector[440..444].copy_from_slice(&self.disk_id[..4]);
+                sector[444..446].fill(0);
+
+                for p in self.partitions.iter() {
+                    if let (Some(number), Some(start), Some(size), PartitionType::MBR(os_type)) =
+                        (p.number(), p.start(), p.size(), p.partition_type())
+                    {
+                        let is_bootable = p.flags().is_some_and(|f| f.bits() & 0x80 != 0);
+                        let entry = MbrEntry::new(
+                            is_bootable,
+                            u8::from(*os_type),
+                            start as u32,
+                            size as u32,
+                        );
+                        let offset = 446 + 16 * (number - 1);
+                        sector[offset..offset + 16].copy_from_slice(&entry.to_bytes());
+                    }
+                }
+            }
+        }
+
+        sector[510] = 0x55;
+        sector[511] = 0xaa;
+
+        sector
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the sectors of a GPT (protective MBR, primary and backup headers,
+    /// and partition entry arrays) with their locations.
+    fn gpt_sectors(&self) -> Vec<(u64, Vec<u8>)> {
+        let mut entries = vec![0u8; self.gpt_entries * gpt::ENTRY_SIZE];
+
+        for p in self.partitions.iter() {
+            if let (Some(number), Some(start), Some(end), PartitionType::GPT(guid)) =
+                (p.number(), p.start(), p.end(), p.partition_type())
+            {
+                let type_guid = gpt::guid_from_str(guid.as_str()).unwrap_or_default();
+                let unique_guid = p
+                    .uuid()
+                    .and_then(|uuid| gpt::guid_from_str(uuid.as_str()))
+                    .unwrap_or_default();
+                let attributes = p.flags().map(|f| f.bits()).unwrap_or_default();
+                let name = p.name().map(|name| name.as_str()).unwrap_or_default();
+
+                let entry = GptEntry::new(type_guid, unique_guid, start, end, attributes, name);
+                let offset = (number - 1) * gpt::ENTRY_SIZE;
+                entries[offset..offset + gpt::ENTRY_SIZE].copy_from_slice(&entry.to_bytes());
+            }
+        }
+
+        let entries_crc32 = gpt::crc32(&entries);
+        entries.resize((self.gpt_entries_sectors() * self.sector_size) as usize, 0);
+
+        let last_lba = self.device_size - 1;
+        let backup_entry_lba = last_lba - self.gpt_entries_sectors();
+        let primary = GptHeader::new(
+            1,
+            last_lba,
+            self.first_usable_lba(),
+            self.last_usable_lba(),
+            self.disk_id,
+            2,
+            self.gpt_entries as u32,
+            entries_crc32,
+        );
+        let backup = primary.relocate(last_lba, 1, backup_entry_lba, self.last_usable_lba());
+
+        let header_sector = |header: &GptHeader| {
+            let mut sector = header.to_bytes().to_vec();
+            sector.resize(self.sector_size as usize, 0);
+            sector
+        };
+
+        vec![
+            (0, self.mbr_sector()),
+            (1, header_sector(&primary)),
+            (2, entries.clone()),
+            (backup_entry_lba, entries),
+            (last_lba, header_sector(&backup)),
+        ]
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const SECTOR: u64 = 512;
+
+    fn image(size: u64) -> File {
+        let file = tempfile::tempfile().unwrap();
+        file.set_len(size * SECTOR).unwrap();
+
+        file
+    }
+
+    fn gpt_entry(guid: Guid) -> PartitionEntry {
+        PartitionEntry::new(PartitionType::GPT(guid))
+    }
+
+    fn dos_entry(os_type: OSType) -> PartitionEntry {
+        PartitionEntry::new(PartitionType::MBR(os_type))
+    }
+
+    #[test]
+    fn partition_table_writer_rejects_unsupported_table_types() {
+        let actual = PartitionTableWriter::new(PartitionTableType::BSD, 8192, SECTOR);
+        assert!(matches!(actual, Err(TableWriterError::Unsupported(_))));
+
+        let actual = PartitionTableWriter::new(PartitionTableType::GPT, 8192, 1000);
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+
+        let actual = PartitionTableWriter::new(PartitionTableType::GPT, 60, SECTOR);
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+    }
+
+    #[test]
+    fn partition_table_writer_aligns_new_partitions() {
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        assert_eq!(table.first_usable_lba(), 34);
+        assert_eq!(table.last_usable_lba(), 8158);
+
+        let first = table
+            .add_partition(gpt_entry(Guid::EfiSystem).with_size(2048))
+            .unwrap();
+        let second = table.add_partition(gpt_entry(Guid::LinuxData)).unwrap();
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 2);
+        assert_eq!(table.partition(1).unwrap().start(), Some(2048));
+        assert_eq!(table.partition(2).unwrap().start(), Some(4096));
+        assert_eq!(table.partition(2).unwrap().end(), Some(8158));
+        assert!(table.partition(2).unwrap().uuid().is_some());
+    }
+
+    #[test]
+    fn partition_table_writer_aligns_new_partitions_with_an_alignment_offset() {
+        let mut table = PartitionTableWriter::new(PartitionTableType::DOS, 16384, SECTOR).unwrap();
+        table.set_alignment(1024 * 1024, 7 * SECTOR);
+
+        let number = table.add_partition(dos_entry(OSType::Linux)).unwrap();
+
+        assert_eq!(table.partition(number).unwrap().start(), Some(2055));
+    }
+
+    #[test]
+    fn partition_table_writer_rejects_invalid_partitions() {
+        let mut table = PartitionTableWriter::new(PartitionTableType::DOS, 16384, SECTOR).unwrap();
+        table
+            .add_partition(dos_entry(OSType::Linux).with_size(4096))
+            .unwrap();
+
+        // Overlap
+        let actual = table.add_partition(dos_entry(OSType::LinuxSwap).with_start(4000));
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+
+        // Wrong partition type
+        let actual = table.add_partition(gpt_entry(Guid::LinuxData));
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+
+        // No name in DOS partition tables
+        let name: Label = "data".parse().unwrap();
+        let actual = table.add_partition(dos_entry(OSType::Linux).with_name(name));
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+
+        // Extended partitions
+        let actual = table.add_partition(dos_entry(OSType::ExtendedPartition));
+        assert!(matches!(actual, Err(TableWriterError::Unsupported(_))));
+
+        // Partition number already used
+        let actual = table.add_partition(dos_entry(OSType::LinuxSwap).with_number(1));
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+
+        // Table full
+        for _ in 0..3 {
+            table
+                .add_partition(dos_entry(OSType::Linux).with_size(2048))
+                .unwrap();
+        }
+        let actual = table.add_partition(dos_entry(OSType::Linux).with_size(2048));
+        assert!(matches!(actual, Err(TableWriterError::Config(_))));
+    }
+
+    #[test]
+    fn partition_table_writer_writes_a_valid_gpt() {
+        let file = image(8192);
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        let name: Label = "root".parse().unwrap();
+        let uuid: Uuid = "3b8f8425-20e0-4f3b-907f-1a25a76f98e8".parse().unwrap();
+        table
+            .add_partition(
+                gpt_entry(Guid::LinuxData)
+                    .with_name(name)
+                    .with_uuid(uuid)
+                    .with_flags(PartitionBitflags::from(1 << 60)),
+            )
+            .unwrap();
+
+        table.write(&file).unwrap();
+
+        let report = gpt::verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+        assert_eq!(
+            report
+                .header(GptLocation::Primary)
+                .unwrap()
+                .disk_guid_bytes(),
+            &table.disk_id
+        );
+
+        let entry = &report.entries(GptLocation::Backup)[0];
+        assert_eq!(entry.partition_type(), Some(Guid::LinuxData));
+        assert_eq!(
+            entry.unique_guid_string(),
+            "3b8f8425-20e0-4f3b-907f-1a25a76f98e8"
+        );
+        assert_eq!(entry.first_lba(), 2048);
+        assert_eq!(entry.last_lba(), 8158);
+        assert_eq!(entry.attributes().bits(), 1 << 60);
+        assert_eq!(entry.name(), "root");
+
+        let mut sector = [0u8; 512];
+        file.read_exact_at(&mut sector, 0).unwrap();
+        let mbr = MbrEntry::parse_table(&sector).unwrap();
+        assert!(mbr[0].is_protective());
+        assert_eq!(mbr[0].size(), 8191);
+        assert!(mbr[1].is_empty());
+    }
+
+    #[test]
+    fn partition_table_writer_writes_a_valid_dos_partition_table() {
+        let file = image(16384);
+        let mut table = PartitionTableWriter::new(PartitionTableType::DOS, 16384, SECTOR).unwrap();
+        table
+            .add_partition(
+                dos_entry(OSType::W95FAT32LBA)
+                    .with_size(4096)
+                    .with_flags(PartitionBitflags::from(0x80)),
+            )
+            .unwrap();
+        table
+            .add_partition(dos_entry(OSType::Linux).with_number(3))
+            .unwrap();
+
+        table.write(&file).unwrap();
+
+        let mut sector = [0u8; 512];
+        file.read_exact_at(&mut sector, 0).unwrap();
+        let mbr = MbrEntry::parse_table(&sector).unwrap();
+
+        assert!(mbr[0].is_bootable());
+        assert_eq!(mbr[0].os_type(), Some(OSType::W95FAT32LBA));
+        assert_eq!((mbr[0].start(), mbr[0].size()), (2048, 4096));
+        assert!(mbr[1].is_empty());
+        assert!(!mbr[2].is_bootable());
+        assert_eq!(mbr[2].os_type(), Some(OSType::Linux));
+        assert_eq!((mbr[2].start(), mbr[2].end()), (6144, 16384));
+
+        let disk_id = format!(
+            "{:08x}",
+            u32::from_le_bytes([sector[440], sector[441], sector[442], sector[443]])
+        );
+        assert_eq!(disk_id, table.disk_id());
+    }
+
+    #[test]
+    fn partition_table_writer_edits_an_existing_gpt() {
+        let file = image(8192);
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        table
+            .add_partition(gpt_entry(Guid::EfiSystem).with_size(2048))
+            .unwrap();
+        table.add_partition(gpt_entry(Guid::LinuxSwap)).unwrap();
+        // Keep boot code in the protective MBR.
+        file.write_all_at(&[0xeb, 0x63, 0x90], 0).unwrap();
+        table.write(&file).unwrap();
+        file.write_all_at(&[0xeb, 0x63, 0x90], 0).unwrap();
+
+        let mut table = PartitionTableWriter::from_device(&file, SECTOR).unwrap();
+        assert_eq!(table.table_type(), &PartitionTableType::GPT);
+        assert_eq!(table.partitions().len(), 2);
+
+        let uuid = table.partition(2).unwrap().uuid().cloned();
+        let name: Label = "root".parse().unwrap();
+        let entry = table
+            .partition(2)
+            .unwrap()
+            .clone()
+            .with_partition_type(PartitionType::GPT(Guid::LinuxData))
+            .with_name(name);
+        table.update_partition(2, entry).unwrap();
+        table.delete_partition(1).unwrap();
+        assert!(table.delete_partition(1).is_err());
+
+        table.write(&file).unwrap();
+
+        let report = gpt::verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+        let entries = report.entries(GptLocation::Primary);
+        assert!(entries[0].is_empty());
+        assert_eq!(entries[1].partition_type(), Some(Guid::LinuxData));
+        assert_eq!(entries[1].name(), "root");
+        assert_eq!(
+            Some(entries[1].unique_guid_string()),
+            uuid.map(|uuid| uuid.as_str().to_owned())
+        );
+
+        let mut boot_code = [0u8; 3];
+        file.read_exact_at(&mut boot_code, 0).unwrap();
+        assert_eq!(boot_code, [0xeb, 0x63, 0x90]);
+    }
+
+    #[test]
+    fn partition_table_writer_replaces_a_gpt_with_a_dos_partition_table() {
+        let file = image(8192);
+        let table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        table.write(&file).unwrap();
+
+        let mut table = PartitionTableWriter::new(PartitionTableType::DOS, 8192, SECTOR).unwrap();
+        table.add_partition(dos_entry(OSType::Linux)).unwrap();
+        table.write(&file).unwrap();
+
+        let report = gpt::verify(&file, SECTOR).unwrap();
+        assert!(report.header(GptLocation::Primary).is_none());
+        assert!(report.header(GptLocation::Backup).is_none());
+
+        let table = PartitionTableWriter::from_device(&file, SECTOR).unwrap();
+        assert_eq!(table.table_type(), &PartitionTableType::DOS);
+        assert_eq!(table.partition(1).unwrap().start(), Some(2048));
+    }
+
+    #[test]
+    fn partition_table_writer_ignores_a_stale_gpt_next_to_a_dos_mbr() {
+        let file = image(8192);
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        table.add_partition(gpt_entry(Guid::LinuxData)).unwrap();
+        table.write(&file).unwrap();
+
+        // Overwrite the protective MBR, and the primary GPT header, leaving a valid backup GPT.
+        let dos_file = image(8192);
+        let mut dos_table =
+            PartitionTableWriter::new(PartitionTableType::DOS, 8192, SECTOR).unwrap();
+        dos_table
+            .add_partition(dos_entry(OSType::W95FAT32LBA).with_start(4096))
+            .unwrap();
+        dos_table.write(&dos_file).unwrap();
+
+        let mut sector = [0u8; 512];
+        dos_file.read_exact_at(&mut sector, 0).unwrap();
+        file.write_all_at(&sector, 0).unwrap();
+        file.write_all_at(&[0u8; 512], SECTOR).unwrap();
+
+        let report = gpt::verify(&file, SECTOR).unwrap();
+        assert!(!report.is_valid(GptLocation::Primary));
+        assert!(report.is_valid(GptLocation::Backup));
+
+        let table = PartitionTableWriter::from_device(&file, SECTOR).unwrap();
+        assert_eq!(table.table_type(), &PartitionTableType::DOS);
+        assert_eq!(table.partitions().len(), 1);
+
+        let partition = table.partition(1).unwrap();
+        assert_eq!(
+            partition.partition_type(),
+            &PartitionType::MBR(OSType::W95FAT32LBA)
+        );
+        assert_eq!(partition.start(), Some(4096));
+    }
+
+    #[test]
+    fn partition_table_writer_refuses_a_gpt_with_a_hybrid_mbr() {
+        let file = image(8192);
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        table
+            .add_partition(gpt_entry(Guid::WindowsBasicData).with_size(2048))
+            .unwrap();
+        table.write(&file).unwrap();
+
+        // Mirror the first GPT partition in the second MBR entry.
+        let entry = MbrEntry::new(false, u8::from(OSType::W95FAT32LBA), 2048, 2048);
+        file.write_all_at(&entry.to_bytes(), 446 + 16).unwrap();
+
+        let actual = PartitionTableWriter::from_device(&file, SECTOR);
+        assert!(matches!(actual, Err(TableWriterError::Unsupported(_))));
+    }
+
+    #[test]
+    fn partition_table_writer_keeps_the_usable_sectors_of_a_loaded_gpt() {
+        // A GPT with a 4-entry partition entry array, spanning a single sector.
+        let file = image(8192);
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 8192, SECTOR).unwrap();
+        table.gpt_entries = 4;
+        assert_eq!(table.first_usable_lba(), 3);
+        assert_eq!(table.last_usable_lba(), 8189);
+
+        table
+            .add_partition(gpt_entry(Guid::LinuxData).with_start(3))
+            .unwrap();
+        table.write(&file).unwrap();
+
+        let mut table = PartitionTableWriter::from_device(&file, SECTOR).unwrap();
+        assert_eq!(table.first_usable_lba(), 3);
+        assert_eq!(table.last_usable_lba(), 8189);
+        assert_eq!(table.partition(1).unwrap().start(), Some(3));
+        assert_eq!(table.partition(1).unwrap().end(), Some(8189));
+
+        // The table is full.
+        assert!(table.add_partition(gpt_entry(Guid::LinuxSwap)).is_err());
+        table.delete_partition(1).unwrap();
+        table
+            .add_partition(gpt_entry(Guid::LinuxData).with_number(4).with_start(3))
+            .unwrap();
+        assert!(table
+            .add_partition(gpt_entry(Guid::LinuxData).with_number(5))
+            .is_err());
+        table.write(&file).unwrap();
+
+        let report = gpt::verify(&file, SECTOR).unwrap();
+        assert!(report.anomalies().is_empty());
+
+        let header = report.header(GptLocation::Primary).unwrap();
+        assert_eq!(header.number_of_entries(), 4);
+        assert_eq!(header.first_usable_lba(), 3);
+        assert_eq!(header.last_usable_lba(), 8189);
+    }
+}
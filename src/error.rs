@@ -10,9 +10,15 @@
 
 // From this library
 use crate::core::errors::ConversionError;
+use crate::core::errors::DevDiskError;
+use crate::core::errors::DeviceError;
 use crate::core::errors::EncodeError;
+use crate::core::errors::ErrorKind;
+use crate::core::errors::FormatError;
+use crate::core::errors::GptError;
 use crate::core::errors::MiscError;
 use crate::core::errors::ParserError;
+use crate::core::errors::TableWriterError;
 
 use crate::cache::CacheBuilderError;
 use crate::cache::CacheError;
@@ -69,12 +75,24 @@ pub enum RsBlkidError {
     #[error(transparent)]
     Conversion(#[from] ConversionError),
 
+    #[error(transparent)]
+    DevDisk(#[from] DevDiskError),
+
+    #[error(transparent)]
+    Device(#[from] DeviceError),
+
     #[error(transparent)]
     Encode(#[from] EncodeError),
 
     #[error(transparent)]
     EntryIter(#[from] EntryIterError),
 
+    #[error(transparent)]
+    Format(#[from] FormatError),
+
+    #[error(transparent)]
+    Gpt(#[from] GptError),
+
     #[error(transparent)]
     Misc(#[from] MiscError),
 
@@ -87,9 +105,30 @@ pub enum RsBlkidError {
     #[error(transparent)]
     ProbeBuilder(#[from] ProbeBuilderError),
 
+    #[error(transparent)]
+    TableWriter(#[from] TableWriterError),
+
     #[error(transparent)]
     TagIter(#[from] TagIterError),
 
     #[error(transparent)]
     Topology(#[from] TopologyError),
 }
+
+impl RsBlkidError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Cache(e) => e.kind(),
+            Self::CacheBuilder(e) => e.kind(),
+            Self::DevDisk(e) => e.kind(),
+            Self::Device(e) => e.kind(),
+            Self::Gpt(e) => e.kind(),
+            Self::Misc(e) => e.kind(),
+            Self::Probe(e) => e.kind(),
+            Self::ProbeBuilder(e) => e.kind(),
+            Self::TableWriter(e) => e.kind(),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
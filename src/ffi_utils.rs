@@ -15,6 +15,15 @@
 
 // From this library
 
+//---- Error functions
+
+#[doc(hidden)]
+/// Sets `errno` to `0`, so that a `libblkid` function failing without setting it is not
+/// reported with a stale value left by an unrelated call.
+pub(crate) fn clear_errno() {
+    unsafe { *libc::__errno_location() = 0 }
+}
+
 //---- Conversion functions
 
 #[doc(hidden)]
@@ -299,10 +299,10 @@
 //!
 //! | `libblkid`                       | `rsblkid`                                                                                                                                                                                    |
 //! | ------------------               | ---------                                                                                                                                                                                    |
-//! | [`blkid_do_fullprobe`][43]       | [`Probe::find_device_properties`](crate::probe::Probe::find_device_properties)                                                                                                               |
+//! | [`blkid_do_fullprobe`][43]       | [`Probe::find_device_properties`](crate::probe::Probe::find_device_properties)<br>[`Probe::try_find_device_properties`](crate::probe::Probe::try_find_device_properties)                     |
 //! | [`blkid_do_wipe`][44]            | [`Probe::delete_properties_from_device`](crate::probe::Probe::delete_properties_from_device)<br>[`Probe::delete_properties_from_memory`](crate::probe::Probe::delete_properties_from_memory) |
-//! | [`blkid_do_probe`][45]           | [`Probe::run_scan`](crate::probe::Probe::run_scan)                                                                                                                                           |
-//! | [`blkid_do_safeprobe`][46]       | [`Probe::find_all_device_properties`](crate::probe::Probe::find_all_device_properties)                                                                                                       |
+//! | [`blkid_do_probe`][45]           | [`Probe::run_scan`](crate::probe::Probe::run_scan)<br>[`Probe::try_run_scan`](crate::probe::Probe::try_run_scan)                                                                             |
+//! | [`blkid_do_safeprobe`][46]       | [`Probe::find_all_device_properties`](crate::probe::Probe::find_all_device_properties)<br>[`Probe::try_find_all_device_properties`](crate::probe::Probe::try_find_all_device_properties)     |
 //! | [`blkid_probe_get_value`][47]    | [`Probe::nth_device_property`](crate::probe::Probe::nth_device_property)                                                                                                                     |
 //! | [`blkid_probe_has_value`][48]    | [`Probe::device_property_has_value`](crate::probe::Probe::device_property_has_value)                                                                                                         |
 //! | [`blkid_probe_lookup_value`][49] | [`Probe::lookup_device_property_value`](crate::probe::Probe::lookup_device_property_value)                                                                                                   |
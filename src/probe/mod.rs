@@ -511,14 +511,22 @@
 pub use fs_property_enum::FsProperty;
 pub use io_hint_struct::IoHint;
 pub use partition_iter_struct::PartitionIter;
+pub use partition_node_struct::PartitionNode;
 pub use partition_scanning_option_enum::PartitionScanningOption;
 pub use partition_struct::Partition;
+pub use partition_table_node_struct::PartitionTableNode;
 pub use partition_table_struct::PartitionTable;
 pub use probe_builder_error_enum::ProbeBuilderError;
 pub(crate) use probe_builder_struct::PrbBuilder;
 pub use probe_builder_struct::ProbeBuilder;
 pub use probe_error_enum::ProbeError;
 pub use probe_struct::Probe;
+pub use property_error_struct::PropertyError;
+pub use property_iter_struct::PropertyIter;
+pub use recovery_candidate_struct::RecoveryCandidate;
+pub use recovery_progress_struct::RecoveryProgress;
+pub use recovery_report_struct::RecoveryReport;
+pub use scan_outcome_enum::ScanOutcome;
 pub use scan_result_enum::ScanResult;
 pub use tag_iter_struct::TagIter;
 pub use topology_error_enum::TopologyError;
@@ -528,13 +536,21 @@
 mod fs_property_enum;
 mod io_hint_struct;
 mod partition_iter_struct;
+mod partition_node_struct;
 mod partition_scanning_option_enum;
 mod partition_struct;
+mod partition_table_node_struct;
 mod partition_table_struct;
 mod probe_builder_error_enum;
 mod probe_builder_struct;
 mod probe_error_enum;
 mod probe_struct;
+mod property_error_struct;
+mod property_iter_struct;
+mod recovery_candidate_struct;
+mod recovery_progress_struct;
+mod recovery_report_struct;
+mod scan_outcome_enum;
 mod scan_result_enum;
 mod tag_iter_struct;
 mod topology_error_enum;
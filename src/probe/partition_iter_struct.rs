@@ -4,11 +4,25 @@
 // From dependency library
 
 // From standard library
+use std::cmp::Ordering;
+use std::iter::Peekable;
 use std::mem::MaybeUninit;
+use std::str::Chars;
+use std::str::FromStr;
 
 // From this library
+use crate::core::partition::Arch;
+use crate::core::partition::DpsRole;
+use crate::core::partition::Guid;
 use crate::probe::{Partition, PartitionTable, Probe};
 
+/// GPT partition attribute (bit 1) telling EFI firmware not to provide a block I/O protocol for
+/// a partition.
+const GPT_FLAG_NO_BLOCK_IO_PROTOCOL: u64 = 1 << 1;
+
+/// GPT partition attribute (bit 63) excluding a partition from automatic discovery.
+const GPT_FLAG_NO_AUTO: u64 = 1 << 63;
+
 /// Iterator over a collection of [`Partition`]s.
 #[derive(Debug)]
 pub struct PartitionIter<'a> {
@@ -118,6 +132,88 @@ pub fn partition_from_device_number(&self, device_number: u64) -> Option<Partiti
 
         })
     }
+
+    /// Returns the partition `systemd-gpt-auto-generator` would pick for the given role in the
+    /// [Discoverable Partitions
+    /// Specification](https://uapi-group.org/specifications/specs/discoverable_partitions_specification/).
+    ///
+    /// For architecture-specific roles (root, `/usr`, and their `dm-verity` partitions), only
+    /// partitions matching `arch`, or the native architecture if `arch` is `None`, are considered.
+    ///
+    /// Following `systemd`'s rules:
+    /// - partitions with the `no-auto` attribute (bit 63) are ignored, except for the ESP,
+    /// - an ESP with the `no-block-io-protocol` attribute (bit 1) is ignored,
+    /// - when several root or `/usr` partitions match, the one with the highest version number in
+    /// its label is chosen (e.g. `root-2.1` over `root-2.0`), or the first one in the partition
+    /// table if their versions are equal,
+    /// - when several partitions match any other role, the first one in the partition table is
+    /// chosen.
+    pub fn find_dps_partition(self, role: DpsRole, arch: Option<Arch>) -> Option<Partition<'a>> {
+        log::debug!(
+            "PartitionIter::find_dps_partition looking for {:?} partition (arch: {:?})",
+            role,
+            arch
+        );
+
+        let arch = if role.is_arch_specific() {
+            arch.or_else(Arch::native)
+        } else {
+            None
+        };
+
+        let is_versioned = matches!(role, DpsRole::Root | DpsRole::Usr);
+
+        self.filter(|partition| {
+            let partition_type = partition
+                .partition_type_string()
+                .and_then(|type_string| Guid::from_str(&type_string).ok());
+
+            is_dps_candidate(partition_type, partition.flags(), role, arch)
+        })
+        .fold(
+            None,
+            |selected: Option<Partition<'a>>, candidate| match selected {
+                Some(current)
+                    if !is_versioned
+                        || compare_versions(
+                            &candidate.name().unwrap_or_default(),
+                            &current.name().unwrap_or_default(),
+                        ) != Ordering::Greater =>
+                {
+                    Some(current)
+                }
+                _otherwise => Some(candidate),
+            },
+        )
+    }
+
+    /// Returns the root partition `systemd-gpt-auto-generator` would pick for the architecture
+    /// `arch` (see [`PartitionIter::find_dps_partition`]).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::partition::Arch;
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("disk.img")
+    ///         .scan_device_partitions(true)
+    ///         .build()?;
+    ///
+    ///     probe.find_device_properties();
+    ///
+    ///     if let Some(root) = probe.iter_partitions().find_root_partition_for(Arch::X86_64) {
+    ///         println!("root partition: {}", root.number());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn find_root_partition_for(self, arch: Arch) -> Option<Partition<'a>> {
+        self.find_dps_partition(DpsRole::Root, Some(arch))
+    }
 }
 
 impl<'a> Iterator for PartitionIter<'a> {
@@ -160,3 +256,180 @@ fn next(&mut self) -> Option<Self::Item> {
         })
     }
 }
+
+#[doc(hidden)]
+/// Helper function: returns `true` if a partition of type `partition_type`, with attributes
+/// `flags`, is discoverable in the given `role` for the architecture `arch`.
+fn is_dps_candidate(
+    partition_type: Option<Guid>,
+    flags: u64,
+    role: DpsRole,
+    arch: Option<Arch>,
+) -> bool {
+    let is_dps_match = partition_type
+        .map(|guid| guid.info())
+        .is_some_and(|info| info.dps_role() == Some(role) && info.arch() == arch);
+    // Like `systemd`, ignore `no-auto` for the ESP, but not `no-block-io-protocol`.
+    let is_discoverable = match role {
+        DpsRole::Esp => flags & GPT_FLAG_NO_BLOCK_IO_PROTOCOL == 0,
+        _otherwise => flags & GPT_FLAG_NO_AUTO == 0,
+    };
+
+    is_dps_match && is_discoverable
+}
+
+#[doc(hidden)]
+/// Helper function: compares the version numbers in two partition labels, sequences of digits
+/// are compared numerically, other characters lexicographically.
+fn compare_versions(first: &str, second: &str) -> Ordering {
+    let mut first = first.chars().peekable();
+    let mut second = second.chars().peekable();
+
+    loop {
+        match (first.peek().copied(), second.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                let a = take_number(&mut first);
+                let b = take_number(&mut second);
+
+                // Without leading zeros, longer numbers are larger.
+                match a.len().cmp(&b.len()).then_with(|| a.cmp(&b)) {
+                    Ordering::Equal => continue,
+                    ordering => return ordering,
+                }
+            }
+            (Some(a), Some(b)) => match a.cmp(&b) {
+                Ordering::Equal => {
+                    first.next();
+                    second.next();
+                }
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: consumes a sequence of digits, returns it without leading zeros.
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+        digits.push(c);
+    }
+
+    digits.trim_start_matches('0').to_owned()
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn compare_versions_compares_numbers_numerically() {
+        assert_eq!(compare_versions("root-2.10", "root-2.9"), Ordering::Greater);
+        assert_eq!(compare_versions("root-2.0", "root-2.00"), Ordering::Equal);
+        assert_eq!(compare_versions("root-1", "root-1.1"), Ordering::Less);
+        assert_eq!(compare_versions("", ""), Ordering::Equal);
+        assert_eq!(compare_versions("root-a", "root-b"), Ordering::Less);
+        assert_eq!(compare_versions("usr-10", "usr-9.9"), Ordering::Greater);
+        assert_eq!(compare_versions("root-007", "root-7"), Ordering::Equal);
+    }
+
+    #[test]
+    fn is_dps_candidate_ignores_partitions_with_the_no_auto_flag() {
+        let root = Some(Guid::LinuxRootx86_64);
+        let arch = Some(Arch::X86_64);
+        assert!(is_dps_candidate(root, 0, DpsRole::Root, arch));
+        assert!(!is_dps_candidate(
+            root,
+            GPT_FLAG_NO_AUTO,
+            DpsRole::Root,
+            arch
+        ));
+
+        // The ESP ignores `no-auto`, but not `no-block-io-protocol`.
+        let esp = Some(Guid::EfiSystem);
+        assert!(is_dps_candidate(esp, GPT_FLAG_NO_AUTO, DpsRole::Esp, None));
+        assert!(!is_dps_candidate(
+            esp,
+            GPT_FLAG_NO_BLOCK_IO_PROTOCOL,
+            DpsRole::Esp,
+            None
+        ));
+
+        // `no-block-io-protocol` only applies to the ESP.
+        assert!(is_dps_candidate(
+            root,
+            GPT_FLAG_NO_BLOCK_IO_PROTOCOL,
+            DpsRole::Root,
+            arch
+        ));
+
+        // Mismatched role or architecture.
+        assert!(!is_dps_candidate(root, 0, DpsRole::Usr, arch));
+        assert!(!is_dps_candidate(root, 0, DpsRole::Root, Some(Arch::Arm64)));
+        assert!(!is_dps_candidate(None, 0, DpsRole::Root, arch));
+    }
+
+    #[test]
+    fn find_dps_partition_picks_the_highest_version_for_root_and_the_first_match_otherwise(
+    ) -> crate::Result<()> {
+        use crate::core::device::Label;
+        use crate::core::partition::{PartitionBitflags, PartitionTableType, PartitionType};
+        use crate::core::utils::table_writer::{PartitionEntry, PartitionTableWriter};
+
+        let tmp_file = tempfile::NamedTempFile::new().unwrap();
+        tmp_file.as_file().set_len(16 * 1024 * 1024).unwrap();
+
+        let mut table = PartitionTableWriter::new(PartitionTableType::GPT, 32768, 512)?;
+        let partitions = [
+            (Guid::LinuxRootx86_64, "root-1.0", 0),
+            (Guid::LinuxRootx86_64, "root-2.10", 0),
+            (Guid::LinuxRootx86_64, "root-2.9", 0),
+            (Guid::LinuxRootx86_64, "root-3.0", GPT_FLAG_NO_AUTO),
+            (Guid::LinuxHome, "home-1", 0),
+            (Guid::LinuxHome, "home-2", 0),
+        ];
+
+        for (guid, name, flags) in partitions {
+            let entry = PartitionEntry::new(PartitionType::GPT(guid))
+                .with_size(2048)
+                .with_name(name.parse::<Label>()?)
+                .with_flags(PartitionBitflags::from(flags));
+            table.add_partition(entry)?;
+        }
+
+        table.write(tmp_file.as_file())?;
+
+        let mut probe = Probe::builder()
+            .scan_file(tmp_file.reopen().unwrap())
+            .scan_device_superblocks(false)
+            .scan_device_partitions(true)
+            .build()?;
+        probe.find_device_properties();
+
+        let root = probe
+            .iter_partitions()
+            .find_root_partition_for(Arch::X86_64)
+            .map(|partition| partition.number());
+        assert_eq!(root, Some(2));
+
+        let home = probe
+            .iter_partitions()
+            .find_dps_partition(DpsRole::Home, None)
+            .map(|partition| partition.number());
+        assert_eq!(home, Some(5));
+
+        let swap = probe
+            .iter_partitions()
+            .find_dps_partition(DpsRole::Swap, None)
+            .map(|partition| partition.number());
+        assert_eq!(swap, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::probe::{Partition, PartitionTableNode};
+
+/// A [`Partition`] in a [`PartitionTableNode`], with the partition table it contains, if any.
+#[derive(Debug)]
+pub struct PartitionNode<'a> {
+    partition: Partition<'a>,
+    ebr_location: Option<u64>,
+    nested_table: Option<PartitionTableNode<'a>>,
+}
+
+impl<'a> PartitionNode<'a> {
+    #[doc(hidden)]
+    /// Creates a new `PartitionNode` instance.
+    pub(super) fn new(
+        partition: Partition<'a>,
+        ebr_location: Option<u64>,
+        nested_table: Option<PartitionTableNode<'a>>,
+    ) -> PartitionNode<'a> {
+        Self {
+            partition,
+            ebr_location,
+            nested_table,
+        }
+    }
+
+    /// Returns the partition.
+    pub fn partition(&self) -> &Partition<'a> {
+        &self.partition
+    }
+
+    /// Returns the location, in bytes, of the `Extended Boot Record` (EBR) describing this
+    /// partition, when it is a `DOS` logical partition.
+    ///
+    /// **Note:** the location is relative to the beginning of the scanned device segment (see
+    /// [`ProbeBuilder::scan_device_segment`](crate::probe::ProbeBuilder::scan_device_segment)),
+    /// i.e. to the beginning of the device when the whole device is scanned.
+    pub fn ebr_location_in_bytes(&self) -> Option<u64> {
+        self.ebr_location
+    }
+
+    /// Returns the partition table nested inside this partition (e.g. a `BSD disklabel` in a `DOS`
+    /// primary partition), if any.
+    pub fn nested_table(&self) -> Option<&PartitionTableNode<'a>> {
+        self.nested_table.as_ref()
+    }
+}
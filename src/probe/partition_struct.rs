@@ -7,6 +7,10 @@
 use std::mem::MaybeUninit;
 
 // From this library
+use crate::core::device::Label;
+use crate::core::device::Uuid;
+use crate::core::partition::PartitionBitflags;
+use crate::core::partition::PartitionType;
 use crate::ffi_utils;
 use crate::probe::PartitionTable;
 use crate::probe::Probe;
@@ -54,6 +58,13 @@ pub fn name(&self) -> Option<String> {
         }
     }
 
+    /// Returns the partition's name as a [`Label`], if supported by the partition type (e.g.
+    /// `GPT`, `Mac`).
+    pub fn typed_name(&self) -> Option<Label> {
+        self.name()
+            .and_then(|name| Label::try_from(name.into_bytes()).ok())
+    }
+
     /// Returns the partition flags (or attributes for GPT partitions).
     pub fn flags(&self) -> u64 {
         let flags = unsafe { libblkid::blkid_partition_get_flags(self.ptr) };
@@ -62,6 +73,11 @@ pub fn flags(&self) -> u64 {
         flags
     }
 
+    /// Returns the partition flags (or attributes for GPT partitions) as [`PartitionBitflags`].
+    pub fn typed_flags(&self) -> PartitionBitflags {
+        PartitionBitflags::from(self.flags())
+    }
+
     /// Returns the partition's number (e.g. `N` in `/dev/sdN`).
     ///
     /// Note that this number is generated by the library independently of your OS.
@@ -180,6 +196,37 @@ pub fn partition_type_string(&self) -> Option<String> {
         }
     }
 
+    /// Returns the partition's type, converted according to the type of its parent partition
+    /// table:
+    /// - an [`OSType`](crate::core::partition::OSType) for `DOS` and `Minix` partitions,
+    /// - a [`Guid`](crate::core::partition::Guid) for `GPT` partitions,
+    /// - a [`BsdFsType`](crate::core::partition::BsdFsType) for partitions in a `BSD disklabel`,
+    /// - a [`SunTag`](crate::core::partition::SunTag) for `Sun` and `Solaris x86` partitions,
+    /// - an [`SgiType`](crate::core::partition::SgiType) for `SGI` partitions.
+    ///
+    /// Returns `None` if the partition's type is unknown, or if its partition table type does not
+    /// have a typed representation (e.g. `Mac`).
+    pub fn typed_partition_type(&self) -> Option<PartitionType> {
+        log::debug!("Partition::typed_partition_type getting partition type");
+
+        let table_type = self
+            .partition_table()
+            .and_then(|table| table.partition_table_type())?;
+        let type_string = self.partition_type_string();
+        let partition_type = PartitionType::for_table_entry(
+            &table_type,
+            self.partition_type(),
+            type_string.as_deref(),
+        );
+
+        log::debug!(
+            "Partition::typed_partition_type partition type: {:?}",
+            partition_type
+        );
+
+        partition_type
+    }
+
     /// Returns a partition's parent partition table.
     ///
     /// In general, all partitions on a device share the same parent partition table. Except for
@@ -234,6 +281,12 @@ pub fn uuid(&self) -> Option<String> {
         }
     }
 
+    /// Returns a partition's UUID as a [`Uuid`], when the partition is a member of a `GPT` or `DOS`
+    /// partition table.
+    pub fn typed_uuid(&self) -> Option<Uuid> {
+        self.uuid().and_then(|uuid| uuid.parse().ok())
+    }
+
     /// Returns `true` when the partition is an `Extended` partition.
     pub fn is_extended(&self) -> bool {
         let extended = unsafe { libblkid::blkid_partition_is_extended(self.ptr) == 1 };
@@ -0,0 +1,300 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::os::unix::fs::FileExt;
+
+// From this library
+use crate::core::partition::OSType;
+use crate::core::partition::PartitionTableType;
+use crate::probe::{Partition, PartitionNode, PartitionTable, Probe};
+
+/// Maximum number of `Extended Boot Record`s followed in a chain, the same limit as `libblkid`.
+const MAX_EBR_CHAIN_LENGTH: usize = 100;
+
+/// A node in the tree of partition tables found on a device, with the partitions the table owns.
+///
+/// Partitions that contain a nested partition table (e.g. a `BSD disklabel` in a `DOS` primary
+/// partition) hold a child `PartitionTableNode`.
+#[derive(Debug)]
+pub struct PartitionTableNode<'a> {
+    table: PartitionTable<'a>,
+    location: Option<u64>,
+    partitions: Vec<PartitionNode<'a>>,
+}
+
+impl<'a> PartitionTableNode<'a> {
+    #[doc(hidden)]
+    /// Builds the tree rooted at `root`, from the list of all partitions found by `probe`.
+    pub(super) fn build(
+        probe: &'a Probe,
+        root: libblkid::blkid_parttable,
+        partitions: &[libblkid::blkid_partition],
+    ) -> PartitionTableNode<'a> {
+        log::debug!("PartitionTableNode::build building partition table tree");
+
+        let mut visited = HashSet::new();
+
+        Self::build_node(probe, root, partitions, 0, &mut visited)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: builds the node of `table`, whose location is relative to `base` bytes
+    /// from the beginning of the device.
+    fn build_node(
+        probe: &'a Probe,
+        table: libblkid::blkid_parttable,
+        partitions: &[libblkid::blkid_partition],
+        base: u64,
+        visited: &mut HashSet<libblkid::blkid_parttable>,
+    ) -> PartitionTableNode<'a> {
+        visited.insert(table);
+
+        let table = PartitionTable::new(probe, table);
+        let location = table.location_in_bytes().map(|offset| base + offset);
+        let members: Vec<_> = partitions
+            .iter()
+            .copied()
+            .filter(|&ptr| unsafe { libblkid::blkid_partition_get_table(ptr) } == table.ptr)
+            .collect();
+
+        let ebr_locations = match table.partition_table_type() {
+            Some(PartitionTableType::DOS) => Self::ebr_locations(probe, &members),
+            _otherwise => BTreeMap::new(),
+        };
+
+        let nodes = members
+            .into_iter()
+            .map(|ptr| {
+                let partition = Partition::new(probe, ptr);
+                let ebr_location = ebr_locations.get(&partition.location_in_bytes()).copied();
+
+                // Tables whose parent is this partition.
+                let nested = partitions
+                    .iter()
+                    .map(|&p| unsafe { libblkid::blkid_partition_get_table(p) })
+                    .find(|&t| {
+                        !t.is_null()
+                            && !visited.contains(&t)
+                            && unsafe { libblkid::blkid_parttable_get_parent(t) } == ptr
+                    });
+
+                let nested_table = nested.map(|t| {
+                    let base = partition.location_in_bytes();
+                    Self::build_node(probe, t, partitions, base, visited)
+                });
+
+                PartitionNode::new(partition, ebr_location, nested_table)
+            })
+            .collect();
+
+        Self {
+            table,
+            location,
+            partitions: nodes,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: maps the location of each logical partition in the `Extended` partitions
+    /// among `members` to the location of its `Extended Boot Record`, in bytes.
+    fn ebr_locations(probe: &Probe, members: &[libblkid::blkid_partition]) -> BTreeMap<u64, u64> {
+        let sector_size = probe.device_logical_sector_size() as u64;
+        let file = probe.device_file();
+        // Partition locations are relative to the scanned segment.
+        let segment = probe.scanned_device_segment_location();
+
+        members
+            .iter()
+            .map(|&ptr| Partition::new(probe, ptr))
+            .filter(|partition| partition.is_extended())
+            .flat_map(|extended| {
+                let start = extended.location_in_bytes() / sector_size;
+                read_ebr_chain(
+                    |sector| read_sector(file, segment, sector, sector_size),
+                    start,
+                )
+            })
+            .map(|(logical, ebr)| (logical * sector_size, ebr * sector_size))
+            .collect()
+    }
+
+    /// Returns the partition table.
+    pub fn table(&self) -> &PartitionTable<'a> {
+        &self.table
+    }
+
+    /// Returns the partition table's type.
+    pub fn partition_table_type(&self) -> Option<PartitionTableType> {
+        self.table.partition_table_type()
+    }
+
+    /// Returns the partition table's location, in bytes, with respect to the beginning of the
+    /// device (unlike [`PartitionTable::location_in_bytes`] which, for nested partition tables,
+    /// is relative to the parent partition).
+    pub fn location_in_bytes(&self) -> Option<u64> {
+        self.location
+    }
+
+    /// Returns the partitions owned by this table, in table order.
+    pub fn partitions(&self) -> &[PartitionNode<'a>] {
+        &self.partitions
+    }
+
+    /// Returns an iterator over the partition tables directly nested in this table's partitions.
+    pub fn nested_tables(&self) -> impl Iterator<Item = &PartitionTableNode<'a>> {
+        self.partitions.iter().filter_map(|p| p.nested_table())
+    }
+
+    /// Returns the depth of this node's subtree, `1` for a table without nested partition tables.
+    pub fn depth(&self) -> usize {
+        1 + self
+            .nested_tables()
+            .map(|table| table.depth())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[doc(hidden)]
+/// Helper function: reads the first 512 bytes of `sector` from `file`, counting sectors from
+/// the segment starting `segment` bytes into the file.
+fn read_sector(file: &File, segment: u64, sector: u64, sector_size: u64) -> Option<[u8; 512]> {
+    let mut buffer = [0u8; 512];
+    let offset = sector
+        .checked_mul(sector_size)
+        .and_then(|offset| offset.checked_add(segment))?;
+
+    file.read_exact_at(&mut buffer, offset)
+        .map_err(|e| {
+            log::debug!(
+                "PartitionTableNode::read_sector failed to read sector {:?}. {:?}",
+                sector,
+                e
+            )
+        })
+        .ok()
+        .map(|_| buffer)
+}
+
+#[doc(hidden)]
+/// Helper function: follows the chain of `Extended Boot Record`s of the `Extended` partition
+/// starting at sector `extended_start`, and returns pairs of (logical partition start, EBR
+/// location) in sectors.
+fn read_ebr_chain<F>(mut read: F, extended_start: u64) -> Vec<(u64, u64)>
+where
+    F: FnMut(u64) -> Option<[u8; 512]>,
+{
+    let mut chain = vec![];
+    let mut visited = HashSet::new();
+    let mut current = extended_start;
+
+    while chain.len() < MAX_EBR_CHAIN_LENGTH && visited.insert(current) {
+        let ebr = match read(current) {
+            Some(ebr) if ebr[510..512] == [0x55, 0xaa] => ebr,
+            _otherwise => break,
+        };
+
+        let mut next = None;
+        for entry in ebr[446..510].chunks_exact(16) {
+            let os_type = entry[4];
+            let start = u32::from_le_bytes([entry[8], entry[9], entry[10], entry[11]]) as u64;
+            let size = u32::from_le_bytes([entry[12], entry[13], entry[14], entry[15]]);
+
+            if os_type == 0 || size == 0 {
+                continue;
+            }
+
+            let is_extended = OSType::try_from(os_type).is_ok_and(|t| t.is_extended());
+            if is_extended {
+                // Links are relative to the start of the outermost Extended partition.
+                next.get_or_insert(extended_start + start);
+            } else {
+                // Logical partitions are relative to the EBR describing them.
+                chain.push((current + start, current));
+            }
+        }
+
+        match next {
+            Some(sector) => current = sector,
+            None => break,
+        }
+    }
+
+    chain
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn ebr(entries: &[(u8, u32, u32)]) -> [u8; 512] {
+        let mut sector = [0u8; 512];
+        for (i, (os_type, start, size)) in entries.iter().enumerate() {
+            let entry = &mut sector[446 + i * 16..446 + (i + 1) * 16];
+            entry[4] = *os_type;
+            entry[8..12].copy_from_slice(&start.to_le_bytes());
+            entry[12..16].copy_from_slice(&size.to_le_bytes());
+        }
+        sector[510] = 0x55;
+        sector[511] = 0xaa;
+
+        sector
+    }
+
+    #[test]
+    fn read_ebr_chain_follows_links_to_the_next_ebr() {
+        // Extended partition at sector 4096, with three logical partitions.
+        let disk: BTreeMap<u64, [u8; 512]> = BTreeMap::from([
+            (4096, ebr(&[(0x83, 2048, 8192), (0x05, 10240, 10240)])),
+            (14336, ebr(&[(0x83, 2048, 8192), (0x05, 20480, 10240)])),
+            (24576, ebr(&[(0x82, 2048, 8192)])),
+        ]);
+
+        let actual = read_ebr_chain(|sector| disk.get(&sector).copied(), 4096);
+        let expected = vec![(6144, 4096), (16384, 14336), (26624, 24576)];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn read_ebr_chain_stops_on_invalid_ebrs_and_loops() {
+        let mut invalid = ebr(&[(0x83, 2048, 8192)]);
+        invalid[511] = 0;
+        let disk = BTreeMap::from([(4096, invalid)]);
+
+        let actual = read_ebr_chain(|sector| disk.get(&sector).copied(), 4096);
+        assert!(actual.is_empty());
+
+        // Second EBR links back to the first one.
+        let disk = BTreeMap::from([
+            (4096, ebr(&[(0x83, 2048, 8192), (0x0f, 10240, 10240)])),
+            (14336, ebr(&[(0x83, 2048, 8192), (0x05, 0, 10240)])),
+        ]);
+
+        let actual = read_ebr_chain(|sector| disk.get(&sector).copied(), 4096);
+        let expected = vec![(6144, 4096), (16384, 14336)];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn read_sector_reads_relative_to_the_scanned_segment() {
+        let segment = 1024 * 1024;
+        let sector = ebr(&[(0x83, 2048, 8192)]);
+
+        let file = tempfile::tempfile().unwrap();
+        file.set_len(2 * segment).unwrap();
+        file.write_all_at(&sector, segment + 4 * 512).unwrap();
+
+        assert_eq!(read_sector(&file, segment, 4, 512), Some(sector));
+        assert_eq!(read_sector(&file, 0, 4, 512), Some([0u8; 512]));
+        assert_eq!(read_sector(&file, segment, u64::MAX, 512), None);
+    }
+}
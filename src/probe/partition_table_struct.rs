@@ -7,9 +7,12 @@
 use std::mem::MaybeUninit;
 
 // From this library
+use crate::core::partition::LayoutGeometry;
+use crate::core::partition::LayoutReport;
+use crate::core::partition::PartitionExtent;
 use crate::core::partition::PartitionTableType;
 use crate::ffi_utils;
-use crate::probe::{Partition, Probe};
+use crate::probe::{Partition, Probe, Topology};
 
 /// A device's partition table.
 #[derive(Debug)]
@@ -133,6 +136,77 @@ pub fn partition_table_type(&self) -> Option<PartitionTableType> {
 
         table_type
     }
+
+    /// Checks the layout of the partitions in this table against the device's [`Topology`], and
+    /// reports:
+    /// - unallocated gaps, at least as large as the allocation grain (`1 MiB`, or the device's
+    /// optimal I/O size if larger),
+    /// - overlapping partitions,
+    /// - partitions extending past the end of the device,
+    /// - partitions not aligned on a physical sector, or an optimal I/O size boundary (taking the
+    /// device's alignment offset into account),
+    /// - partitions located before their predecessor in the table.
+    ///
+    /// **Note:** logical partitions are not reported as overlapping their `Extended` container,
+    /// and partitions of a nested partition table (e.g. `Solaris`) may be located relative to
+    /// their parent partition.
+    ///
+    /// ```ignore
+    /// use rsblkid::core::partition::Severity;
+    /// use rsblkid::probe::{Probe, ScanResult};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .scan_device_superblocks(false)
+    ///         .scan_device_partitions(true)
+    ///         .scan_device_topology(true)
+    ///         .build()?;
+    ///
+    ///     if probe.find_device_properties() == ScanResult::FoundProperties {
+    ///         let topology = probe.topology()?;
+    ///
+    ///         if let Some(table) = probe.iter_partitions().partition_table() {
+    ///             let report = table.analyze(&topology);
+    ///
+    ///             for finding in report.findings_with_severity(Severity::Warning) {
+    ///                 eprintln!("{}: {}", finding.severity(), finding);
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn analyze(&self, topology: &Topology) -> LayoutReport {
+        log::debug!("PartitionTable::analyze analysing partition table layout");
+
+        let extents: Vec<_> = self
+            .marker
+            .iter_partitions()
+            .filter(|partition| {
+                partition
+                    .partition_table()
+                    .is_some_and(|table| table.ptr == self.ptr)
+            })
+            .map(|partition| PartitionExtent {
+                number: partition.number(),
+                start: partition.location_in_sectors(),
+                size: partition.size_in_sectors(),
+                is_extended: partition.is_extended(),
+                is_logical: partition.is_logical(),
+            })
+            .collect();
+
+        let geometry = LayoutGeometry {
+            device_size: self.marker.device_size_in_sectors(),
+            alignment_offset: topology.alignment_offset_in_bytes(),
+            physical_sector_size: topology.physical_sector_size(),
+            optimal_io_size: topology.optimal_io_size(),
+        };
+
+        LayoutReport::analyze(&extents, &geometry)
+    }
 }
 
 impl<'a> PartialEq for PartitionTable<'a> {
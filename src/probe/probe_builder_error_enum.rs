@@ -7,6 +7,8 @@
 // From standard library
 
 // From this library
+use crate::core::errors::ErrorKind;
+
 use crate::probe::ProbeError;
 
 /// [`ProbeBuilder`](crate::probe::ProbeBuilder) runtime errors.
@@ -25,3 +27,13 @@ pub enum ProbeBuilderError {
     #[error("{}", .0)]
     Required(String),
 }
+
+impl ProbeBuilderError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::ProbeBuild(e) => e.kind(),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
@@ -8,6 +8,11 @@
 
 // From this library
 use crate::core::errors::ConversionError;
+use crate::core::errors::DeviceError;
+use crate::core::errors::EncodeError;
+use crate::core::errors::ErrorKind;
+use crate::core::errors::GptError;
+use crate::core::errors::TableWriterError;
 use crate::probe::TopologyError;
 
 /// [`Probe`](crate::probe::Probe) runtime errors.
@@ -26,10 +31,22 @@ pub enum ProbeError {
     #[error("{0}")]
     Conversion(#[from] ConversionError),
 
+    /// Error caused by the state of a device, or reported by `libblkid`.
+    #[error(transparent)]
+    Device(#[from] DeviceError),
+
+    /// Error while encoding a device property value.
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+
     /// Error while deleting a device properties.
     #[error("{}", .0)]
     DeleteProperty(String),
 
+    /// Error while reading, or writing, a GUID Partition Table.
+    #[error(transparent)]
+    Gpt(#[from] GptError),
+
     /// Error while performing Input/Output operations.
     #[error(transparent)]
     IoError(#[from] std::io::Error),
@@ -41,6 +58,24 @@ pub enum ProbeError {
     #[error("{}", .0)]
     Search(String),
 
+    /// Error while creating, or editing, a partition table.
+    #[error(transparent)]
+    TableWriter(#[from] TableWriterError),
+
     #[error(transparent)]
     Topology(#[from] TopologyError),
 }
+
+impl ProbeError {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Device(e) => e.kind(),
+            Self::Gpt(e) => e.kind(),
+            Self::IoError(e) => ErrorKind::from(e),
+            Self::IoWrite(_) => ErrorKind::ReadOnly,
+            Self::TableWriter(e) => e.kind(),
+            _otherwise => ErrorKind::Other,
+        }
+    }
+}
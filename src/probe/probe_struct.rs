@@ -8,27 +8,49 @@
 use std::fs::{File, OpenOptions};
 use std::mem::MaybeUninit;
 use std::os::fd::AsRawFd;
-use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::fs::{FileExt, FileTypeExt, OpenOptionsExt};
 use std::path::Path;
 
 // From this library
+use crate::core::device::Label;
+use crate::core::device::Name;
 use crate::core::device::Tag;
 use crate::core::device::TagName;
 use crate::core::device::Usage;
+use crate::core::device::Uuid;
 use crate::core::errors::ConversionError;
+use crate::core::errors::DeviceError;
 
+use crate::core::partition::Codepage;
+use crate::core::partition::DecodedLabel;
+use crate::core::partition::Endian;
 use crate::core::partition::FileSystem;
+use crate::core::partition::GptMbrConsistency;
+use crate::core::partition::LabelEncoding;
+use crate::core::partition::PartitionBitflags;
 use crate::core::partition::PartitionTableType;
+use crate::core::partition::PartitionType;
 use crate::core::partition::RawBytes;
+use crate::core::utils::gpt;
+use crate::core::utils::gpt::GptLocation;
+use crate::core::utils::gpt::GptRepairPlan;
+use crate::core::utils::gpt::GptReport;
+use crate::core::utils::table_writer::PartitionTableWriter;
 
 use crate::probe::Filter;
 use crate::probe::FsProperty;
 use crate::probe::IoHint;
 use crate::probe::PartitionIter;
 use crate::probe::PartitionScanningOption;
+use crate::probe::PartitionTableNode;
 use crate::probe::PrbBuilder;
 use crate::probe::ProbeBuilder;
 use crate::probe::ProbeError;
+use crate::probe::PropertyIter;
+use crate::probe::RecoveryCandidate;
+use crate::probe::RecoveryProgress;
+use crate::probe::RecoveryReport;
+use crate::probe::ScanOutcome;
 use crate::probe::ScanResult;
 use crate::probe::TagIter;
 use crate::probe::Topology;
@@ -100,6 +122,7 @@ fn set_device(
     ) -> Result<(), ProbeError> {
         log::debug!("Probe::set_device setting device to scan");
 
+        ffi_utils::clear_errno();
         let result = unsafe {
             libblkid::blkid_probe_set_device(ptr, file.as_raw_fd(), location as i64, size as i64)
         };
@@ -111,6 +134,7 @@ fn set_device(
                 Ok(())
             }
             code => {
+                let err = DeviceError::libblkid_failure("blkid_probe_set_device", code);
                 let err_msg = "failed to set device to scan".to_owned();
                 log::debug!(
                         "Probe::set_device {}. libblkid::blkid_probe_set_device returned error code {:?}",
@@ -118,7 +142,7 @@ fn set_device(
                         code
                     );
 
-                Err(ProbeError::Config(err_msg))
+                Err(ProbeError::from(err))
             }
         }
     }
@@ -149,9 +173,10 @@ pub(crate) fn new_read_only<T>(
         let file = OpenOptions::new()
             .read(true)
             .custom_flags(status_flags)
-            .open(file_name)?;
+            .open(file_name)
+            .map_err(|e| DeviceError::from_io(file_name, e))?;
 
-        Self::new(file, scan_segment, false)
+        Self::new(file, scan_segment, true)
     }
 
     #[doc(hidden)]
@@ -180,7 +205,8 @@ pub(crate) fn new_read_write<T>(
         let file = OpenOptions::new()
             .read(true)
             .custom_flags(status_flags)
-            .open(file_name)?;
+            .open(file_name)
+            .map_err(|e| DeviceError::from_io(file_name, e))?;
 
         let mut probe = Self::new(file, scan_segment, false)?;
         // Required if we want to erase device properties on device or in memory
@@ -560,6 +586,91 @@ fn to_scan_result(returned_code: i32, libblkid_fn_name: &str, fn_name: &str) ->
         }
     }
 
+    #[doc(hidden)]
+    /// Converts a return code to a `Result<ScanOutcome, ProbeError>`.
+    ///
+    /// **Note:** must be called right after `libblkid_fn_name`, before `errno` is overwritten.
+    ///
+    /// # Arguments
+    ///
+    /// - `returned_code` -- code returned by any of the `libblkid::blkid_do_*` functions.
+    /// - `libblkid_fn_name` -- libblkid function name (e.g. `blkid_do_fullprobe`).
+    /// - `fn_name` -- equivalent rsblkid fully-qualified function name (e.g. `Probe::try_find_device_properties`).
+    fn to_scan_outcome(
+        &mut self,
+        returned_code: i32,
+        libblkid_fn_name: &'static str,
+        fn_name: &str,
+    ) -> Result<ScanOutcome, ProbeError> {
+        // Capture `errno` before it is overwritten.
+        let err = DeviceError::libblkid_failure(libblkid_fn_name, returned_code);
+        let result = Self::to_scan_result(returned_code, libblkid_fn_name, fn_name);
+
+        match ScanOutcome::from_scan_result(result, || self.conflicting_candidates()) {
+            Some(res) => {
+                log::debug!("{} returned {:?}", fn_name, res);
+
+                Ok(res)
+            }
+            None => {
+                log::debug!(
+                    "{} failed to scan device. libblkid::{} returned error code {:?}",
+                    fn_name,
+                    libblkid_fn_name,
+                    returned_code
+                );
+
+                Err(ProbeError::from(err))
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: runs each search function one after the other, and collects the type of
+    /// every signature found on the device.
+    ///
+    /// Called when `libblkid` detects conflicting values during a scan; in that case, device
+    /// properties are discarded by `libblkid`. The `Probe` is reset before and after the
+    /// collection.
+    fn conflicting_candidates(&mut self) -> Vec<Tag> {
+        log::debug!("Probe::conflicting_candidates collecting conflicting signatures");
+
+        unsafe { libblkid::blkid_reset_probe(self.inner) }
+
+        let mut candidates = Vec::new();
+        while unsafe { libblkid::blkid_do_probe(self.inner) } == libblkid::BLKID_PROBE_OK {
+            // `libblkid` keeps the values of a category when moving onto the next one. Since
+            // partition tables are searched after file systems, a `PTTYPE` value means the last
+            // match is a partition table.
+            let tag_name = if self.device_property_has_value(&TagName::PtType) {
+                TagName::PtType
+            } else {
+                TagName::Type
+            };
+
+            if let Some(value) = self.lookup_device_property_value(&tag_name) {
+                match Tag::try_from((tag_name, value.as_bytes())) {
+                    Ok(tag) if !candidates.contains(&tag) => candidates.push(tag),
+                    Ok(_) => {}
+                    Err(e) => log::debug!(
+                        "Probe::conflicting_candidates ignoring candidate {:?}={:?}. {}",
+                        tag_name,
+                        value,
+                        e
+                    ),
+                }
+            }
+        }
+
+        unsafe { libblkid::blkid_reset_probe(self.inner) }
+        log::debug!(
+            "Probe::conflicting_candidates conflicting signatures: {:?}",
+            candidates
+        );
+
+        candidates
+    }
+
     /// Runs search functions for device properties, collects data from the first match in a
     /// requested category, then moves onto the next (as described in the
     /// [overview](crate::probe#overview)) of the `probe` module.
@@ -671,6 +782,39 @@ pub fn find_device_properties(&mut self) -> ScanResult {
         )
     }
 
+    /// Same as [`Probe::find_device_properties`], except scan failures are reported as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::{Probe, ScanOutcome};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .build()?;
+    ///
+    ///     if probe.try_find_device_properties()? == ScanOutcome::FoundProperties {
+    ///         for property in probe.iter_device_properties() {
+    ///             println!("{property}")
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_find_device_properties(&mut self) -> Result<ScanOutcome, ProbeError> {
+        log::debug!("Probe::try_find_device_properties collecting all device properties");
+
+        ffi_utils::clear_errno();
+        let rc = unsafe { libblkid::blkid_do_fullprobe(self.inner) };
+        self.to_scan_outcome(
+            rc,
+            "blkid_do_fullprobe",
+            "Probe::try_find_device_properties",
+        )
+    }
+
     /// Follows the same process as [`Probe::find_device_properties`]. However, instead of moving
     /// onto the next category after finding a match, this method continues to run the remaining
     /// non-executed search functions in each category, telling the caller about any data collision
@@ -785,6 +929,57 @@ pub fn find_all_device_properties(&mut self) -> ScanResult {
         )
     }
 
+    /// Same as [`Probe::find_all_device_properties`], except scan failures are reported as
+    /// errors.
+    ///
+    /// When several search functions in the same category find conflicting values, this method
+    /// returns a [`ScanOutcome::ConflictingValues`] listing the type of each conflicting
+    /// signature.
+    ///
+    /// **Note:** to list conflicting candidates, the `Probe` runs each search function again,
+    /// then resets its state. Candidates are identified by their `TYPE` or `PTTYPE` properties;
+    /// the list will be empty if the `Probe` is not configured to collect them.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::{Probe, ScanOutcome};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .build()?;
+    ///
+    ///     match probe.try_find_all_device_properties()? {
+    ///         ScanOutcome::ConflictingValues(candidates) => {
+    ///             eprintln!("conflicting signatures:");
+    ///             for candidate in candidates {
+    ///                 eprintln!("  {candidate}");
+    ///             }
+    ///         }
+    ///         ScanOutcome::FoundProperties => {
+    ///             for property in probe.iter_device_properties() {
+    ///                 println!("{property}")
+    ///             }
+    ///         }
+    ///         _ => eprintln!("could not find any supported file system properties"),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_find_all_device_properties(&mut self) -> Result<ScanOutcome, ProbeError> {
+        log::debug!("Probe::try_find_all_device_properties collecting all device properties and checking value consistencies");
+
+        ffi_utils::clear_errno();
+        let rc = unsafe { libblkid::blkid_do_safeprobe(self.inner) };
+        self.to_scan_outcome(
+            rc,
+            "blkid_do_safeprobe",
+            "Probe::try_find_all_device_properties",
+        )
+    }
+
     /// Runs sequentially each search function for device properties in the current category, until
     /// one matches. It then collects the device properties found, and saves its last position in
     /// the sequence resuming the search process on the next function call.
@@ -912,6 +1107,36 @@ pub fn run_scan(&mut self) -> ScanResult {
         Self::to_scan_result(rc, "libblkid::blkid_do_probe", "Probe::run_scan")
     }
 
+    /// Same as [`Probe::run_scan`], except scan failures are reported as errors.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::{Probe, ScanOutcome};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .build()?;
+    ///
+    ///     // Print the properties of each signature found on the device.
+    ///     while probe.try_run_scan()? == ScanOutcome::FoundProperties {
+    ///         for property in probe.iter_device_properties() {
+    ///             println!("{property}")
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_run_scan(&mut self) -> Result<ScanOutcome, ProbeError> {
+        log::debug!("Probe::try_run_scan searching for next device properties");
+
+        ffi_utils::clear_errno();
+        let rc = unsafe { libblkid::blkid_do_probe(self.inner) };
+        self.to_scan_outcome(rc, "blkid_do_probe", "Probe::try_run_scan")
+    }
+
     /// Sets the current position in the sequence of search functions to that of the one executed before last.
     ///
     /// # Examples
@@ -1148,6 +1373,7 @@ fn delete_properties(
         if !is_read_only {
             let dry_run = if is_dry_run { 1 } else { 0 };
 
+            ffi_utils::clear_errno();
             let result = unsafe { libblkid::blkid_do_wipe(ptr, dry_run) };
 
             match result {
@@ -1160,11 +1386,10 @@ fn delete_properties(
                     Ok(())
                 }
                 code => {
+                    let err = DeviceError::libblkid_failure("blkid_do_wipe", code);
                     log::debug!("Probe::delete_properties failed to delete device properties. libblkid::blkid_do_wipe returned error code {:?}", code);
 
-                    Err(ProbeError::DeleteProperty(
-                        "failed to delete device properties".to_owned(),
-                    ))
+                    Err(ProbeError::from(err))
                 }
             }
         } else {
@@ -1180,13 +1405,92 @@ pub fn iter_device_properties(&self) -> TagIter {
         TagIter::new(self)
     }
 
+    /// Returns an iterator over all the properties gathered during a block device scan.
+    ///
+    /// Yields a [`PropertyError`](crate::probe::PropertyError) for each property that can not be converted to a
+    /// [`Tag`](crate::core::device::Tag), so that no property reported by `libblkid` is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .build()?;
+    ///
+    ///     probe.try_find_device_properties()?;
+    ///
+    ///     for property in probe.try_iter_device_properties() {
+    ///         match property {
+    ///             Ok(tag) => println!("{tag}"),
+    ///             Err(e) => eprintln!("{}={:?}", e.name(), e.value()),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_iter_device_properties(&self) -> PropertyIter {
+        log::debug!("Probe::try_iter_device_properties creating a new `PropertyIter` instance");
+        PropertyIter::new(self)
+    }
+
     /// Returns the `nth` property gathered during a device scan as a [`Tag`](crate::core::device::Tag).
+    ///
+    /// Returns `None` if there is no such property, or if it can not be converted to a `Tag`.
     pub fn nth_device_property(&self, n: usize) -> Option<Tag> {
         log::debug!(
             "Probe::nth_device_property accessing device property number: {:?}",
             n
         );
-        self.iter_device_properties().nth(n)
+
+        self.nth_raw_device_property(n)
+            .and_then(|(name, value)| PropertyIter::to_tag(name, value).ok())
+    }
+
+    #[doc(hidden)]
+    /// Returns the name and value of the `nth` property gathered during a device scan.
+    pub(crate) fn nth_raw_device_property(&self, n: usize) -> Option<(String, RawBytes)> {
+        let index = i32::try_from(n).ok()?;
+        let mut name_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+        let mut value_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+
+        let result = unsafe {
+            libblkid::blkid_probe_get_value(
+                self.inner,
+                index,
+                name_ptr.as_mut_ptr(),
+                value_ptr.as_mut_ptr(),
+                std::ptr::null_mut(),
+            )
+        };
+
+        match result {
+            0 => {
+                let name_ptr = unsafe { name_ptr.assume_init() };
+                let value_ptr = unsafe { value_ptr.assume_init() };
+
+                let name = ffi_utils::const_c_char_array_to_c_str(name_ptr)
+                    .to_string_lossy()
+                    .to_string();
+                let value = RawBytes::from(ffi_utils::const_c_char_array_to_bytes(value_ptr));
+                log::debug!(
+                    "Probe::nth_raw_device_property device property {:?}: {}={:?}",
+                    n,
+                    name,
+                    value
+                );
+
+                Some((name, value))
+            }
+            code => {
+                log::debug!("Probe::nth_raw_device_property can not get device property {:?}. libblkid::blkid_probe_get_value returned error code {:?}", n, code);
+
+                None
+            }
+        }
     }
 
     /// Returns `true` if the property of a device associated with a `Probe` has a value.
@@ -1275,108 +1579,554 @@ pub fn count_device_properties(&self) -> usize {
     }
 
     #[doc(hidden)]
-    /// Activates/Deactivates file system superblock scanning.
-    fn configure_chain_superblocks(
-        ptr: libblkid::blkid_probe,
-        enable: bool,
-    ) -> Result<(), ProbeError> {
-        log::debug!("Probe::configure_chain_superblocks enable: {}", enable);
+    /// Helper function: returns the value of the device property `tag_name` as a [`Tag`], or
+    /// `None` if the property is not set or can not be converted.
+    fn lookup_device_property(&mut self, tag_name: TagName) -> Option<Tag> {
+        let value = self.lookup_device_property_value(&tag_name)?;
 
-        let operation = if enable { "enable" } else { "disable" };
-        let enable = if enable { 1 } else { 0 };
-
-        let result = unsafe { libblkid::blkid_probe_enable_superblocks(ptr, enable) };
-
-        match result {
-            0 => {
+        Tag::try_from((tag_name, value.as_bytes()))
+            .map_err(|e| {
                 log::debug!(
-                    "Probe::configure_chain_superblocks {}d superblocks chain",
-                    operation
+                    "Probe::lookup_device_property failed to convert value of device property {:?}. {}",
+                    tag_name,
+                    e
                 );
+            })
+            .ok()
+    }
 
-                Ok(())
-            }
-            code => {
-                let err_msg = format!("failed to {} superblocks scanning", operation);
-                log::debug!("Probe::configure_chain_superblocks {}. libblkid::blkid_probe_enable_superblocks returned error code {}", err_msg, code);
-
-                Err(ProbeError::Config(err_msg))
-            }
+    /// Returns the type of file system found on the device (`TYPE`).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// # use pretty_assertions::assert_eq;
+    /// use rsblkid::core::partition::FileSystem;
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         // Assuming `/dev/vda` has an ext4 file system
+    ///         .scan_device("/dev/vda")
+    ///         .build()?;
+    ///
+    ///     probe.try_find_device_properties()?;
+    ///
+    ///     let actual = probe.fs_type();
+    ///     let expected = Some(FileSystem::Ext4);
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fs_type(&mut self) -> Option<FileSystem> {
+        match self.lookup_device_property(TagName::Type)? {
+            Tag::Type(value) => Some(value),
+            _unreachable => None,
         }
     }
 
-    #[doc(hidden)]
-    /// Activate file system search functions.
-    pub(super) fn enable_chain_superblocks(&mut self) -> Result<(), ProbeError> {
-        log::debug!("Probe::enable_chain_superblocks enabling superblocks chain");
-        Self::configure_chain_superblocks(self.inner, true)
+    /// Returns the secondary file system type (`SEC_TYPE`), e.g. `ext2` for an `ext3` file system.
+    pub fn secondary_fs_type(&mut self) -> Option<FileSystem> {
+        match self.lookup_device_property(TagName::SecType)? {
+            Tag::SecType(value) => Some(value),
+            _unreachable => None,
+        }
     }
 
-    #[doc(hidden)]
-    /// Deactivate file system search functions.
-    pub(super) fn disable_chain_superblocks(&mut self) -> Result<(), ProbeError> {
-        log::debug!("Probe::disable_chain_superblocks disabling superblocks chain");
-        Self::configure_chain_superblocks(self.inner, false)
+    /// Returns the UUID of the file system found on the device (`UUID`), its format identified
+    /// according to the type of file system (see [`Uuid::for_file_system`]).
+    pub fn uuid(&mut self) -> Option<Uuid> {
+        let value = match self.lookup_device_property(TagName::Uuid)? {
+            Tag::Uuid(value) => value,
+            _unreachable => return None,
+        };
+
+        match self.fs_type() {
+            Some(file_system) => Uuid::for_file_system(value.as_bytes(), &file_system).ok(),
+            None => Uuid::try_from(value.as_bytes()).ok(),
+        }
     }
 
-    /// Returns an iterator over all file systems supported by `rsblkid`.
-    pub fn iter_supported_file_systems() -> All<FileSystem> {
-        log::debug!("Probe::iter_supported_file_systems iterating all supported file systems");
+    /// Returns the label of the file system found on the device (`LABEL`).
+    ///
+    /// **Note:** returns `None` for labels that are not valid UTF-8 (e.g. FAT labels in an OEM
+    /// code page), see [`Probe::decoded_label`].
+    pub fn label(&mut self) -> Option<Label> {
+        let value = match self.lookup_device_property(TagName::Label)? {
+            Tag::Label(value) => value,
+            _unreachable => return None,
+        };
 
-        enum_iterator::all::<FileSystem>()
+        Label::try_from(value.as_bytes())
+            .map_err(|e| {
+                log::debug!("Probe::label failed to convert label {:?}. {}", value, e);
+            })
+            .ok()
     }
 
-    /// Specifies which file systems to search for/exclude when scanning a device. By default,
-    /// a `Probe` will try to identify any of the supported [`FileSystem`]s.
+    /// Returns the label of the file system found on the device, decoded according to the file
+    /// system's type (`TYPE`).
     ///
-    /// **Warning:** Each time this method is called, [`Probe`] discards the last saved position in
-    /// the sequence of search functions. So, when [`Probe::run_scan`] is called, the search
-    /// sequence starts over instead of resuming from where it left off.
+    /// Labels are decoded from their raw on-disk bytes (`LABEL_RAW`), in the encoding
+    /// [`LabelEncoding::for_file_system`] picks for the file system (e.g. UTF-16 for `ntfs`, or
+    /// the OEM `codepage` a FAT file system was created with, since FAT does not record it).
+    /// Labels in a variety of encodings (e.g. `iso9660` with Joliet extensions, or `udf`) are
+    /// taken from `LABEL` instead, which `libblkid` converts to UTF-8.
     ///
-    /// Therefore, it is **strongly advised NOT to call** this function while **in a loop**.
+    /// **Note:** `LABEL_RAW` is only available if the `Probe` collects
+    /// [`FsProperty::LabelRaw`]. Otherwise, labels are taken from `LABEL`, as is for FAT file
+    /// systems, as UTF-8 for others.
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// use rsblkid::core::partition::FileSystem;
-    /// use rsblkid::probe::{Filter, Probe};
+    /// # use pretty_assertions::assert_eq;
+    /// use rsblkid::core::partition::{Codepage, DecodingConfidence};
+    /// use rsblkid::probe::Probe;
     ///
     /// fn main() -> rsblkid::Result<()> {
-    ///     let probe = Probe::builder()
-    ///         .scan_device("/dev/vda")
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("fat32_cp850_O_tilde.img")
     ///         .scan_device_superblocks(true)
-    ///         // Specify which file systems to search for when scanning the device, by default all
-    ///         // supported search functions are tried.
-    ///         .scan_superblocks_for_file_systems(Filter::In,
-    ///             vec![
-    ///                 FileSystem::APFS,
-    ///                 FileSystem::Ext4,
-    ///                 FileSystem::VFAT,
-    ///             ])
     ///         .build()?;
     ///
-    ///     // Do some work...
+    ///     probe.find_device_properties();
+    ///
+    ///     let decoded = probe.decoded_label(Codepage::Cp850).unwrap();
+    ///     assert_eq!(decoded.label(), "ÕÕÕ");
+    ///     assert_eq!(decoded.confidence(), DecodingConfidence::Assumed);
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn scan_superblocks_for_file_systems(
-        &mut self,
-        filter: Filter,
-        fs_types: &[FileSystem],
-    ) -> Result<(), ProbeError> {
-        log::debug!(
-            "Probe::scan_superblocks_for_file_systems scanning for superblocks with file systems {:?} [{:?}]",
-            filter,
-            fs_types
-        );
+    pub fn decoded_label(&mut self, codepage: Codepage) -> Option<DecodedLabel> {
+        let encoding = self
+            .fs_type()
+            .map(|fs_type| LabelEncoding::for_file_system(&fs_type, codepage))
+            .unwrap_or(LabelEncoding::Utf8);
+
+        let label_raw = match encoding {
+            LabelEncoding::Utf8 => None,
+            _otherwise => self.lookup_device_property_value(&TagName::LabelRaw),
+        };
 
-        // Convert each FileSystem element to CString
-        let fs_filters: Vec<_> = fs_types.iter().map(|fs| fs.to_c_string()).collect();
+        match (label_raw, encoding) {
+            (Some(label), _) => Some(label.decode_label(encoding)),
+            // `libblkid` does not convert FAT labels to UTF-8.
+            (None, LabelEncoding::Codepage(_)) => self
+                .lookup_device_property_value(&TagName::Label)
+                .map(|label| label.decode_label(encoding)),
+            (None, _) => self
+                .lookup_device_property_value(&TagName::Label)
+                .map(|label| label.decode_label(LabelEncoding::Utf8)),
+        }
+    }
 
-        // Convert each CString to a C char array
-        let mut filters: Vec<_> = fs_filters
-            .iter()
+    /// Returns the version of the file system found on the device (`VERSION`).
+    pub fn fs_version(&mut self) -> Option<Name> {
+        match self.lookup_device_property(TagName::Version)? {
+            Tag::Version(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the size in bytes of the file system found on the device (`FSSIZE`).
+    pub fn fs_size(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::FsSize)? {
+            Tag::FsSize(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the last block of the file system found on the device (`FSLASTBLOCK`).
+    pub fn fs_last_block(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::FsLastBlock)? {
+            Tag::FsLastBlock(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the block size of the file system found on the device (`BLOCK_SIZE`).
+    pub fn block_size(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::BlockSize)? {
+            Tag::BlockSize(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the minimal block size accessible by the file system (`FSBLOCKSIZE`).
+    pub fn fs_block_size(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::FsBlockSize)? {
+            Tag::FsBlockSize(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the usage of the file system found on the device (`USAGE`).
+    pub fn usage(&mut self) -> Option<Usage> {
+        match self.lookup_device_property(TagName::Usage)? {
+            Tag::Usage(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the endianness of the superblock found on the device (`ENDIANNESS`).
+    pub fn endianness(&mut self) -> Option<Endian> {
+        match self.lookup_device_property(TagName::Endianness)? {
+            Tag::Endianness(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the type of partition table found on the device (`PTTYPE`).
+    pub fn pt_type(&mut self) -> Option<PartitionTableType> {
+        match self.lookup_device_property(TagName::PtType)? {
+            Tag::PtType(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the UUID of the partition table found on the device (`PTUUID`).
+    pub fn pt_uuid(&mut self) -> Option<Uuid> {
+        match self.lookup_device_property(TagName::PtUuid)? {
+            Tag::PtUuid(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the type of the partition entry scanned (`PART_ENTRY_TYPE`).
+    pub fn part_entry_type(&mut self) -> Option<PartitionType> {
+        match self.lookup_device_property(TagName::PartEntryType)? {
+            Tag::PartEntryType(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the UUID of the partition entry scanned (`PART_ENTRY_UUID`).
+    pub fn part_entry_uuid(&mut self) -> Option<Uuid> {
+        match self.lookup_device_property(TagName::PartEntryUuid)? {
+            Tag::PartEntryUuid(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the name of the partition entry scanned (`PART_ENTRY_NAME`).
+    pub fn part_entry_name(&mut self) -> Option<Name> {
+        match self.lookup_device_property(TagName::PartEntryName)? {
+            Tag::PartEntryName(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the number of the partition entry scanned (`PART_ENTRY_NUMBER`).
+    pub fn part_entry_number(&mut self) -> Option<u32> {
+        match self.lookup_device_property(TagName::PartEntryNumber)? {
+            Tag::PartEntryNumber(value) => value.to_u32(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the location in sectors of the partition entry scanned (`PART_ENTRY_OFFSET`).
+    pub fn part_entry_offset(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::PartEntryOffset)? {
+            Tag::PartEntryOffset(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the size in sectors of the partition entry scanned (`PART_ENTRY_SIZE`).
+    pub fn part_entry_size(&mut self) -> Option<u64> {
+        match self.lookup_device_property(TagName::PartEntrySize)? {
+            Tag::PartEntrySize(value) => value.to_u64(),
+            _unreachable => None,
+        }
+    }
+
+    /// Returns the attribute flags of the partition entry scanned (`PART_ENTRY_FLAGS`).
+    pub fn part_entry_flags(&mut self) -> Option<PartitionBitflags> {
+        match self.lookup_device_property(TagName::PartEntryFlags)? {
+            Tag::PartEntryFlags(value) => Some(value),
+            _unreachable => None,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: converts a device property to the properties `systemd-udevd`'s `blkid`
+    /// builtin would emit (see the function
+    /// [`print_property`](https://github.com/systemd/systemd/blob/v255/src/udev/udev-builtin-blkid.c#L66)).
+    fn to_udev_builtin_properties(name: &str, value: &RawBytes) -> Vec<(String, String)> {
+        // `systemd-udevd` converts values in a single 256 bytes buffer, ignoring conversion
+        // errors. Conversions stop when the buffer is full, leaving the value truncated. An extra
+        // byte guards against `blkid_encode_string` writing its NUL terminator past the end.
+        const BUFFER_SIZE: usize = 256;
+        let mut buffer: [libc::c_char; BUFFER_SIZE + 1] = [0; BUFFER_SIZE + 1];
+
+        let mut value_c = value.as_bytes().to_vec();
+        // Add '\0' C string terminator.
+        value_c.push(0);
+
+        let safe = |buffer: &mut [libc::c_char; BUFFER_SIZE + 1]| {
+            let _result = unsafe {
+                libblkid::blkid_safe_string(
+                    value_c.as_ptr() as *const _,
+                    buffer.as_mut_ptr(),
+                    BUFFER_SIZE,
+                )
+            };
+
+            ffi_utils::c_char_array_to_string(buffer.as_ptr())
+        };
+        let encoded = |buffer: &mut [libc::c_char; BUFFER_SIZE + 1]| {
+            let _result = unsafe {
+                libblkid::blkid_encode_string(
+                    value_c.as_ptr() as *const _,
+                    buffer.as_mut_ptr(),
+                    BUFFER_SIZE,
+                )
+            };
+
+            ffi_utils::c_char_array_to_string(buffer.as_ptr())
+        };
+        let raw = |value: &RawBytes| value.as_str_lossy().to_owned();
+
+        match name {
+            "TYPE" => vec![("ID_FS_TYPE".to_owned(), raw(value))],
+            "USAGE" => vec![("ID_FS_USAGE".to_owned(), raw(value))],
+            "VERSION" => vec![("ID_FS_VERSION".to_owned(), raw(value))],
+            "UUID" | "UUID_SUB" | "LABEL" => vec![
+                (format!("ID_FS_{}", name), safe(&mut buffer)),
+                (format!("ID_FS_{}_ENC", name), encoded(&mut buffer)),
+            ],
+            "FSSIZE" | "FSLASTBLOCK" | "FSBLOCKSIZE" => {
+                vec![(format!("ID_FS_{}", &name[2..]), raw(value))]
+            }
+            "PTTYPE" => vec![("ID_PART_TABLE_TYPE".to_owned(), raw(value))],
+            "PTUUID" => vec![("ID_PART_TABLE_UUID".to_owned(), raw(value))],
+            "PART_ENTRY_NAME" | "PART_ENTRY_TYPE" => {
+                vec![(format!("ID_{}", name), encoded(&mut buffer))]
+            }
+            name if name.starts_with("PART_ENTRY_") => vec![(format!("ID_{}", name), raw(value))],
+            "SYSTEM_ID" | "PUBLISHER_ID" | "APPLICATION_ID" | "BOOT_SYSTEM_ID" | "VOLUME_ID"
+            | "LOGICAL_VOLUME_ID" | "VOLUME_SET_ID" | "DATA_PREPARER_ID" => {
+                vec![(format!("ID_FS_{}", name), encoded(&mut buffer))]
+            }
+            _otherwise => vec![],
+        }
+    }
+
+    /// Scans the device, then returns the properties `systemd-udevd`'s `blkid` builtin would
+    /// emit for it (`ID_FS_TYPE`, `ID_FS_UUID`, `ID_FS_UUID_ENC`, `ID_PART_TABLE_TYPE`,
+    /// `ID_PART_ENTRY_*`, etc.), in the same order.
+    ///
+    /// This method reconfigures the `Probe` the way the builtin does:
+    /// - collects file system properties `LABEL`, `UUID`, `TYPE`, `SEC_TYPE`, `USAGE`, `VERSION`
+    /// (and `FSSIZE`, `FSLASTBLOCK`, `FSBLOCKSIZE` with `libblkid` v2.39 and later),
+    /// - activates partition search functions, with [`PartitionScanningOption::EntryDetails`],
+    /// - on whole disks smaller than a floppy (1440 KiB), does not search for file systems if the
+    /// disk has a partition table.
+    ///
+    /// Scanning filters set on the `Probe` (e.g. to exclude RAID superblocks like `udev`'s
+    /// `--noraid` option) are preserved.
+    ///
+    /// **Note:** properties requiring data from outside the device (e.g.
+    /// `ID_PART_GPT_AUTO_ROOT`, which depends on EFI variables) are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda1")
+    ///         .build()?;
+    ///
+    ///     for (name, value) in probe.udev_properties()? {
+    ///         println!("{}={}", name, value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn udev_properties(&mut self) -> Result<Vec<(String, String)>, ProbeError> {
+        log::debug!("Probe::udev_properties collecting udev properties");
+
+        self.reset();
+
+        #[allow(unused_mut)]
+        let mut fs_properties = vec![
+            FsProperty::Label,
+            FsProperty::Uuid,
+            FsProperty::Type,
+            FsProperty::SecondType,
+            FsProperty::Usage,
+            FsProperty::Version,
+        ];
+        #[cfg(v2_39)]
+        fs_properties.push(FsProperty::FsInfo);
+
+        self.collect_fs_properties(&fs_properties)?;
+        self.enable_chain_partitions()?;
+
+        let is_char_device = self.file.metadata()?.file_type().is_char_device();
+        let mut has_partition_table = false;
+
+        // Same as the function `probe_superblocks` in `systemd-udevd`'s `blkid` builtin.
+        if !is_char_device && self.device_size() <= 1024 * 1440 && self.is_device_whole_disk() {
+            // Check if a small disk is partitioned. If so, do not search for file systems.
+            self.disable_chain_superblocks()?;
+
+            ffi_utils::clear_errno();
+            let rc = unsafe { libblkid::blkid_do_fullprobe(self.inner) };
+            if rc < 0 {
+                let err = DeviceError::libblkid_failure("blkid_do_fullprobe", rc);
+                log::debug!("Probe::udev_properties failed to scan device. libblkid::blkid_do_fullprobe returned error code {:?}", rc);
+
+                return Err(ProbeError::from(err));
+            }
+
+            has_partition_table = self.device_property_has_value(&TagName::PtType);
+        }
+
+        if !has_partition_table {
+            self.set_partitions_scanning_options(&[PartitionScanningOption::EntryDetails])?;
+            self.enable_chain_superblocks()?;
+
+            ffi_utils::clear_errno();
+            let rc = unsafe { libblkid::blkid_do_safeprobe(self.inner) };
+            match rc {
+                libblkid::BLKID_PROBE_AMBIGUOUS => {
+                    let err_msg = "found device properties with conflicting values".to_owned();
+                    log::debug!("Probe::udev_properties {}. libblkid::blkid_do_safeprobe returned error code {:?}", err_msg, rc);
+
+                    return Err(ProbeError::Search(err_msg));
+                }
+                rc if rc < 0 => {
+                    let err = DeviceError::libblkid_failure("blkid_do_safeprobe", rc);
+                    log::debug!("Probe::udev_properties failed to scan device. libblkid::blkid_do_safeprobe returned error code {:?}", rc);
+
+                    return Err(ProbeError::from(err));
+                }
+                _ => {}
+            }
+        }
+
+        let mut properties = Vec::new();
+        for n in 0..self.count_device_properties() {
+            if let Some((name, value)) = self.nth_raw_device_property(n) {
+                properties.extend(Self::to_udev_builtin_properties(&name, &value));
+            }
+        }
+
+        log::debug!("Probe::udev_properties udev properties: {:?}", properties);
+
+        Ok(properties)
+    }
+
+    #[doc(hidden)]
+    /// Activates/Deactivates file system superblock scanning.
+    fn configure_chain_superblocks(
+        ptr: libblkid::blkid_probe,
+        enable: bool,
+    ) -> Result<(), ProbeError> {
+        log::debug!("Probe::configure_chain_superblocks enable: {}", enable);
+
+        let operation = if enable { "enable" } else { "disable" };
+        let enable = if enable { 1 } else { 0 };
+
+        let result = unsafe { libblkid::blkid_probe_enable_superblocks(ptr, enable) };
+
+        match result {
+            0 => {
+                log::debug!(
+                    "Probe::configure_chain_superblocks {}d superblocks chain",
+                    operation
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to {} superblocks scanning", operation);
+                log::debug!("Probe::configure_chain_superblocks {}. libblkid::blkid_probe_enable_superblocks returned error code {}", err_msg, code);
+
+                Err(ProbeError::Config(err_msg))
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Activate file system search functions.
+    pub(super) fn enable_chain_superblocks(&mut self) -> Result<(), ProbeError> {
+        log::debug!("Probe::enable_chain_superblocks enabling superblocks chain");
+        Self::configure_chain_superblocks(self.inner, true)
+    }
+
+    #[doc(hidden)]
+    /// Deactivate file system search functions.
+    pub(super) fn disable_chain_superblocks(&mut self) -> Result<(), ProbeError> {
+        log::debug!("Probe::disable_chain_superblocks disabling superblocks chain");
+        Self::configure_chain_superblocks(self.inner, false)
+    }
+
+    /// Returns an iterator over all file systems supported by `rsblkid`.
+    pub fn iter_supported_file_systems() -> All<FileSystem> {
+        log::debug!("Probe::iter_supported_file_systems iterating all supported file systems");
+
+        enum_iterator::all::<FileSystem>()
+    }
+
+    /// Specifies which file systems to search for/exclude when scanning a device. By default,
+    /// a `Probe` will try to identify any of the supported [`FileSystem`]s.
+    ///
+    /// **Warning:** Each time this method is called, [`Probe`] discards the last saved position in
+    /// the sequence of search functions. So, when [`Probe::run_scan`] is called, the search
+    /// sequence starts over instead of resuming from where it left off.
+    ///
+    /// Therefore, it is **strongly advised NOT to call** this function while **in a loop**.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::partition::FileSystem;
+    /// use rsblkid::probe::{Filter, Probe};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .scan_device_superblocks(true)
+    ///         // Specify which file systems to search for when scanning the device, by default all
+    ///         // supported search functions are tried.
+    ///         .scan_superblocks_for_file_systems(Filter::In,
+    ///             vec![
+    ///                 FileSystem::APFS,
+    ///                 FileSystem::Ext4,
+    ///                 FileSystem::VFAT,
+    ///             ])
+    ///         .build()?;
+    ///
+    ///     // Do some work...
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn scan_superblocks_for_file_systems(
+        &mut self,
+        filter: Filter,
+        fs_types: &[FileSystem],
+    ) -> Result<(), ProbeError> {
+        log::debug!(
+            "Probe::scan_superblocks_for_file_systems scanning for superblocks with file systems {:?} [{:?}]",
+            filter,
+            fs_types
+        );
+
+        // Convert each FileSystem element to CString
+        let fs_filters: Vec<_> = fs_types.iter().map(|fs| fs.to_c_string()).collect();
+
+        // Convert each CString to a C char array
+        let mut filters: Vec<_> = fs_filters
+            .iter()
             .map(|str| str.as_ptr() as *mut _)
             .collect();
 
@@ -1981,6 +2731,615 @@ pub fn iter_partitions(&self) -> PartitionIter {
         PartitionIter::new(self)
     }
 
+    /// Returns the tree of partition tables found after a device scan, rooted at the device's
+    /// top-level partition table.
+    ///
+    /// Each node lists the partitions its table owns, with the partition tables nested inside
+    /// them (e.g. a `BSD disklabel` in a `DOS` primary partition), and for `DOS` logical
+    /// partitions the location of the `Extended Boot Record` describing them.
+    ///
+    /// ```ignore
+    /// use rsblkid::probe::{PartitionTableNode, Probe};
+    ///
+    /// fn print_tree(node: &PartitionTableNode, indent: usize) {
+    ///     let table_type = node.partition_table_type().map(|t| t.to_string());
+    ///     println!("{:indent$}{:?} at {:?}", "", table_type, node.location_in_bytes());
+    ///
+    ///     for child in node.partitions() {
+    ///         let partition = child.partition();
+    ///         println!("{:indent$}  #{} EBR: {:?}", "", partition.number(), child.ebr_location_in_bytes());
+    ///
+    ///         if let Some(nested) = child.nested_table() {
+    ///             print_tree(nested, indent + 4);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .scan_device_superblocks(false)
+    ///         .scan_device_partitions(true)
+    ///         .build()?;
+    ///
+    ///     probe.find_device_properties();
+    ///
+    ///     if let Some(root) = probe.partition_tree() {
+    ///         print_tree(&root, 0);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn partition_tree(&self) -> Option<PartitionTableNode> {
+        log::debug!("Probe::partition_tree building tree of partition tables");
+
+        let list = unsafe { libblkid::blkid_probe_get_partitions(self.inner) };
+        if list.is_null() {
+            log::debug!("Probe::partition_tree no partition list. libblkid::blkid_probe_get_partitions returned a NULL pointer");
+
+            return None;
+        }
+
+        let root = unsafe { libblkid::blkid_partlist_get_table(list) };
+        if root.is_null() {
+            log::debug!("Probe::partition_tree no partition table. libblkid::blkid_partlist_get_table returned a NULL pointer");
+
+            return None;
+        }
+
+        let count = unsafe { libblkid::blkid_partlist_numof_partitions(list) }.max(0);
+        let partitions: Vec<_> = (0..count)
+            .map(|i| unsafe { libblkid::blkid_partlist_get_partition(list, i) })
+            .filter(|ptr| !ptr.is_null())
+            .collect();
+
+        Some(PartitionTableNode::build(self, root, &partitions))
+    }
+
+    /// Checks whether the Master Boot Record (MBR) of a device with a GUID Partition Table (GPT)
+    /// is a valid protective MBR, or a hybrid MBR consistent with the GPT. Returns `Ok(None)` if
+    /// the last partitions scan did not find a `GPT` partition table.
+    ///
+    /// **Note:** `libblkid` ignores a GPT without a protective MBR, unless the
+    /// [`PartitionScanningOption::ForceGPT`](crate::probe::PartitionScanningOption::ForceGPT)
+    /// option is set.
+    ///
+    /// ```ignore
+    /// use rsblkid::core::partition::MbrKind;
+    /// use rsblkid::probe::{PartitionScanningOption, Probe};
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         .scan_device_superblocks(false)
+    ///         .scan_device_partitions(true)
+    ///         .partitions_scanning_options(vec![PartitionScanningOption::ForceGPT])
+    ///         .build()?;
+    ///
+    ///     probe.find_device_properties();
+    ///
+    ///     if let Some(report) = probe.gpt_mbr_consistency()? {
+    ///         if report.kind() == MbrKind::Hybrid {
+    ///             println!("hybrid MBR");
+    ///         }
+    ///
+    ///         for anomaly in report.anomalies() {
+    ///             eprintln!("{}: {}", anomaly.severity(), anomaly);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn gpt_mbr_consistency(&self) -> Result<Option<GptMbrConsistency>, ProbeError> {
+        log::debug!("Probe::gpt_mbr_consistency checking MBR against GPT");
+
+        let partitions = self.iter_partitions();
+        let is_gpt = partitions
+            .partition_table()
+            .and_then(|table| table.partition_table_type())
+            == Some(PartitionTableType::GPT);
+
+        if !is_gpt {
+            log::debug!("Probe::gpt_mbr_consistency no GPT partition table found");
+
+            return Ok(None);
+        }
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        let device_size = self.device_size() / sector_size;
+        let gpt_partitions: Vec<_> = partitions
+            .map(|partition| {
+                (
+                    partition.location_in_bytes() / sector_size,
+                    partition.size_in_bytes() / sector_size,
+                )
+            })
+            .collect();
+
+        // The MBR is on the first sector of the scanned segment.
+        let mut sector = [0u8; 512];
+        self.file
+            .read_exact_at(&mut sector, self.scanned_device_segment_location())?;
+
+        let report = GptMbrConsistency::check(&sector, device_size, &gpt_partitions);
+
+        Ok(Some(report))
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns an error if this `Probe` scans a segment of its device, instead
+    /// of the whole device.
+    fn check_whole_device(&self, operation: &str) -> Result<(), ProbeError> {
+        let device_size = gpt::file_size(&self.file)?;
+        let is_segment = self.scanned_device_segment_location() != 0
+            || self.scanned_device_segment_size() != device_size;
+
+        if is_segment {
+            let err_msg = format!(
+                "can not {}. `Probe` scans a segment of the device, not the whole device",
+                operation
+            );
+            log::debug!("Probe::check_whole_device {}", err_msg);
+
+            return Err(ProbeError::Config(err_msg));
+        }
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns an error if this `Probe` can not write to its device, i.e. if it
+    /// is in read-only mode, or if the device is in use (e.g. mounted).
+    fn check_write_access(&self, operation: &str) -> Result<(), ProbeError> {
+        if self.is_read_only {
+            let err_msg = format!("can not {}. `Probe` is in read-only mode", operation);
+            log::debug!("Probe::check_write_access {}", err_msg);
+
+            return Err(ProbeError::IoWrite(err_msg));
+        }
+
+        // Like `wipefs`, open a block device exclusively to make sure it is not mounted, or
+        // held by another subsystem (e.g. device-mapper, or MD RAID).
+        if self.file.metadata()?.file_type().is_block_device() {
+            let fd_path = format!("/proc/self/fd/{}", self.file.as_raw_fd());
+            let device_path = std::fs::read_link(&fd_path).unwrap_or_else(|_| fd_path.into());
+
+            OpenOptions::new()
+                .read(true)
+                .custom_flags(libc::O_EXCL | libc::O_CLOEXEC)
+                .open(&device_path)
+                .map_err(|e| {
+                    log::debug!(
+                        "Probe::check_write_access can not {}. Device {:?} is in use: {}",
+                        operation,
+                        device_path,
+                        e
+                    );
+
+                    DeviceError::from_io(&device_path, e)
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads and verifies the primary and backup GUID Partition Tables (GPT) of the device
+    /// associated with this `Probe`, without relying on `libblkid`.
+    ///
+    /// Unlike a partitions scan, which silently falls back to the backup GPT when the primary
+    /// is damaged, the returned [`GptReport`] lists every corruption found in either copy, and
+    /// every field on which they disagree.
+    ///
+    /// **Note:** the `Probe` must scan the whole device, not a segment of it (see
+    /// [`ProbeBuilder::scan_device_segment`](crate::probe::ProbeBuilder::scan_device_segment)).
+    ///
+    /// ```ignore
+    /// use rsblkid::core::utils::gpt::GptLocation;
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let probe = Probe::builder().scan_device("/dev/vda").build()?;
+    ///
+    ///     let report = probe.verify_gpt()?;
+    ///
+    ///     if !report.is_valid(GptLocation::Primary) {
+    ///         eprintln!("primary GPT is damaged");
+    ///     }
+    ///
+    ///     for anomaly in report.anomalies() {
+    ///         eprintln!("{}: {}", anomaly.severity(), anomaly);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn verify_gpt(&self) -> Result<GptReport, ProbeError> {
+        log::debug!("Probe::verify_gpt verifying primary and backup GPT");
+
+        self.check_whole_device("verify GPT")?;
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        let report = gpt::verify(&self.file, sector_size)?;
+
+        Ok(report)
+    }
+
+    /// Returns a dry-run preview of the writes [`Probe::repair_gpt`] would perform to rebuild
+    /// the GUID Partition Table (GPT) of the device associated with this `Probe` from its
+    /// `source` copy. Nothing is written to the device.
+    ///
+    /// **Note:** the `Probe` must scan the whole device, not a segment of it (see
+    /// [`ProbeBuilder::scan_device_segment`](crate::probe::ProbeBuilder::scan_device_segment)).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::utils::gpt::GptLocation;
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let probe = Probe::builder().scan_device("/dev/vda").build()?;
+    ///
+    ///     let plan = probe.preview_gpt_repair(GptLocation::Backup)?;
+    ///
+    ///     for action in plan.actions() {
+    ///         println!("{}", action);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn preview_gpt_repair(&self, source: GptLocation) -> Result<GptRepairPlan, ProbeError> {
+        log::debug!(
+            "Probe::preview_gpt_repair planning GPT repair from {} copy",
+            source
+        );
+
+        self.check_whole_device("repair GPT")?;
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        let plan = gpt::plan_repair(&self.file, sector_size, source)?;
+
+        Ok(plan)
+    }
+
+    /// Rebuilds the GUID Partition Table (GPT) of the device associated with this `Probe` from
+    /// its `source` copy, i.e. rewrites the other copy's header and partition entry array, and
+    /// recomputes their CRC32s. If the backup header is not on the device's last sector (e.g.
+    /// after enlarging a disk image), it is **permanently** moved there.
+    ///
+    /// Returns the [`GptRepairPlan`] that was applied. Use [`Probe::preview_gpt_repair`] for a
+    /// dry run.
+    ///
+    /// **Note:** the `Probe` must be in read/write mode (see
+    /// [`ProbeBuilder::allow_writes`](crate::probe::ProbeBuilder::allow_writes)), scan the whole
+    /// device, and the device must not be in use (e.g. mounted). Cached buffers are emptied after
+    /// the repair, so that the next scan reads the repaired GPT.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::utils::gpt::GptLocation;
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         // Open device in read/write mode.
+    ///         .allow_writes()
+    ///         .build()?;
+    ///
+    ///     let report = probe.verify_gpt()?;
+    ///
+    ///     if !report.is_valid(GptLocation::Primary) && report.is_valid(GptLocation::Backup) {
+    ///         probe.repair_gpt(GptLocation::Backup)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn repair_gpt(&mut self, source: GptLocation) -> Result<GptRepairPlan, ProbeError> {
+        log::debug!("Probe::repair_gpt repairing GPT from {} copy", source);
+
+        self.check_write_access("repair GPT")?;
+
+        let plan = self.preview_gpt_repair(source)?;
+        gpt::apply_repair(&self.file, &plan)?;
+        self.empty_buffers()?;
+
+        log::debug!("Probe::repair_gpt applied {} actions", plan.actions().len());
+
+        Ok(plan)
+    }
+
+    //---------- partition table edition functions
+
+    #[doc(hidden)]
+    /// Helper function: aligns new partitions in `table` on the device's topology, if available.
+    fn align_partition_table(&self, table: &mut PartitionTableWriter) {
+        if let Ok(topology) = self.topology() {
+            let grain = topology.optimal_io_size().max(1024 * 1024);
+            table.set_alignment(grain, topology.alignment_offset_in_bytes());
+        }
+    }
+
+    /// Creates an empty partition table of type `table_type` (`GPT` or `DOS`) for the device
+    /// associated with this `Probe`, with new partitions aligned on the device's
+    /// [`Topology`](crate::probe::Topology).
+    ///
+    /// Nothing is written to the device until the table is passed to
+    /// [`Probe::write_partition_table`].
+    ///
+    /// **Note:** the `Probe` must scan the whole device, not a segment of it (see
+    /// [`ProbeBuilder::scan_device_segment`](crate::probe::ProbeBuilder::scan_device_segment)).
+    pub fn new_partition_table(
+        &self,
+        table_type: PartitionTableType,
+    ) -> Result<PartitionTableWriter, ProbeError> {
+        log::debug!(
+            "Probe::new_partition_table creating a new {} partition table",
+            table_type
+        );
+
+        self.check_whole_device("create a partition table")?;
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        let device_size = self.device_size() / sector_size;
+        let mut table = PartitionTableWriter::new(table_type, device_size, sector_size)?;
+        self.align_partition_table(&mut table);
+
+        Ok(table)
+    }
+
+    /// Loads the GPT, or DOS partition table, of the device associated with this `Probe` for
+    /// editing, with new partitions aligned on the device's
+    /// [`Topology`](crate::probe::Topology).
+    ///
+    /// Nothing is written to the device until the table is passed to
+    /// [`Probe::write_partition_table`].
+    ///
+    /// **Note:** the `Probe` must scan the whole device, not a segment of it (see
+    /// [`ProbeBuilder::scan_device_segment`](crate::probe::ProbeBuilder::scan_device_segment)).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rsblkid::core::device::Label;
+    /// use rsblkid::core::partition::{Guid, PartitionType};
+    /// use rsblkid::probe::Probe;
+    ///
+    /// fn main() -> rsblkid::Result<()> {
+    ///     let mut probe = Probe::builder()
+    ///         .scan_device("/dev/vda")
+    ///         // Open device in read/write mode.
+    ///         .allow_writes()
+    ///         .build()?;
+    ///
+    ///     let mut table = probe.edit_partition_table()?;
+    ///
+    ///     // Rename, and change the type of, the second partition.
+    ///     if let Some(entry) = table.partition(2).cloned() {
+    ///         let entry = entry
+    ///             .with_partition_type(PartitionType::GPT(Guid::LinuxHome))
+    ///             .with_name("home".parse::<Label>()?);
+    ///         table.update_partition(2, entry)?;
+    ///     }
+    ///
+    ///     // Delete the third partition.
+    ///     table.delete_partition(3)?;
+    ///
+    ///     probe.write_partition_table(&table)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn edit_partition_table(&self) -> Result<PartitionTableWriter, ProbeError> {
+        log::debug!("Probe::edit_partition_table loading partition table");
+
+        self.check_whole_device("edit partition table")?;
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        let mut table = PartitionTableWriter::from_device(&self.file, sector_size)?;
+        self.align_partition_table(&mut table);
+
+        Ok(table)
+    }
+
+    /// Writes `table` to the device associated with this `Probe`, **permanently** replacing its
+    /// partition table.
+    ///
+    /// **Note:** the `Probe` must be in read/write mode (see
+    /// [`ProbeBuilder::allow_writes`](crate::probe::ProbeBuilder::allow_writes)), scan the whole
+    /// device, and the device must not be in use (e.g. mounted). Cached buffers are emptied after
+    /// writing, so that the next partitions scan reads the new table. The kernel is not notified
+    /// of the change.
+    pub fn write_partition_table(
+        &mut self,
+        table: &PartitionTableWriter,
+    ) -> Result<(), ProbeError> {
+        log::debug!(
+            "Probe::write_partition_table writing {} partition table",
+            table.table_type()
+        );
+
+        self.check_write_access("write partition table")?;
+        self.check_whole_device("write partition table")?;
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        if table.sector_size() != sector_size {
+            let err_msg = format!(
+                "can not write partition table. Sector size mismatch: table {} bytes, device {} bytes",
+                table.sector_size(),
+                sector_size
+            );
+            log::debug!("Probe::write_partition_table {}", err_msg);
+
+            return Err(ProbeError::Config(err_msg));
+        }
+
+        table.write(&self.file)?;
+        self.empty_buffers()?;
+
+        log::debug!("Probe::write_partition_table wrote partition table");
+
+        Ok(())
+    }
+
+    /// Slides a window across the device associated with this `Probe`, in increments of `step`
+    /// bytes, searching for file system superblocks left behind by a lost, or destroyed,
+    /// partition table.
+    ///
+    /// `step` must be a non-zero multiple of the device's logical sector size. A step of one
+    /// sector finds every file system, at the cost of a longer scan; a step matching the
+    /// partitioning tool's alignment (usually 1 MiB) is enough for most modern disks.
+    ///
+    /// When a file system reports its extent (`FSLASTBLOCK` and `FSBLOCKSIZE`), or its size
+    /// (`FSSIZE`), the scan resumes after its end, so that backup superblocks inside it are not
+    /// reported as separate file systems. Both require `libblkid` v2.39 or later. Locations
+    /// that can not be read are skipped.
+    ///
+    /// Each window extends to the end of the device. RAID members `libblkid` identifies without
+    /// a superblock magic at a fixed offset from the window's start (e.g. `mdraid`, or firmware
+    /// RAID metadata, looked for at the end of the device) would be found from every location,
+    /// they are ignored. RAID members with a magic (e.g. `LVM` physical volumes) are reported.
+    ///
+    /// `progress` is called after each step, with the number of bytes scanned so far.
+    ///
+    /// Use [`RecoveryReport::to_partition_table`] on the result to propose a new partition
+    /// table, then [`Probe::write_partition_table`] to restore it.
+    pub fn scan_lost_partitions<F>(
+        &self,
+        step: u64,
+        mut progress: F,
+    ) -> Result<RecoveryReport, ProbeError>
+    where
+        F: FnMut(&RecoveryProgress),
+    {
+        log::debug!(
+            "Probe::scan_lost_partitions scanning device in steps of {} bytes",
+            step
+        );
+
+        let sector_size = self.device_logical_sector_size() as u64;
+        if step == 0 || step % sector_size != 0 {
+            let err_msg = format!(
+                "invalid scan step: {} bytes. Expected a non-zero multiple of the sector size ({} bytes)",
+                step, sector_size
+            );
+            log::debug!("Probe::scan_lost_partitions {}", err_msg);
+
+            return Err(ProbeError::Config(err_msg));
+        }
+
+        let base = self.scanned_device_segment_location();
+        let total = self.device_size();
+
+        // Scan with a separate probe, to leave this one's configuration and results untouched.
+        let file = self.file.try_clone()?;
+        let mut scanner = Self::new(file, (base, total), true)?;
+
+        #[allow(unused_mut)]
+        let mut fs_properties = vec![
+            FsProperty::Type,
+            FsProperty::Uuid,
+            FsProperty::Usage,
+            FsProperty::Magic,
+        ];
+        #[cfg(v2_39)]
+        fs_properties.push(FsProperty::FsInfo);
+
+        scanner.collect_fs_properties(&fs_properties)?;
+        scanner.disable_chain_partitions()?;
+
+        let mut candidates = Vec::new();
+        let mut offset = 0;
+
+        while offset < total {
+            Self::set_device(
+                scanner.inner,
+                &mut scanner.file,
+                base + offset,
+                total - offset,
+            )?;
+            let mut next = offset.saturating_add(step);
+
+            match scanner.try_find_device_properties() {
+                Ok(outcome) if outcome.found_properties() => {
+                    if let Some(file_system) = scanner.fs_type() {
+                        let magic_offset =
+                            match scanner.lookup_device_property(TagName::SbMagicOffset) {
+                                Some(Tag::SbMagicOffset(value)) => value.to_u64(),
+                                _otherwise => None,
+                            };
+                        let is_trailing_raid =
+                            scanner.usage() == Some(Usage::Raid) && magic_offset.is_none();
+
+                        if is_trailing_raid {
+                            log::debug!(
+                                "Probe::scan_lost_partitions skipping {} RAID signature seen from byte {}",
+                                file_system,
+                                offset
+                            );
+                        } else {
+                            let candidate = RecoveryCandidate {
+                                file_system,
+                                location: offset,
+                                size: scanner.fs_size(),
+                                last_block: scanner.fs_last_block(),
+                                block_size: scanner.fs_block_size(),
+                                uuid: scanner.uuid(),
+                            };
+                            log::debug!(
+                                "Probe::scan_lost_partitions found {} at byte {} (size: {:?})",
+                                candidate.file_system(),
+                                offset,
+                                candidate.size_in_bytes()
+                            );
+
+                            // Resume the scan at the first step after the end of the file system.
+                            let end = candidate.extent_in_bytes().or(candidate.size_in_bytes());
+                            if let Some(size) = end.filter(|&size| size > 0) {
+                                let steps = (size - 1) / step + 1;
+                                next = offset.saturating_add(steps.saturating_mul(step));
+                            }
+
+                            candidates.push(candidate);
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::debug!(
+                        "Probe::scan_lost_partitions skipping byte {}: {}",
+                        offset,
+                        e
+                    );
+                }
+            }
+
+            offset = next.min(total);
+            progress(&RecoveryProgress {
+                scanned: offset,
+                total,
+                candidates: candidates.len(),
+            });
+        }
+
+        log::debug!(
+            "Probe::scan_lost_partitions found {} file systems",
+            candidates.len()
+        );
+
+        Ok(RecoveryReport {
+            device_size: total,
+            sector_size,
+            step,
+            candidates,
+        })
+    }
+
     //---------- device topology search functions
 
     #[doc(hidden)]
@@ -2055,6 +3414,15 @@ pub fn topology(&self) -> Result<Topology, ProbeError> {
     }
 }
 
+// SAFETY: a `blkid_probe` owns all of its resources (file descriptor, read buffers, value
+// lists), and `libblkid` keeps no per-thread state about it. It is therefore safe to move a
+// `Probe` to another thread. Objects borrowing a `Probe` (`Partition`, `Topology`, ...) can not
+// outlive it, so they can not be left behind on the original thread.
+//
+// A `Probe` is NOT `Sync`; `libblkid` does not synchronize concurrent calls on the same
+// `blkid_probe`.
+unsafe impl Send for Probe {}
+
 impl Drop for Probe {
     fn drop(&mut self) {
         log::debug!("Probe:: deallocating probe instance");
@@ -2069,6 +3437,55 @@ mod tests {
     use super::*;
     use pretty_assertions::{assert_eq, assert_ne};
 
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn probe_is_send() {
+        assert_send::<Probe>();
+    }
+
+    #[test]
+    fn probe_converts_device_properties_to_udev_builtin_properties() {
+        let value = RawBytes::from(b"my label");
+        let actual = Probe::to_udev_builtin_properties("LABEL", &value);
+        let expected = vec![
+            ("ID_FS_LABEL".to_owned(), "my_label".to_owned()),
+            ("ID_FS_LABEL_ENC".to_owned(), r"my\x20label".to_owned()),
+        ];
+        assert_eq!(actual, expected);
+
+        let value = RawBytes::from(b"1048576");
+        let actual = Probe::to_udev_builtin_properties("FSSIZE", &value);
+        let expected = vec![("ID_FS_SIZE".to_owned(), "1048576".to_owned())];
+        assert_eq!(actual, expected);
+
+        let value = RawBytes::from(b"0x80");
+        let actual = Probe::to_udev_builtin_properties("PART_ENTRY_FLAGS", &value);
+        let expected = vec![("ID_PART_ENTRY_FLAGS".to_owned(), "0x80".to_owned())];
+        assert_eq!(actual, expected);
+
+        let value = RawBytes::from(b"ext4");
+        let actual = Probe::to_udev_builtin_properties("SBMAGIC", &value);
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn probe_truncates_udev_builtin_properties_to_fit_a_256_bytes_buffer() {
+        let value = RawBytes::from("x".repeat(300));
+        let actual = Probe::to_udev_builtin_properties("LABEL", &value);
+        assert_eq!(actual.len(), 2);
+
+        for (_, value) in actual {
+            assert!(!value.is_empty() && value.len() < 256);
+            assert!(value.bytes().all(|b| b == b'x'));
+        }
+
+        // Values passed through are not truncated.
+        let actual = Probe::to_udev_builtin_properties("TYPE", &value);
+        let expected = vec![("ID_FS_TYPE".to_owned(), "x".repeat(300))];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     #[should_panic(expected = "one of the options `scan_device` or `scan_file` must be set")]
     fn probe_one_of_scan_device_or_scan_file_must_be_set() {
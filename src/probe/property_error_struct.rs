@@ -0,0 +1,52 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use thiserror::Error;
+
+// From standard library
+
+// From this library
+use crate::core::errors::ConversionError;
+use crate::core::partition::RawBytes;
+
+/// Device property reported by `libblkid` that could not be converted to a
+/// [`Tag`](crate::core::device::Tag).
+///
+/// Either `libblkid` reported a property `rsblkid` does not know about (e.g. a property added in a
+/// newer version of `libblkid`), or the property's value could not be parsed.
+#[derive(Debug, Error)]
+#[error("invalid device property {}={}. {source}", .name, .value.as_str_safe())]
+pub struct PropertyError {
+    name: String,
+    value: RawBytes,
+    #[source]
+    source: ConversionError,
+}
+
+impl PropertyError {
+    #[doc(hidden)]
+    /// Creates a new `PropertyError`.
+    pub(super) fn new(name: String, value: RawBytes, source: ConversionError) -> PropertyError {
+        Self {
+            name,
+            value,
+            source,
+        }
+    }
+
+    /// Returns the name of the device property, as reported by `libblkid`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the raw value of the device property.
+    pub fn value(&self) -> &RawBytes {
+        &self.value
+    }
+
+    /// Returns the conversion error.
+    pub fn conversion_error(&self) -> &ConversionError {
+        &self.source
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::partition::RawBytes;
+use crate::probe::Probe;
+use crate::probe::PropertyError;
+
+/// Iterator over all the device properties gathered by a [`Probe`].
+///
+/// Unlike [`TagIter`](crate::probe::TagIter), a `PropertyIter` yields a [`PropertyError`] for
+/// each property that can not be converted to a [`Tag`], instead of skipping it.
+#[derive(Debug)]
+pub struct PropertyIter<'a> {
+    probe: &'a Probe,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> PropertyIter<'a> {
+    /// Create a new `PropertyIter` instance.
+    pub(super) fn new(probe: &'a Probe) -> PropertyIter<'a> {
+        log::debug!("PropertyIter::new creating a new `PropertyIter` instance");
+        let count = probe.count_device_properties();
+
+        Self {
+            probe,
+            index: 0,
+            count,
+        }
+    }
+
+    #[doc(hidden)]
+    /// Helper function: converts a raw device property to a [`Tag`].
+    pub(super) fn to_tag(name: String, value: RawBytes) -> Result<Tag, PropertyError> {
+        TagName::try_from(name.as_bytes())
+            .and_then(|tag_name| Tag::try_from((tag_name, value.as_bytes())))
+            .map_err(|e| {
+                log::debug!(
+                    "PropertyIter::to_tag failed to convert property {}={:?}. {}",
+                    name,
+                    value,
+                    e
+                );
+
+                PropertyError::new(name, value, e)
+            })
+    }
+}
+
+impl<'a> Iterator for PropertyIter<'a> {
+    type Item = Result<Tag, PropertyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("PropertyIter::next iterating next element");
+
+        while self.index < self.count {
+            let index = self.index;
+            self.index += 1;
+
+            if let Some((name, value)) = self.probe.nth_raw_device_property(index) {
+                return Some(Self::to_tag(name, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.count - self.index))
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn property_iter_can_convert_a_known_property() -> crate::Result<()> {
+        let actual = PropertyIter::to_tag("LABEL".to_owned(), RawBytes::from(b"nixos"));
+        let expected: Tag = "LABEL=nixos".parse()?;
+        assert_eq!(actual.unwrap(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn property_iter_keeps_an_unknown_property() {
+        let value = RawBytes::from(b"42");
+        let actual = PropertyIter::to_tag("UNKNOWN_PROPERTY".to_owned(), value.clone());
+
+        let err = actual.unwrap_err();
+        assert_eq!(err.name(), "UNKNOWN_PROPERTY");
+        assert_eq!(err.value(), &value);
+    }
+}
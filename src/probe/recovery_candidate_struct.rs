@@ -0,0 +1,69 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Uuid;
+use crate::core::partition::FileSystem;
+
+/// File system found by [`Probe::scan_lost_partitions`](crate::probe::Probe::scan_lost_partitions)
+/// outside of any partition table, i.e. a likely lost partition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCandidate {
+    pub(super) file_system: FileSystem,
+    pub(super) location: u64,
+    pub(super) size: Option<u64>,
+    pub(super) last_block: Option<u64>,
+    pub(super) block_size: Option<u64>,
+    pub(super) uuid: Option<Uuid>,
+}
+
+impl RecoveryCandidate {
+    /// Returns the type of file system found.
+    pub fn file_system(&self) -> &FileSystem {
+        &self.file_system
+    }
+
+    /// Returns the location of the file system, in bytes from the start of the scanned device.
+    pub fn location_in_bytes(&self) -> u64 {
+        self.location
+    }
+
+    /// Returns the size of the file system in bytes, as recorded in its superblock (`FSSIZE`).
+    /// `None` if `libblkid` does not report it for this type of file system.
+    pub fn size_in_bytes(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Returns the number of the last block of the file system (`FSLASTBLOCK`). `None` if
+    /// `libblkid` does not report it for this type of file system.
+    pub fn fs_last_block(&self) -> Option<u64> {
+        self.last_block
+    }
+
+    /// Returns the size of a file system block in bytes (`FSBLOCKSIZE`). `None` if `libblkid`
+    /// does not report it for this type of file system.
+    pub fn fs_block_size(&self) -> Option<u64> {
+        self.block_size
+    }
+
+    /// Returns the number of bytes the file system spans from its first byte, i.e.
+    /// `(FSLASTBLOCK + 1) × FSBLOCKSIZE`. `None` if either value is unknown.
+    ///
+    /// Unlike [`RecoveryCandidate::size_in_bytes`], which for some file systems excludes
+    /// metadata, the extent covers every block the file system may use.
+    pub fn extent_in_bytes(&self) -> Option<u64> {
+        let last_block = self.last_block?;
+        let block_size = self.block_size?;
+
+        last_block.checked_add(1)?.checked_mul(block_size)
+    }
+
+    /// Returns the UUID of the file system, if it has one.
+    pub fn uuid(&self) -> Option<&Uuid> {
+        self.uuid.as_ref()
+    }
+}
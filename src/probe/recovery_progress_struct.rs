@@ -0,0 +1,39 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Progress of a [`Probe::scan_lost_partitions`](crate::probe::Probe::scan_lost_partitions)
+/// scan, passed to its progress callback after each step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RecoveryProgress {
+    pub(super) scanned: u64,
+    pub(super) total: u64,
+    pub(super) candidates: usize,
+}
+
+impl RecoveryProgress {
+    /// Returns the number of bytes scanned so far.
+    pub fn scanned_bytes(&self) -> u64 {
+        self.scanned
+    }
+
+    /// Returns the total number of bytes to scan.
+    pub fn total_bytes(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of file systems found so far.
+    pub fn candidates(&self) -> usize {
+        self.candidates
+    }
+
+    /// Returns `true` when the whole device has been scanned.
+    pub fn is_done(&self) -> bool {
+        self.scanned >= self.total
+    }
+}
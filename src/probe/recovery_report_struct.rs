@@ -0,0 +1,295 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::errors::TableWriterError;
+use crate::core::partition::FileSystem;
+use crate::core::partition::Guid;
+use crate::core::partition::OSType;
+use crate::core::partition::PartitionTableType;
+use crate::core::partition::PartitionType;
+use crate::core::utils::table_writer::PartitionEntry;
+use crate::core::utils::table_writer::PartitionTableWriter;
+use crate::probe::RecoveryCandidate;
+
+/// Result of a [`Probe::scan_lost_partitions`](crate::probe::Probe::scan_lost_partitions) scan.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rsblkid::core::partition::PartitionTableType;
+/// use rsblkid::probe::Probe;
+///
+/// fn main() -> rsblkid::Result<()> {
+///     let mut probe = Probe::builder()
+///         .scan_device("/dev/vda")
+///         .allow_writes()
+///         .build()?;
+///
+///     // Scan the device in 1 MiB steps.
+///     let report = probe.scan_lost_partitions(1024 * 1024, |progress| {
+///         eprint!("\r{}/{} bytes", progress.scanned_bytes(), progress.total_bytes());
+///     })?;
+///
+///     for candidate in report.candidates() {
+///         println!(
+///             "{} at byte {} (size: {:?})",
+///             candidate.file_system(),
+///             candidate.location_in_bytes(),
+///             candidate.size_in_bytes()
+///         );
+///     }
+///
+///     // Review the proposed table before writing it.
+///     let table = report.to_partition_table(PartitionTableType::GPT)?;
+///     for entry in table.partitions() {
+///         println!("{:?}", entry);
+///     }
+///
+///     probe.write_partition_table(&table)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryReport {
+    pub(super) device_size: u64,
+    pub(super) sector_size: u64,
+    pub(super) step: u64,
+    pub(super) candidates: Vec<RecoveryCandidate>,
+}
+
+impl RecoveryReport {
+    /// Returns the size of the scanned device, in bytes.
+    pub fn device_size(&self) -> u64 {
+        self.device_size
+    }
+
+    /// Returns the size of a logical sector, in bytes.
+    pub fn sector_size(&self) -> u64 {
+        self.sector_size
+    }
+
+    /// Returns the distance between two scanned locations, in bytes.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Returns the file systems found, ordered by location.
+    pub fn candidates(&self) -> &[RecoveryCandidate] {
+        &self.candidates
+    }
+
+    /// Proposes a new partition table of type `table_type` (`GPT` or `DOS`), with a partition
+    /// for each candidate.
+    ///
+    /// Partitions start at their candidate's location. A partition ends at the end of its file
+    /// system's extent (see [`RecoveryCandidate::extent_in_bytes`]), or before the next
+    /// candidate when the extent is unknown, or exceeds the space available. A file system's
+    /// size (`FSSIZE`) may exclude some of its metadata, it is not used to shrink a partition.
+    /// Candidates that can not be placed in the table (e.g. a file system
+    /// starting on the first sector, or a fifth partition in a `DOS` table) are left out.
+    ///
+    /// The proposed table is not written to the device, see
+    /// [`Probe::write_partition_table`](crate::probe::Probe::write_partition_table).
+    pub fn to_partition_table(
+        &self,
+        table_type: PartitionTableType,
+    ) -> Result<PartitionTableWriter, TableWriterError> {
+        log::debug!(
+            "RecoveryReport::to_partition_table proposing a {} partition table for {} candidates",
+            table_type,
+            self.candidates.len()
+        );
+
+        let is_gpt = table_type == PartitionTableType::GPT;
+        let mut table = PartitionTableWriter::new(
+            table_type,
+            self.device_size / self.sector_size,
+            self.sector_size,
+        )?;
+
+        let starts: Vec<u64> = self
+            .candidates
+            .iter()
+            .map(|c| c.location_in_bytes() / self.sector_size)
+            .collect();
+
+        for (i, candidate) in self.candidates.iter().enumerate() {
+            let start = starts[i];
+            let last = starts
+                .get(i + 1)
+                .map(|next| next.saturating_sub(1))
+                .unwrap_or_else(|| table.last_usable_lba())
+                .min(table.last_usable_lba());
+
+            if start < table.first_usable_lba() || start > last {
+                log::debug!(
+                    "RecoveryReport::to_partition_table skipping {} at sector {}: outside usable area",
+                    candidate.file_system(),
+                    start
+                );
+
+                continue;
+            }
+
+            let available = last - start + 1;
+            let size = match candidate.extent_in_bytes() {
+                Some(extent) if extent > 0 => {
+                    let sectors =
+                        extent / self.sector_size + (extent % self.sector_size != 0) as u64;
+                    sectors.min(available)
+                }
+                _otherwise => available,
+            };
+
+            let partition_type = Self::partition_type(candidate.file_system(), is_gpt);
+            let entry = PartitionEntry::new(partition_type)
+                .with_start(start)
+                .with_size(size);
+
+            if let Err(e) = table.add_partition(entry) {
+                log::debug!(
+                    "RecoveryReport::to_partition_table skipping {} at sector {}: {}",
+                    candidate.file_system(),
+                    start,
+                    e
+                );
+            }
+        }
+
+        Ok(table)
+    }
+
+    #[doc(hidden)]
+    /// Helper function: returns the partition type usually assigned to a `file_system`.
+    fn partition_type(file_system: &FileSystem, is_gpt: bool) -> PartitionType {
+        match (file_system, is_gpt) {
+            (FileSystem::VFAT | FileSystem::NTFS | FileSystem::ExFAT, true) => {
+                PartitionType::GPT(Guid::WindowsBasicData)
+            }
+            (FileSystem::Swap, true) => PartitionType::GPT(Guid::LinuxSwap),
+            (FileSystem::LVM2, true) => PartitionType::GPT(Guid::LinuxLVM),
+            (FileSystem::LinuxRaid, true) => PartitionType::GPT(Guid::LinuxRaid),
+            (_otherwise, true) => PartitionType::GPT(Guid::LinuxData),
+            (FileSystem::VFAT, false) => PartitionType::MBR(OSType::W95FAT32LBA),
+            (FileSystem::NTFS | FileSystem::ExFAT, false) => {
+                PartitionType::MBR(OSType::HPFSNTFSExfat)
+            }
+            (FileSystem::Swap, false) => PartitionType::MBR(OSType::LinuxSwap),
+            (FileSystem::LVM2, false) => PartitionType::MBR(OSType::LinuxLVM),
+            (FileSystem::LinuxRaid, false) => PartitionType::MBR(OSType::LinuxRaidAuto),
+            (_otherwise, false) => PartitionType::MBR(OSType::Linux),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const MIB: u64 = 1024 * 1024;
+
+    fn candidate(
+        file_system: FileSystem,
+        location: u64,
+        size: Option<u64>,
+        extent: Option<(u64, u64)>,
+    ) -> RecoveryCandidate {
+        RecoveryCandidate {
+            file_system,
+            location,
+            size,
+            last_block: extent.map(|(last_block, _)| last_block),
+            block_size: extent.map(|(_, block_size)| block_size),
+            uuid: None,
+        }
+    }
+
+    #[test]
+    fn recovery_report_proposes_a_gpt_partition_table() -> crate::Result<()> {
+        let report = RecoveryReport {
+            device_size: 64 * MIB,
+            sector_size: 512,
+            step: MIB,
+            candidates: vec![
+                candidate(FileSystem::VFAT, MIB, Some(8 * MIB), Some((16383, 512))),
+                candidate(FileSystem::Ext4, 16 * MIB, None, None),
+                candidate(FileSystem::XFS, 32 * MIB, Some(2 * MIB), None),
+                candidate(FileSystem::Swap, 48 * MIB, None, Some((1023, 4096))),
+            ],
+        };
+
+        let table = report.to_partition_table(PartitionTableType::GPT)?;
+        let partitions = table.partitions();
+        assert_eq!(partitions.len(), 4);
+
+        assert_eq!(partitions[0].number(), Some(1));
+        assert_eq!(partitions[0].start(), Some(2048));
+        assert_eq!(partitions[0].size(), Some(16384));
+        assert_eq!(
+            partitions[0].partition_type(),
+            &PartitionType::GPT(Guid::WindowsBasicData)
+        );
+
+        // Unknown extent, the partition ends before the next candidate.
+        assert_eq!(partitions[1].start(), Some(32768));
+        assert_eq!(partitions[1].size(), Some(32768));
+        assert_eq!(
+            partitions[1].partition_type(),
+            &PartitionType::GPT(Guid::LinuxData)
+        );
+
+        // Only `FSSIZE` is known, the partition is not shrunk to it.
+        assert_eq!(partitions[2].start(), Some(65536));
+        assert_eq!(partitions[2].size(), Some(32768));
+
+        assert_eq!(partitions[3].start(), Some(98304));
+        assert_eq!(partitions[3].size(), Some(8192));
+        assert_eq!(
+            partitions[3].partition_type(),
+            &PartitionType::GPT(Guid::LinuxSwap)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn recovery_report_skips_candidates_outside_the_usable_area() -> crate::Result<()> {
+        let report = RecoveryReport {
+            device_size: 16 * MIB,
+            sector_size: 512,
+            step: 512,
+            candidates: vec![
+                candidate(FileSystem::VFAT, 0, Some(MIB), Some((2047, 512))),
+                candidate(
+                    FileSystem::Ext4,
+                    2 * MIB,
+                    Some(64 * MIB),
+                    Some((65535, 1024)),
+                ),
+            ],
+        };
+
+        let table = report.to_partition_table(PartitionTableType::DOS)?;
+        let partitions = table.partitions();
+        assert_eq!(partitions.len(), 1);
+
+        // The file system's extent exceeds the device's size, the partition ends at the last
+        // usable sector.
+        assert_eq!(partitions[0].start(), Some(4096));
+        assert_eq!(partitions[0].end(), Some(table.last_usable_lba()));
+        assert_eq!(
+            partitions[0].partition_type(),
+            &PartitionType::MBR(OSType::Linux)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::device::Tag;
+use crate::probe::ScanResult;
+
+/// Successful outcome of a device scan.
+///
+/// Returned by [`Probe::try_find_device_properties`](crate::probe::Probe::try_find_device_properties),
+/// [`Probe::try_find_all_device_properties`](crate::probe::Probe::try_find_all_device_properties),
+/// and [`Probe::try_run_scan`](crate::probe::Probe::try_run_scan). Scan failures are reported
+/// as [`ProbeError`](crate::probe::ProbeError)s instead.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ScanOutcome {
+    /// Found device properties.
+    FoundProperties,
+    /// Found no device properties.
+    NoProperties,
+    /// Found several signatures in the same category, with conflicting values. In this case,
+    /// manual intervention is advised.
+    ///
+    /// Holds the type of each conflicting candidate, as a [`Tag::Type`] for file systems, or a
+    /// [`Tag::PtType`] for partition tables.
+    ConflictingValues(Vec<Tag>),
+}
+
+impl ScanOutcome {
+    /// Returns `true` if the scan found device properties.
+    pub fn found_properties(&self) -> bool {
+        matches!(self, Self::FoundProperties)
+    }
+
+    /// Returns the conflicting candidates, if any.
+    pub fn conflicting_candidates(&self) -> &[Tag] {
+        match self {
+            Self::ConflictingValues(candidates) => candidates,
+            _otherwise => &[],
+        }
+    }
+
+    #[doc(hidden)]
+    /// Converts a [`ScanResult`] to a `ScanOutcome`, calling `candidates` to list conflicting
+    /// signatures. Returns `None` for scan failures (i.e. [`ScanResult::Error`] and
+    /// [`ScanResult::Exception`]).
+    pub(crate) fn from_scan_result<F>(result: ScanResult, candidates: F) -> Option<ScanOutcome>
+    where
+        F: FnOnce() -> Vec<Tag>,
+    {
+        match result {
+            ScanResult::ConflictingValues => Some(Self::ConflictingValues(candidates())),
+            ScanResult::FoundProperties => Some(Self::FoundProperties),
+            ScanResult::NoProperties => Some(Self::NoProperties),
+            ScanResult::Error | ScanResult::Exception(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::partition::FileSystem;
+    use crate::core::partition::PartitionTableType;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn scan_outcome_can_be_converted_from_a_successful_scan_result() {
+        let no_candidates = || -> Vec<Tag> { unreachable!() };
+
+        let actual = ScanOutcome::from_scan_result(ScanResult::FoundProperties, no_candidates);
+        let expected = Some(ScanOutcome::FoundProperties);
+        assert_eq!(actual, expected);
+        assert!(actual.unwrap().found_properties());
+
+        let actual = ScanOutcome::from_scan_result(ScanResult::NoProperties, no_candidates);
+        let expected = Some(ScanOutcome::NoProperties);
+        assert_eq!(actual, expected);
+        assert!(!actual.unwrap().found_properties());
+    }
+
+    #[test]
+    fn scan_outcome_lists_the_candidates_of_a_conflicting_scan_result() {
+        let candidates = || {
+            vec![
+                Tag::Type(FileSystem::VFAT),
+                Tag::PtType(PartitionTableType::DOS),
+            ]
+        };
+
+        let actual = ScanOutcome::from_scan_result(ScanResult::ConflictingValues, candidates);
+        let expected = Some(ScanOutcome::ConflictingValues(candidates()));
+        assert_eq!(actual, expected);
+
+        let outcome = actual.unwrap();
+        assert!(!outcome.found_properties());
+        assert_eq!(outcome.conflicting_candidates(), candidates().as_slice());
+    }
+
+    #[test]
+    fn scan_outcome_can_not_be_converted_from_a_failed_scan_result() {
+        let no_candidates = || -> Vec<Tag> { unreachable!() };
+
+        let actual = ScanOutcome::from_scan_result(ScanResult::Error, no_candidates);
+        assert_eq!(actual, None);
+
+        let actual = ScanOutcome::from_scan_result(ScanResult::Exception(-22), no_candidates);
+        assert_eq!(actual, None);
+    }
+}
@@ -4,19 +4,21 @@
 // From dependency library
 
 // From standard library
-use std::mem::MaybeUninit;
 
 // From this library
 use crate::core::device::Tag;
-use crate::core::device::TagName;
-use crate::ffi_utils;
 use crate::probe::Probe;
+use crate::probe::PropertyIter;
 
 /// Iterator over a collection of [`Tag`]s.
+///
+/// Device properties that can not be converted to a [`Tag`] are skipped. Use a
+/// [`PropertyIter`](crate::probe::PropertyIter) to get notified about them.
 #[derive(Debug)]
 pub struct TagIter<'a> {
     probe: &'a Probe,
-    index: i32,
+    index: usize,
+    count: usize,
 }
 
 impl<'a> TagIter<'a> {
@@ -24,7 +26,13 @@ impl<'a> TagIter<'a> {
     #[allow(dead_code)]
     pub(super) fn new(probe: &'a Probe) -> TagIter<'a> {
         log::debug!("TagIter::new creating a new `TagIter` instance");
-        Self { probe, index: 0 }
+        let count = probe.count_device_properties();
+
+        Self {
+            probe,
+            index: 0,
+            count,
+        }
     }
 }
 
@@ -34,37 +42,18 @@ impl<'a> Iterator for TagIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         log::debug!("TagIter::next iterating next element");
 
-        let mut tag_name_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
-        let mut tag_value_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
-
-        let result = unsafe {
-            libblkid::blkid_probe_get_value(
-                self.probe.inner,
-                self.index,
-                tag_name_ptr.as_mut_ptr(),
-                tag_value_ptr.as_mut_ptr(),
-                std::ptr::null_mut(),
-            )
-        };
-
-        match result {
-            0 => {
-                log::debug!("TagIter::next found next element");
-                self.index += 1;
-                let tag_name_ptr = unsafe { tag_name_ptr.assume_init() };
-                let tag_value_ptr = unsafe { tag_value_ptr.assume_init() };
+        while self.index < self.count {
+            let index = self.index;
+            self.index += 1;
 
-                let cstr = ffi_utils::const_c_char_array_to_c_str(tag_name_ptr);
-                let tag_name = TagName::try_from(cstr).ok()?;
-                let tag_value = ffi_utils::const_c_char_array_to_bytes(tag_value_ptr);
-
-                Tag::try_from((tag_name, tag_value)).ok()
-            }
-            code => {
-                log::debug!("TagIter::next can not get next element. libblkid::blkid_probe_get_value returned error code {:?}", code);
-
-                None
+            if let Some((name, value)) = self.probe.nth_raw_device_property(index) {
+                match PropertyIter::to_tag(name, value) {
+                    Ok(tag) => return Some(tag),
+                    Err(e) => log::debug!("TagIter::next skipping device property. {}", e),
+                }
             }
         }
+
+        None
     }
 }
@@ -0,0 +1,33 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[test]
+fn cache_tag_value_from_device_ext4() {
+    use rsblkid::cache::{Cache, SharedCache};
+    use rsblkid::core::device::TagName;
+    use rsblkid::core::partition::RawBytes;
+
+    let temp_image_file = common::image_file("filesystems/ext4");
+    let path = temp_image_file.path();
+
+    let cache = Cache::builder().discard_changes_on_drop().build().unwrap();
+
+    let actual = cache.tag_value_from_device(&TagName::Label, path);
+    let expected = Some(RawBytes::from("test-ext4"));
+    assert_eq!(actual, expected);
+
+    let actual = cache.tag_value_from_device(&TagName::Uuid, path);
+    let expected = Some(RawBytes::from("ada110f6-bd6d-49db-955d-342c27627b61"));
+    assert_eq!(actual, expected);
+
+    // Only `LABEL` and `UUID` tags are supported.
+    let actual = cache.tag_value_from_device(&TagName::Type, path);
+    assert_eq!(actual, None);
+
+    let cache = SharedCache::new(cache);
+    let actual = cache.tag_value_from_device(&TagName::Label, path);
+    let expected = Some(RawBytes::from("test-ext4"));
+    assert_eq!(actual, expected);
+}
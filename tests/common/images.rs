@@ -0,0 +1,31 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::Write;
+
+/// Returns the decompressed content of the device image `name` (e.g. `filesystems/ext4`) from
+/// `util-linux`'s test suite.
+pub fn decompress_image(name: &str) -> Vec<u8> {
+    let base_dir: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    let mut compressed_image_file_path = std::path::PathBuf::new();
+    compressed_image_file_path.push(base_dir);
+    compressed_image_file_path.push("third-party/vendor/util-linux/blkid/images/");
+    compressed_image_file_path.push(format!("{}.img.xz", name));
+
+    let compressed_image_file = std::fs::File::open(&compressed_image_file_path).unwrap();
+    let mut decompressed = xz2::read::XzDecoder::new(compressed_image_file);
+
+    let mut image = vec![];
+    std::io::copy(&mut decompressed, &mut image).unwrap();
+
+    image
+}
+
+/// Copies the decompressed device image `name` (e.g. `filesystems/ext4`) to a temporary file.
+pub fn image_file(name: &str) -> tempfile::NamedTempFile {
+    let mut temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.write_all(&decompress_image(name)).unwrap();
+
+    temp_image_file
+}
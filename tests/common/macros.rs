@@ -95,6 +95,40 @@ fn [< probe_file_system_ $fs_name:lower >]() {
                 f.read_to_string(&mut expected).unwrap();
 
                 pretty_assertions::assert_eq!(output, expected, "comparing scanned device properties to expected values");
+
+                // Check typed accessors against expected values
+                let expected_values: std::collections::HashMap<&str, &str> = expected
+                    .lines()
+                    .filter_map(|line| line.split_once('='))
+                    .collect();
+                let decoded = |key: &str| {
+                    expected_values
+                        .get(key)
+                        .map(|value| rsblkid::core::utils::encode::decode_string(value).unwrap())
+                };
+                let text = |key: &str| decoded(key).map(|value| String::from_utf8_lossy(&value).to_string());
+                let number = |key: &str| text(key).map(|value| value.parse::<u64>().unwrap());
+
+                let fs_type = probe.fs_type();
+                pretty_assertions::assert_eq!(fs_type.as_ref().map(|t| t.as_str().to_owned()), text("ID_FS_TYPE"), "Probe::fs_type");
+                pretty_assertions::assert_eq!(probe.secondary_fs_type().map(|t| t.as_str().to_owned()), text("ID_FS_SEC_TYPE"), "Probe::secondary_fs_type");
+
+                // Values that are not valid UTF-8 are not converted.
+                let utf8 = |key: &str| decoded(key).and_then(|value| String::from_utf8(value).ok());
+
+                // `Uuid`s are in canonical form, compare them to `blkid`'s output regardless of
+                // case and separators.
+                let canonical = |uuid: &str| uuid.replace('-', "").to_lowercase();
+                pretty_assertions::assert_eq!(probe.uuid().map(|uuid| canonical(uuid.as_str())), utf8("ID_FS_UUID_ENC").map(|uuid| canonical(&uuid)), "Probe::uuid");
+
+                pretty_assertions::assert_eq!(probe.label().map(|label| label.as_str().to_owned()), utf8("ID_FS_LABEL_ENC"), "Probe::label");
+                pretty_assertions::assert_eq!(probe.fs_version().map(|version| version.as_str().to_owned()), text("ID_FS_VERSION"), "Probe::fs_version");
+                pretty_assertions::assert_eq!(probe.fs_size(), number("ID_FS_FSSIZE"), "Probe::fs_size");
+                pretty_assertions::assert_eq!(probe.fs_last_block(), number("ID_FS_FSLASTBLOCK"), "Probe::fs_last_block");
+                pretty_assertions::assert_eq!(probe.block_size(), number("ID_FS_BLOCK_SIZE"), "Probe::block_size");
+                pretty_assertions::assert_eq!(probe.fs_block_size(), number("ID_FS_FSBLOCKSIZE"), "Probe::fs_block_size");
+                pretty_assertions::assert_eq!(probe.usage().map(|usage| usage.as_str().to_owned()), text("ID_FS_USAGE"), "Probe::usage");
+                pretty_assertions::assert_eq!(probe.endianness().map(|endian| endian.as_str().to_owned()), text("ID_FS_ENDIANNESS"), "Probe::endianness");
             }
         }
     };
@@ -216,6 +250,20 @@ fn [< probe_partition_table_ $pt_name:lower >]() {
                 f.read_to_string(&mut expected).unwrap();
 
                 pretty_assertions::assert_eq!(output, expected);
+
+                // Check typed accessors
+                pretty_assertions::assert_eq!(probe.pt_type(), Some(pt), "Probe::pt_type");
+                pretty_assertions::assert_eq!(probe.pt_uuid().map(|uuid| uuid.to_string()), partition_table_id, "Probe::pt_uuid");
+
+                // The whole device is scanned, not one of its partitions, there is no partition
+                // entry to report.
+                pretty_assertions::assert_eq!(probe.part_entry_type(), None, "Probe::part_entry_type");
+                pretty_assertions::assert_eq!(probe.part_entry_uuid(), None, "Probe::part_entry_uuid");
+                pretty_assertions::assert_eq!(probe.part_entry_name(), None, "Probe::part_entry_name");
+                pretty_assertions::assert_eq!(probe.part_entry_number(), None, "Probe::part_entry_number");
+                pretty_assertions::assert_eq!(probe.part_entry_offset(), None, "Probe::part_entry_offset");
+                pretty_assertions::assert_eq!(probe.part_entry_size(), None, "Probe::part_entry_size");
+                pretty_assertions::assert_eq!(probe.part_entry_flags(), None, "Probe::part_entry_flags");
             }
         }
     };
@@ -1,6 +1,8 @@
 // Copyright (c) 2023 Nick Piaddo
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub use images::*;
 pub use macros::*;
 
+mod images;
 mod macros;
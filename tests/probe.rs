@@ -205,3 +205,716 @@
     "sgi",
     "sun"
 ];
+
+#[test]
+fn probe_partition_tree_dos_bsd() {
+    let temp_image_file = common::image_file("partition_tables/dos_bsd");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    let root = probe.partition_tree().unwrap();
+    assert_eq!(
+        root.partition_table_type(),
+        Some(rsblkid::core::partition::PartitionTableType::DOS)
+    );
+    assert_eq!(root.location_in_bytes(), Some(446));
+    assert_eq!(root.depth(), 2);
+
+    let numbers: Vec<_> = root
+        .partitions()
+        .iter()
+        .map(|node| node.partition().number())
+        .collect();
+    assert_eq!(numbers, vec![1, 2]);
+
+    // The FreeBSD slice holds a nested BSD disklabel.
+    let slice = &root.partitions()[1];
+    assert_eq!(slice.ebr_location_in_bytes(), None);
+
+    let nested = slice.nested_table().unwrap();
+    let numbers: Vec<_> = nested
+        .partitions()
+        .iter()
+        .map(|node| node.partition().number())
+        .collect();
+    assert_eq!(numbers, vec![5, 6]);
+    assert!(nested
+        .partitions()
+        .iter()
+        .all(|node| node.nested_table().is_none()));
+}
+
+#[test]
+#[ignore = "requires root privileges to attach a loop device"]
+fn probe_partition_entry_gpt() {
+    use rsblkid::probe::PartitionScanningOption;
+    use std::process::Command;
+
+    // Detaches a loop device when dropped.
+    struct LoopDevice(String);
+
+    impl Drop for LoopDevice {
+        fn drop(&mut self) {
+            let _ = Command::new("losetup").args(["--detach", &self.0]).status();
+        }
+    }
+
+    let temp_image_file = common::image_file("partition_tables/gpt");
+
+    // `libblkid` only reports partition entries when scanning a partition of a block device.
+    let output = Command::new("losetup")
+        .args(["--find", "--show", "--partscan"])
+        .arg(temp_image_file.path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let loop_device = LoopDevice(String::from_utf8(output.stdout).unwrap().trim().to_owned());
+
+    let partition = std::path::PathBuf::from(format!("{}p2", loop_device.0));
+    for _ in 0..50 {
+        if partition.exists() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_device(partition)
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .partitions_scanning_options(vec![PartitionScanningOption::EntryDetails])
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    // Values of the second partition in `lowprobe-pt-gpt`.
+    assert_eq!(
+        probe.part_entry_type().map(|t| t.to_string()),
+        Some(String::from("ebd0a0a2-b9e5-4433-87c0-68b6b72699c7"))
+    );
+    assert_eq!(
+        probe.part_entry_uuid().map(|uuid| uuid.to_string()),
+        Some(String::from("a1d03a96-7238-46c6-bbb3-789cbe173ec7"))
+    );
+    assert_eq!(
+        probe.part_entry_name().map(|name| name.as_str().to_owned()),
+        Some(String::from("ThisIsOtherName"))
+    );
+    assert_eq!(probe.part_entry_number(), Some(2));
+    assert_eq!(probe.part_entry_offset(), Some(2048));
+    assert_eq!(probe.part_entry_size(), Some(2048));
+    assert_eq!(probe.part_entry_flags(), None);
+}
+
+#[test]
+fn probe_gpt_mbr_consistency_in_a_device_segment() {
+    use std::os::unix::fs::FileExt;
+
+    let image = common::decompress_image("partition_tables/gpt");
+
+    // Copy the device image 1 MiB into a temporary file, preceded by an empty MBR.
+    let segment = 1024 * 1024;
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file
+        .as_file()
+        .set_len(segment + image.len() as u64)
+        .unwrap();
+    temp_image_file
+        .as_file()
+        .write_all_at(&image, segment)
+        .unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_segment(segment, image.len() as u64)
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    let report = probe.gpt_mbr_consistency().unwrap().unwrap();
+    assert_eq!(report.kind(), rsblkid::core::partition::MbrKind::Protective);
+    assert!(report.is_consistent());
+}
+
+#[test]
+fn probe_repair_gpt_refuses_a_device_segment() {
+    use rsblkid::core::utils::gpt::GptLocation;
+    use rsblkid::probe::ProbeError;
+    use std::os::unix::fs::FileExt;
+
+    let image = common::decompress_image("partition_tables/gpt");
+
+    // Copy the device image 1 MiB into a temporary file.
+    let segment = 1024 * 1024;
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file
+        .as_file()
+        .set_len(segment + image.len() as u64)
+        .unwrap();
+    temp_image_file
+        .as_file()
+        .write_all_at(&image, segment)
+        .unwrap();
+    let before = std::fs::read(temp_image_file.path()).unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .allow_writes()
+        .scan_device_segment(segment, image.len() as u64)
+        .build()
+        .unwrap();
+
+    let actual = probe.verify_gpt();
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    let actual = probe.preview_gpt_repair(GptLocation::Primary);
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    let actual = probe.repair_gpt(GptLocation::Primary);
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    // Nothing was written to the device.
+    let after = std::fs::read(temp_image_file.path()).unwrap();
+    assert!(before == after);
+}
+
+#[test]
+fn probe_partition_table_edition_refuses_a_device_segment() {
+    use rsblkid::core::partition::PartitionTableType;
+    use rsblkid::probe::ProbeError;
+    use std::os::unix::fs::FileExt;
+
+    let image = common::decompress_image("partition_tables/gpt");
+
+    // Copy the device image 1 MiB into a temporary file.
+    let segment = 1024 * 1024;
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file
+        .as_file()
+        .set_len(segment + image.len() as u64)
+        .unwrap();
+    temp_image_file
+        .as_file()
+        .write_all_at(&image, segment)
+        .unwrap();
+    let before = std::fs::read(temp_image_file.path()).unwrap();
+
+    let mut whole_device = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+    let table = whole_device
+        .new_partition_table(PartitionTableType::GPT)
+        .unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .allow_writes()
+        .scan_device_segment(segment, image.len() as u64)
+        .build()
+        .unwrap();
+
+    let actual = probe.new_partition_table(PartitionTableType::GPT);
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    let actual = probe.edit_partition_table();
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    let actual = probe.write_partition_table(&table);
+    assert!(matches!(actual, Err(ProbeError::Config(_))));
+
+    // Nothing was written to the device.
+    let after = std::fs::read(temp_image_file.path()).unwrap();
+    assert!(before == after);
+}
+
+#[test]
+fn probe_write_partition_table_gpt() {
+    use rsblkid::core::device::Label;
+    use rsblkid::core::partition::{Guid, PartitionTableType, PartitionType};
+    use rsblkid::core::utils::table_writer::PartitionEntry;
+
+    // Create an empty 16 MiB device image.
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(16 * 1024 * 1024).unwrap();
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(temp_image_file.path())
+        .unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(file)
+        .allow_writes()
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .build()
+        .unwrap();
+
+    let mut table = probe.new_partition_table(PartitionTableType::GPT).unwrap();
+
+    let esp = PartitionEntry::new(PartitionType::GPT(Guid::EfiSystem))
+        .with_size(8192)
+        .with_name("esp".parse::<Label>().unwrap());
+    let data = PartitionEntry::new(PartitionType::GPT(Guid::LinuxData));
+
+    assert_eq!(table.add_partition(esp).unwrap(), 1);
+    assert_eq!(table.add_partition(data).unwrap(), 2);
+
+    probe.write_partition_table(&table).unwrap();
+    probe.find_device_properties();
+
+    let partitions: Vec<_> = probe.iter_partitions().collect();
+    assert_eq!(partitions.len(), 2);
+
+    let partition_table = partitions[0].partition_table().unwrap();
+    assert_eq!(
+        partition_table.partition_table_type(),
+        Some(PartitionTableType::GPT)
+    );
+    assert_eq!(partition_table.id(), Some(table.disk_id()));
+
+    let esp = &partitions[0];
+    assert_eq!(esp.number(), 1);
+    assert_eq!(esp.location_in_sectors(), 2048);
+    assert_eq!(esp.size_in_sectors(), 8192);
+    assert_eq!(
+        esp.typed_partition_type(),
+        Some(PartitionType::GPT(Guid::EfiSystem))
+    );
+    assert_eq!(esp.typed_name().unwrap().to_string(), "esp");
+
+    let data = &partitions[1];
+    assert_eq!(data.number(), 2);
+    assert_eq!(data.location_in_sectors(), 10240);
+    assert_eq!(data.size_in_sectors(), table.last_usable_lba() - 10240 + 1);
+    assert_eq!(
+        data.typed_partition_type(),
+        Some(PartitionType::GPT(Guid::LinuxData))
+    );
+}
+
+#[test]
+fn probe_write_partition_table_dos() {
+    use rsblkid::core::partition::{OSType, PartitionBitflags, PartitionTableType, PartitionType};
+    use rsblkid::core::utils::table_writer::PartitionEntry;
+
+    // Create an empty 16 MiB device image.
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(16 * 1024 * 1024).unwrap();
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(temp_image_file.path())
+        .unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(file)
+        .allow_writes()
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .build()
+        .unwrap();
+
+    let mut table = probe.new_partition_table(PartitionTableType::DOS).unwrap();
+
+    let boot = PartitionEntry::new(PartitionType::MBR(OSType::W95FAT32LBA))
+        .with_size(8192)
+        .with_flags(PartitionBitflags::from(0x80));
+    let data = PartitionEntry::new(PartitionType::MBR(OSType::Linux)).with_number(3);
+
+    assert_eq!(table.add_partition(boot).unwrap(), 1);
+    assert_eq!(table.add_partition(data).unwrap(), 3);
+
+    probe.write_partition_table(&table).unwrap();
+    probe.find_device_properties();
+
+    let partitions: Vec<_> = probe.iter_partitions().collect();
+    assert_eq!(partitions.len(), 2);
+
+    let partition_table = partitions[0].partition_table().unwrap();
+    assert_eq!(
+        partition_table.partition_table_type(),
+        Some(PartitionTableType::DOS)
+    );
+    assert_eq!(partition_table.id(), Some(table.disk_id()));
+
+    let boot = &partitions[0];
+    assert_eq!(boot.number(), 1);
+    assert_eq!(boot.location_in_sectors(), 2048);
+    assert_eq!(boot.size_in_sectors(), 8192);
+    assert_eq!(boot.flags(), 0x80);
+    assert_eq!(
+        boot.typed_partition_type(),
+        Some(PartitionType::MBR(OSType::W95FAT32LBA))
+    );
+
+    let data = &partitions[1];
+    assert_eq!(data.number(), 3);
+    assert_eq!(data.location_in_sectors(), 10240);
+    assert_eq!(data.size_in_sectors(), 32768 - 10240);
+    assert_eq!(data.flags(), 0x00);
+    assert_eq!(
+        data.typed_partition_type(),
+        Some(PartitionType::MBR(OSType::Linux))
+    );
+}
+
+#[test]
+fn probe_write_partition_table_fails_in_read_only_mode() {
+    use rsblkid::core::partition::PartitionTableType;
+    use rsblkid::probe::ProbeError;
+
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(16 * 1024 * 1024).unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(false)
+        .scan_device_partitions(true)
+        .build()
+        .unwrap();
+
+    let table = probe.new_partition_table(PartitionTableType::DOS).unwrap();
+
+    let actual = probe.write_partition_table(&table);
+    assert!(matches!(actual, Err(ProbeError::IoWrite(_))));
+
+    // Nothing was written to the device.
+    probe.find_device_properties();
+    assert_eq!(probe.iter_partitions().count(), 0);
+    assert!(probe.iter_partitions().partition_table().is_none());
+}
+
+#[test]
+fn probe_scan_lost_partitions() {
+    use rsblkid::core::partition::{FileSystem, Guid, PartitionTableType, PartitionType};
+    use std::os::unix::fs::FileExt;
+
+    const MIB: u64 = 1024 * 1024;
+
+    // Copy the decompressed file system image twice, at 1 MiB and 72 MiB, on a 96 MiB device
+    // without partition table. The 8 MiB image holds the beginning of a 64 MiB file system.
+    let image = common::decompress_image("filesystems/ext4");
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(96 * MIB).unwrap();
+
+    for location in [MIB, 72 * MIB] {
+        temp_image_file
+            .as_file()
+            .write_all_at(&image, location)
+            .unwrap();
+    }
+
+    let probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    let mut calls = 0;
+    let report = probe
+        .scan_lost_partitions(MIB, |progress| {
+            calls += 1;
+            assert!(progress.scanned_bytes() <= progress.total_bytes());
+        })
+        .unwrap();
+
+    assert!(calls > 0);
+
+    let candidates: Vec<_> = report
+        .candidates()
+        .iter()
+        .map(|c| (c.file_system().clone(), c.location_in_bytes()))
+        .collect();
+    assert_eq!(
+        candidates,
+        vec![(FileSystem::Ext4, MIB), (FileSystem::Ext4, 72 * MIB)]
+    );
+
+    let table = report.to_partition_table(PartitionTableType::GPT).unwrap();
+    let partitions = table.partitions();
+    let starts: Vec<_> = partitions.iter().map(|p| p.start()).collect();
+    assert_eq!(starts, vec![Some(2048), Some(147456)]);
+    assert!(partitions
+        .iter()
+        .all(|p| p.partition_type() == &PartitionType::GPT(Guid::LinuxData)));
+
+    // The file system spans 65537 blocks of 1 KiB (`FSLASTBLOCK=65536`, `FSBLOCKSIZE=1024`).
+    #[cfg(v2_39)]
+    {
+        let extent = 65537 * 1024;
+        assert_eq!(report.candidates()[0].extent_in_bytes(), Some(extent));
+        assert_eq!(partitions[0].size(), Some(extent / 512));
+    }
+
+    // Without the file system's extent, the partition ends before the next candidate.
+    #[cfg(not(v2_39))]
+    assert_eq!(partitions[0].size(), Some(147456 - 2048));
+
+    // The second file system's extent exceeds the device's size.
+    assert_eq!(partitions[1].end(), Some(table.last_usable_lba()));
+}
+
+#[test]
+fn probe_scan_lost_partitions_ignores_raid_metadata_at_the_end_of_the_device() {
+    use std::os::unix::fs::FileExt;
+
+    const MIB: u64 = 1024 * 1024;
+
+    // Copy the 10 MiB image of an `mdraid` v0.90 member, with its superblock near its end, at
+    // the end of a 16 MiB device.
+    let image = common::decompress_image("filesystems/mdraid");
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(16 * MIB).unwrap();
+    temp_image_file
+        .as_file()
+        .write_all_at(&image, 6 * MIB)
+        .unwrap();
+
+    let probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    // Every window reaches the superblock, none is reported as a lost partition.
+    let report = probe.scan_lost_partitions(MIB, |_| {}).unwrap();
+    assert!(report.candidates().is_empty());
+}
+
+#[test]
+fn probe_scan_lost_partitions_rejects_an_invalid_step() {
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(1024 * 1024).unwrap();
+
+    let probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    assert!(probe.scan_lost_partitions(0, |_| {}).is_err());
+    assert!(probe.scan_lost_partitions(100, |_| {}).is_err());
+}
+
+#[test]
+fn probe_decoded_label_fat32_cp850() {
+    use rsblkid::core::partition::{Codepage, DecodingConfidence, LabelEncoding};
+
+    let temp_image_file = common::image_file("filesystems/fat32_cp850_O_tilde");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(true)
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    // The label is stored as `0xE5 0xE5 0xE5`, i.e. `ÕÕÕ` in code page 850.
+    let decoded = probe.decoded_label(Codepage::Cp850).unwrap();
+    assert_eq!(decoded.label(), "ÕÕÕ");
+    assert_eq!(decoded.encoding(), LabelEncoding::Codepage(Codepage::Cp850));
+    assert_eq!(decoded.confidence(), DecodingConfidence::Assumed);
+}
+
+#[test]
+fn probe_decoded_label_iso9660_joliet() {
+    use rsblkid::core::partition::{Codepage, DecodingConfidence, LabelEncoding};
+
+    let temp_image_file = common::image_file("filesystems/iso-joliet");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(true)
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    // The Joliet label is stored in UTF-16BE, `libblkid` converts it to UTF-8 in `LABEL`.
+    let decoded = probe.decoded_label(Codepage::Cp437).unwrap();
+    assert_eq!(decoded.label(), "ThisWonderfulLabelIsVeryVeryLong");
+    assert_eq!(decoded.encoding(), LabelEncoding::Utf8);
+    assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+}
+
+#[test]
+fn probe_decoded_label_ntfs() {
+    use rsblkid::core::partition::{Codepage, DecodingConfidence, LabelEncoding};
+    use rsblkid::probe::FsProperty;
+
+    let temp_image_file = common::image_file("filesystems/ntfs");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(true)
+        .collect_fs_properties(vec![
+            FsProperty::Label,
+            FsProperty::LabelRaw,
+            FsProperty::Type,
+        ])
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    // The label is stored in UTF-16LE, and decoded from `LABEL_RAW`.
+    let decoded = probe.decoded_label(Codepage::Cp437).unwrap();
+    assert_eq!(decoded.label(), "Новый том");
+    assert_eq!(decoded.encoding(), LabelEncoding::Utf16Le);
+    assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+}
+
+#[test]
+fn probe_decoded_label_exfat() {
+    use rsblkid::core::partition::{Codepage, DecodingConfidence, LabelEncoding};
+    use rsblkid::probe::FsProperty;
+
+    let temp_image_file = common::image_file("filesystems/exfat");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .scan_device_superblocks(true)
+        .collect_fs_properties(vec![
+            FsProperty::Label,
+            FsProperty::LabelRaw,
+            FsProperty::Type,
+        ])
+        .build()
+        .unwrap();
+
+    probe.find_device_properties();
+
+    let decoded = probe.decoded_label(Codepage::Cp437).unwrap();
+    assert_eq!(decoded.label(), "Новый том");
+    assert_eq!(decoded.encoding(), LabelEncoding::Utf16Le);
+    assert_eq!(decoded.confidence(), DecodingConfidence::Certain);
+}
+
+#[test]
+fn probe_udev_properties_ext4() {
+    let temp_image_file = common::image_file("filesystems/ext4");
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    let actual: Vec<_> = probe
+        .udev_properties()
+        .unwrap()
+        .into_iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+
+    // Properties printed by `udevadm test-builtin blkid` for a loop device backed by the
+    // image, in the order `libblkid` reports device properties.
+    let expected = vec![
+        "ID_FS_LABEL=test-ext4",
+        "ID_FS_LABEL_ENC=test-ext4",
+        "ID_FS_UUID=ada110f6-bd6d-49db-955d-342c27627b61",
+        "ID_FS_UUID_ENC=ada110f6-bd6d-49db-955d-342c27627b61",
+        "ID_FS_VERSION=1.0",
+        "ID_FS_BLOCKSIZE=1024",
+        "ID_FS_LASTBLOCK=65536",
+        "ID_FS_SIZE=67108864",
+        "ID_FS_TYPE=ext4",
+        "ID_FS_USAGE=filesystem",
+    ];
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn probe_try_scan_methods_found_properties() {
+    use rsblkid::probe::ScanOutcome;
+
+    let temp_image_file = common::image_file("filesystems/ext4");
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    let actual = probe.try_find_device_properties().unwrap();
+    assert_eq!(actual, ScanOutcome::FoundProperties);
+
+    let actual = probe.try_find_all_device_properties().unwrap();
+    assert_eq!(actual, ScanOutcome::FoundProperties);
+
+    probe.reset();
+    let actual = probe.try_run_scan().unwrap();
+    assert_eq!(actual, ScanOutcome::FoundProperties);
+}
+
+#[test]
+fn probe_try_scan_methods_no_properties() {
+    use rsblkid::probe::ScanOutcome;
+
+    let temp_image_file = tempfile::NamedTempFile::new().unwrap();
+    temp_image_file.as_file().set_len(1024 * 1024).unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    let actual = probe.try_find_device_properties().unwrap();
+    assert_eq!(actual, ScanOutcome::NoProperties);
+
+    let actual = probe.try_find_all_device_properties().unwrap();
+    assert_eq!(actual, ScanOutcome::NoProperties);
+
+    probe.reset();
+    let actual = probe.try_run_scan().unwrap();
+    assert_eq!(actual, ScanOutcome::NoProperties);
+}
+
+#[test]
+fn probe_try_scan_methods_conflicting_values() {
+    use rsblkid::core::device::Tag;
+    use rsblkid::core::partition::FileSystem;
+    use rsblkid::probe::ScanOutcome;
+    use std::os::unix::fs::FileExt;
+
+    // Copy the superblock of an ext4 file system into the unused reserved sectors of a FAT32
+    // file system.
+    let ext4 = common::decompress_image("filesystems/ext4");
+    let temp_image_file = common::image_file("filesystems/small-fat32");
+    temp_image_file
+        .as_file()
+        .write_all_at(&ext4[1024..2048], 1024)
+        .unwrap();
+
+    let mut probe = rsblkid::probe::Probe::builder()
+        .scan_file(temp_image_file.into_file())
+        .build()
+        .unwrap();
+
+    let actual = probe.try_find_all_device_properties().unwrap();
+    let candidates = actual.conflicting_candidates();
+    assert_eq!(candidates.len(), 2);
+    assert!(candidates.contains(&Tag::Type(FileSystem::Ext4)));
+    assert!(candidates.contains(&Tag::Type(FileSystem::VFAT)));
+
+    // Only `Probe::try_find_all_device_properties` reports conflicting values.
+    let actual = probe.try_find_device_properties().unwrap();
+    assert_eq!(actual, ScanOutcome::FoundProperties);
+
+    probe.reset();
+    let actual = probe.try_run_scan().unwrap();
+    assert_eq!(actual, ScanOutcome::FoundProperties);
+}